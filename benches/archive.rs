@@ -0,0 +1,81 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![allow(deprecated)]
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use dusk_jubjub::JubJubScalar;
+use dusk_pki::{Compact, PublicSpendKey, SecretSpendKey, StealthAddress};
+use rand_core::OsRng;
+use rkyv::{check_archived_root, Archive, Deserialize, Infallible, Serialize};
+
+#[derive(Archive, Serialize, Deserialize)]
+#[archive(check_bytes)]
+struct Output {
+    psk: PublicSpendKey,
+    sa: StealthAddress,
+}
+
+#[derive(Archive, Serialize, Deserialize)]
+#[archive(check_bytes)]
+struct CompactOutput {
+    #[with(Compact)]
+    psk: PublicSpendKey,
+    #[with(Compact)]
+    sa: StealthAddress,
+}
+
+fn archive_layouts(c: &mut Criterion) {
+    let psk = SecretSpendKey::random(&mut OsRng).public_spend_key();
+    let sa = psk.gen_stealth_address(&JubJubScalar::random(&mut OsRng));
+
+    let default = rkyv::to_bytes::<_, 1024>(&Output { psk, sa }).unwrap();
+    let compact = rkyv::to_bytes::<_, 256>(&CompactOutput { psk, sa }).unwrap();
+
+    println!(
+        "archived output size: default {} bytes, compact {} bytes",
+        default.len(),
+        compact.len()
+    );
+
+    let mut group = c.benchmark_group("archive");
+
+    group.bench_function("default/check", |b| {
+        b.iter(|| check_archived_root::<Output>(black_box(&default)).unwrap())
+    });
+    group.bench_function("compact/check", |b| {
+        b.iter(|| {
+            check_archived_root::<CompactOutput>(black_box(&compact)).unwrap()
+        })
+    });
+
+    let archived = check_archived_root::<Output>(&default).unwrap();
+    group.bench_function("default/access", |b| {
+        b.iter(|| {
+            let psk: PublicSpendKey = black_box(&archived.psk)
+                .deserialize(&mut Infallible)
+                .unwrap();
+            let sa: StealthAddress = black_box(&archived.sa)
+                .deserialize(&mut Infallible)
+                .unwrap();
+            (psk, sa)
+        })
+    });
+
+    let archived = check_archived_root::<CompactOutput>(&compact).unwrap();
+    group.bench_function("compact/access", |b| {
+        b.iter(|| {
+            let psk = black_box(&archived.psk).decode().unwrap();
+            let sa = black_box(&archived.sa).decode().unwrap();
+            (psk, sa)
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, archive_layouts);
+criterion_main!(benches);
@@ -0,0 +1,54 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![allow(deprecated)]
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use dusk_jubjub::{JubJubScalar, GENERATOR_EXTENDED};
+use dusk_pki::{PublicKey, SecretKey, SecretSpendKey};
+use rand_core::OsRng;
+
+fn fixed_base(c: &mut Criterion) {
+    let s = JubJubScalar::random(&mut OsRng);
+    let sk = SecretKey::from(s);
+
+    let mut group = c.benchmark_group("fixed_base");
+
+    // A public key is a single multiplication of the generator, so it
+    // compares the table against the ladder it replaces
+    group.bench_function("ladder", |b| {
+        b.iter(|| GENERATOR_EXTENDED * black_box(s))
+    });
+    group.bench_function("table", |b| {
+        b.iter(|| PublicKey::from(black_box(&sk)))
+    });
+
+    group.finish();
+}
+
+fn key_paths(c: &mut Criterion) {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let psk = ssk.public_spend_key();
+    let vk = ssk.view_key();
+    let r = JubJubScalar::random(&mut OsRng);
+    let sa = psk.gen_stealth_address(&r);
+
+    let mut group = c.benchmark_group("fixed_base/keys");
+
+    group.bench_function("public_spend_key", |b| {
+        b.iter(|| black_box(&ssk).public_spend_key())
+    });
+    group.bench_function("view_key", |b| b.iter(|| black_box(&ssk).view_key()));
+    group.bench_function("gen_stealth_address", |b| {
+        b.iter(|| black_box(&psk).gen_stealth_address(black_box(&r)))
+    });
+    group.bench_function("owns", |b| b.iter(|| black_box(&vk).owns(&sa)));
+
+    group.finish();
+}
+
+criterion_group!(benches, fixed_base, key_paths);
+criterion_main!(benches);
@@ -0,0 +1,138 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![allow(deprecated)]
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use dusk_bytes::Serializable;
+use dusk_jubjub::{BlsScalar, JubJubScalar, GENERATOR_EXTENDED};
+use dusk_pki::{
+    IntegratedAddress, IntegratedStealthAddress, KeyDerivation, Nullifier,
+    PublicKey, PublicSpendKey, Scanner, SecretKey, SecretSpendKey,
+    StealthAddress, ViewKey, VrfOutput, VrfProof,
+};
+use rand_core::OsRng;
+
+fn key_operations(c: &mut Criterion) {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let psk = ssk.public_spend_key();
+    let vk = ssk.view_key();
+    let r = JubJubScalar::random(&mut OsRng);
+    let sa = psk.gen_stealth_address(&r);
+
+    let mut group = c.benchmark_group("keys");
+
+    group.bench_function("SecretSpendKey::random", |b| {
+        b.iter(|| SecretSpendKey::random(&mut OsRng))
+    });
+    group.bench_function("public_spend_key", |b| {
+        b.iter(|| black_box(&ssk).public_spend_key())
+    });
+    group.bench_function("view_key", |b| b.iter(|| black_box(&ssk).view_key()));
+    group.bench_function("gen_stealth_address", |b| {
+        b.iter(|| black_box(&psk).gen_stealth_address(black_box(&r)))
+    });
+    group.bench_function("owns", |b| b.iter(|| black_box(&vk).owns(&sa)));
+    group.bench_function("sk_r", |b| b.iter(|| black_box(&ssk).sk_r(&sa)));
+
+    // The legacy stealth key is the bare permutation hash of the point
+    let p = GENERATOR_EXTENDED * r;
+    group.bench_function("permutation::hash", |b| {
+        b.iter(|| KeyDerivation::LEGACY.stealth_key(black_box(&p)))
+    });
+
+    group.finish();
+}
+
+macro_rules! bench_serialization {
+    ($group:expr, $ty:ty, $value:expr) => {{
+        let value: $ty = $value;
+        let bytes = value.to_bytes();
+
+        $group.bench_function(concat!(stringify!($ty), "/to_bytes"), |b| {
+            b.iter(|| black_box(&value).to_bytes())
+        });
+        $group.bench_function(concat!(stringify!($ty), "/from_bytes"), |b| {
+            b.iter(|| <$ty>::from_bytes(black_box(&bytes)).unwrap())
+        });
+    }};
+}
+
+fn serialization(c: &mut Criterion) {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let psk = ssk.public_spend_key();
+    let r = JubJubScalar::random(&mut OsRng);
+    let sa = psk.gen_stealth_address(&r);
+    let sk = ssk.sk_r(&sa);
+    let (output, proof) = sk.vrf_prove(&[BlsScalar::one()]);
+    let address = IntegratedAddress::new(psk, [7; 8]);
+
+    let mut group = c.benchmark_group("serialization");
+
+    bench_serialization!(group, SecretKey, sk);
+    bench_serialization!(group, PublicKey, PublicKey::from(&sk));
+    bench_serialization!(group, SecretSpendKey, ssk);
+    bench_serialization!(group, PublicSpendKey, psk);
+    bench_serialization!(group, ViewKey, ssk.view_key());
+    bench_serialization!(group, StealthAddress, sa);
+    bench_serialization!(group, IntegratedAddress, address);
+    bench_serialization!(
+        group,
+        IntegratedStealthAddress,
+        address.gen_stealth_address(&r)
+    );
+    bench_serialization!(group, Nullifier, Nullifier::new(&sk));
+    bench_serialization!(group, VrfOutput, output);
+    bench_serialization!(group, VrfProof, proof);
+    bench_serialization!(group, Scanner, Scanner::new(ssk.view_key(), 0));
+
+    group.finish();
+}
+
+#[cfg(feature = "alloc")]
+fn batch(c: &mut Criterion) {
+    use dusk_pki::{PreparedViewKey, ReserveProof};
+
+    const OUTPUTS: usize = 64;
+
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let psk = ssk.public_spend_key();
+    let prepared = PreparedViewKey::new(&ssk.view_key());
+    let outputs: Vec<StealthAddress> = (0..OUTPUTS)
+        .map(|_| psk.gen_stealth_address(&JubJubScalar::random(&mut OsRng)))
+        .collect();
+
+    let mut group = c.benchmark_group("batch");
+
+    group.bench_function("PreparedViewKey::owns_batch", |b| {
+        b.iter(|| black_box(&prepared).owns_batch(&outputs))
+    });
+
+    let proof = ReserveProof::new(&ssk, &outputs, &mut OsRng)
+        .unwrap()
+        .unwrap();
+    let bytes = proof.to_bytes();
+    group.bench_function("ReserveProof::new", |b| {
+        b.iter(|| ReserveProof::new(black_box(&ssk), &outputs, &mut OsRng))
+    });
+    group.bench_function("ReserveProof::verify", |b| {
+        b.iter(|| black_box(&proof).verify(&psk, &outputs))
+    });
+    group.bench_function("ReserveProof::to_bytes", |b| {
+        b.iter(|| black_box(&proof).to_bytes())
+    });
+    group.bench_function("ReserveProof::from_slice", |b| {
+        b.iter(|| ReserveProof::from_slice(black_box(&bytes)).unwrap())
+    });
+
+    group.finish();
+}
+
+#[cfg(not(feature = "alloc"))]
+criterion_group!(benches, key_operations, serialization);
+#[cfg(feature = "alloc")]
+criterion_group!(benches, key_operations, serialization, batch);
+criterion_main!(benches);
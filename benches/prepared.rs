@@ -0,0 +1,58 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![allow(deprecated)]
+
+use criterion::{
+    black_box, criterion_group, criterion_main, Criterion, Throughput,
+};
+use dusk_jubjub::JubJubScalar;
+use dusk_pki::{PreparedViewKey, SecretSpendKey, StealthAddress};
+use rand_core::OsRng;
+
+const OUTPUTS: usize = 256;
+
+fn prepared_view_key(c: &mut Criterion) {
+    let vk = SecretSpendKey::random(&mut OsRng).view_key();
+    let prepared = PreparedViewKey::new(&vk);
+
+    // A rescan mostly meets outputs of other keys
+    let foreign = SecretSpendKey::random(&mut OsRng).public_spend_key();
+    let outputs: Vec<StealthAddress> = (0..OUTPUTS)
+        .map(|_| foreign.gen_stealth_address(&JubJubScalar::random(&mut OsRng)))
+        .collect();
+
+    let mut group = c.benchmark_group("prepared");
+    group.throughput(Throughput::Elements(OUTPUTS as u64));
+
+    group.bench_function("view_key/owns", |b| {
+        b.iter(|| {
+            outputs
+                .iter()
+                .map(|sa| black_box(&vk).owns(sa))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("prepared/owns", |b| {
+        b.iter(|| {
+            outputs
+                .iter()
+                .map(|sa| black_box(&prepared).owns(sa))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("prepared/owns_batch", |b| {
+        b.iter(|| black_box(&prepared).owns_batch(&outputs))
+    });
+    group.bench_function("prepare", |b| {
+        b.iter(|| PreparedViewKey::new(black_box(&vk)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, prepared_view_key);
+criterion_main!(benches);
@@ -0,0 +1,36 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+fn main() {
+    #[cfg(feature = "ffi")]
+    ffi::build();
+}
+
+/// Generates the C header of the bindings
+///
+/// The C library is built by the `dusk-pki-ffi` crate in `ffi/`, whose test
+/// suite links the C harness of `tests/ffi`.
+#[cfg(feature = "ffi")]
+mod ffi {
+    use std::env;
+    use std::path::PathBuf;
+
+    pub fn build() {
+        let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+        println!("cargo:rerun-if-changed=src/ffi.rs");
+        println!("cargo:rerun-if-changed=cbindgen.toml");
+
+        let config = cbindgen::Config::from_file("cbindgen.toml")
+            .expect("cbindgen.toml should be valid");
+        cbindgen::Builder::new()
+            .with_config(config)
+            .with_src("src/ffi.rs")
+            .generate()
+            .expect("The bindings should be generated")
+            .write_to_file(out_dir.join("dusk_pki.h"));
+    }
+}
@@ -0,0 +1,19 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Static and dynamic C library of the bindings of [`dusk_pki::ffi`]
+//!
+//! The library crate of `dusk-pki` is `no_std`, so it cannot be built as a
+//! `staticlib` or `cdylib` itself. This crate links it with `std` and
+//! exports its bindings, declared by `include/dusk_pki.h`:
+//!
+//! ```text
+//! cargo build --release --manifest-path ffi/Cargo.toml
+//! ```
+
+#![allow(deprecated)]
+
+pub use dusk_pki::ffi::*;
@@ -0,0 +1,19 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+// Links the bindings called by the harness
+use dusk_pki_ffi as _;
+
+use dusk_pki_ffi_harness::dusk_pki_harness;
+
+#[test]
+fn c_harness() {
+    let line = unsafe { dusk_pki_harness() };
+    assert_eq!(
+        line, 0,
+        "Check at line {line} of tests/ffi/harness.c failed"
+    );
+}
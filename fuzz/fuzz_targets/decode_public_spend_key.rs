@@ -0,0 +1,13 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![no_main]
+#![allow(deprecated)]
+
+use dusk_pki::PublicSpendKey;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| dusk_pki_fuzz::decode::<PublicSpendKey, 64>(data));
@@ -0,0 +1,127 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Checks shared by the fuzz targets
+//!
+//! Every decoder must reject malformed input without panicking, and anything
+//! it accepts must re-encode to the bytes it was decoded from. The seed
+//! corpus of the targets is generated by `tests/fuzz_seeds.rs`, and kept
+//! apart from the corpus grown by the fuzzer:
+//!
+//! ```text
+//! cargo fuzz run decode_view_key corpus/decode_view_key seeds/decode_view_key
+//! ```
+
+#![allow(deprecated)]
+
+use core::fmt::Debug;
+use core::mem;
+
+use dusk_bytes::{DeserializableSlice, ParseHexStr, Serializable};
+use dusk_pki::{
+    Compact, InvalidArchive, KeyError, PublicKey, PublicSpendKey, SecretKey,
+    SecretSpendKey, StealthAddress, ViewKey,
+};
+use rkyv::ser::serializers::AllocSerializer;
+use rkyv::validation::validators::DefaultValidator;
+use rkyv::{
+    check_archived_root, AlignedVec, Archive, CheckBytes, Deserialize,
+    Fallible, Serialize,
+};
+
+/// Deserializer failing on the invalid encodings of the [`Compact`] keys
+pub struct Strict;
+
+impl Fallible for Strict {
+    type Error = InvalidArchive;
+}
+
+/// Keys archived with the [`Compact`] layout
+#[derive(Archive, Serialize, Deserialize)]
+#[archive(check_bytes)]
+pub struct CompactKeys {
+    #[with(Compact)]
+    pk: PublicKey,
+    #[with(Compact)]
+    psk: PublicSpendKey,
+    #[with(Compact)]
+    sa: StealthAddress,
+}
+
+/// Decodes the input with `from_slice`, `from_bytes` and `from_hex_str`
+///
+/// The decoders only read the first `N` bytes, or `2 · N` hex digits, of a
+/// longer input, so an accepted key re-encodes to that prefix.
+pub fn decode<T, const N: usize>(data: &[u8])
+where
+    T: Serializable<N, Error = KeyError> + PartialEq + Debug,
+{
+    let decoded = T::from_slice(data);
+
+    if data.len() >= N {
+        let mut bytes = [0u8; N];
+        bytes.copy_from_slice(&data[..N]);
+        assert_eq!(T::from_bytes(&bytes), decoded);
+    }
+
+    if let Ok(key) = decoded {
+        assert_eq!(key.to_bytes()[..], data[..N]);
+    }
+
+    if let Ok(s) = core::str::from_utf8(data) {
+        if let Ok(key) = T::from_hex_str(s) {
+            let hex: String =
+                key.to_bytes().iter().map(|b| format!("{b:02x}")).collect();
+            assert_eq!(hex, s[..2 * N].to_ascii_lowercase());
+        }
+    }
+}
+
+/// Accesses the archived type selected by the first byte of the input
+///
+/// The selector indexes, modulo their number, [`SecretKey`], [`PublicKey`],
+/// [`SecretSpendKey`], [`PublicSpendKey`], [`ViewKey`], [`StealthAddress`]
+/// and [`CompactKeys`]. The remaining bytes are the archive.
+pub fn access_archived(data: &[u8]) {
+    let Some((selector, archive)) = data.split_first() else {
+        return;
+    };
+
+    let mut bytes = AlignedVec::new();
+    bytes.extend_from_slice(archive);
+
+    match selector % 7 {
+        0 => check_archive::<SecretKey>(&bytes),
+        1 => check_archive::<PublicKey>(&bytes),
+        2 => check_archive::<SecretSpendKey>(&bytes),
+        3 => check_archive::<PublicSpendKey>(&bytes),
+        4 => check_archive::<ViewKey>(&bytes),
+        5 => check_archive::<StealthAddress>(&bytes),
+        _ => check_archive::<CompactKeys>(&bytes),
+    }
+}
+
+/// Checks the root archived at the end of the bytes and, if it is valid,
+/// that the deserialized value archives to the same bytes
+fn check_archive<T>(bytes: &[u8])
+where
+    T: Archive + Serialize<AllocSerializer<256>>,
+    T::Archived:
+        for<'a> CheckBytes<DefaultValidator<'a>> + Deserialize<T, Strict>,
+{
+    let Ok(archived) = check_archived_root::<T>(bytes) else {
+        return;
+    };
+
+    let value: T = archived
+        .deserialize(&mut Strict)
+        .expect("A checked archive should deserialize");
+
+    let root = bytes.len() - mem::size_of::<T::Archived>();
+    let rearchived =
+        rkyv::to_bytes::<_, 256>(&value).expect("Failed to archive");
+    assert_eq!(rearchived[..], bytes[root..]);
+}
@@ -0,0 +1,160 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ *
+ * Copyright (c) DUSK NETWORK. All rights reserved. */
+
+#ifndef DUSK_PKI_H
+#define DUSK_PKI_H
+
+/* Generated with cbindgen from src/ffi.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/*
+ Size of the seed of a secret spend key
+ */
+#define DUSK_PKI_SEED_SIZE 32
+
+/*
+ Size of an encoded scalar
+ */
+#define DUSK_PKI_SCALAR_SIZE 32
+
+/*
+ Size of an encoded secret key
+ */
+#define DUSK_PKI_SECRET_KEY_SIZE 32
+
+/*
+ Size of an encoded secret spend key
+ */
+#define DUSK_PKI_SECRET_SPEND_KEY_SIZE 64
+
+/*
+ Size of an encoded public spend key
+ */
+#define DUSK_PKI_PUBLIC_SPEND_KEY_SIZE 64
+
+/*
+ Size of an encoded view key
+ */
+#define DUSK_PKI_VIEW_KEY_SIZE 64
+
+/*
+ Size of an encoded stealth address
+ */
+#define DUSK_PKI_STEALTH_ADDRESS_SIZE 64
+
+/*
+ Result of a call
+ */
+typedef enum DuskPkiStatus {
+  /*
+   The call succeeded
+   */
+  DUSK_PKI_STATUS_OK = 0,
+  /*
+   A pointer argument is null
+   */
+  DUSK_PKI_STATUS_NULL_POINTER = 1,
+  /*
+   A scalar is not canonically encoded
+   */
+  DUSK_PKI_STATUS_INVALID_SCALAR = 2,
+  /*
+   A point is not on the curve
+   */
+  DUSK_PKI_STATUS_OFF_CURVE_POINT = 3,
+  /*
+   A point is of small order
+   */
+  DUSK_PKI_STATUS_SMALL_ORDER_POINT = 4,
+  /*
+   The encoding of a key is invalid
+   */
+  DUSK_PKI_STATUS_INVALID_DATA = 5,
+} DuskPkiStatus;
+
+/*
+ Generates a secret spend key from a seed of `SEED_SIZE` bytes
+
+ The same seed always generates the same key.
+
+ # Safety
+
+ `seed` must point to `SEED_SIZE` readable bytes, and `ssk` to
+ `SECRET_SPEND_KEY_SIZE` writable bytes.
+ */
+enum DuskPkiStatus dusk_pki_ssk_from_seed(const uint8_t *seed, uint8_t *ssk);
+
+/*
+ Reduces `2 * SCALAR_SIZE` uniformly random bytes into a scalar, suitable
+ as the `r` of [`dusk_pki_gen_stealth_address`]
+
+ # Safety
+
+ `wide` must point to `2 * SCALAR_SIZE` readable bytes, and `scalar` to
+ `SCALAR_SIZE` writable bytes.
+ */
+enum DuskPkiStatus dusk_pki_scalar_from_wide(const uint8_t *wide, uint8_t *scalar);
+
+/*
+ Derives the view key of a secret spend key
+
+ # Safety
+
+ `ssk` must point to `SECRET_SPEND_KEY_SIZE` readable bytes, and `vk` to
+ `VIEW_KEY_SIZE` writable bytes.
+ */
+enum DuskPkiStatus dusk_pki_view_key(const uint8_t *ssk, uint8_t *vk);
+
+/*
+ Derives the public spend key of a secret spend key
+
+ # Safety
+
+ `ssk` must point to `SECRET_SPEND_KEY_SIZE` readable bytes, and `psk` to
+ `PUBLIC_SPEND_KEY_SIZE` writable bytes.
+ */
+enum DuskPkiStatus dusk_pki_public_spend_key(const uint8_t *ssk, uint8_t *psk);
+
+/*
+ Generates the stealth address `PKr = H(A · r) · G + B` of a public spend
+ key
+
+ # Safety
+
+ `psk` must point to `PUBLIC_SPEND_KEY_SIZE` readable bytes, `r` to
+ `SCALAR_SIZE` readable bytes, and `sa` to `STEALTH_ADDRESS_SIZE` writable
+ bytes.
+ */
+enum DuskPkiStatus dusk_pki_gen_stealth_address(const uint8_t *psk, const uint8_t *r, uint8_t *sa);
+
+/*
+ Checks whether a stealth address is owned by a view key
+
+ # Safety
+
+ `vk` must point to `VIEW_KEY_SIZE` readable bytes, `sa` to
+ `STEALTH_ADDRESS_SIZE` readable bytes, and `owned` to a writable `bool`.
+ */
+enum DuskPkiStatus dusk_pki_owns(const uint8_t *vk, const uint8_t *sa, bool *owned);
+
+/*
+ Derives the one-time secret key `sk_r = H(a · R) + b` of a stealth
+ address
+
+ # Safety
+
+ `ssk` must point to `SECRET_SPEND_KEY_SIZE` readable bytes, `sa` to
+ `STEALTH_ADDRESS_SIZE` readable bytes, and `sk_r` to `SECRET_KEY_SIZE`
+ writable bytes.
+ */
+enum DuskPkiStatus dusk_pki_sk_r(const uint8_t *ssk, const uint8_t *sa, uint8_t *sk_r);
+
+#endif /* DUSK_PKI_H */
@@ -0,0 +1,320 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Validation of the archived key types
+//!
+//! The derived `CheckBytes` of the curve types only guarantees that the bytes
+//! are a valid bit pattern for the field elements. The archived keys check in
+//! addition that every scalar and coordinate is reduced, and that every point
+//! is a consistent extended representation of a point of the curve, so they
+//! can be safely accessed straight from untrusted storage.
+//!
+//! The [`Compact`] wrapper provides an opt-in alternative layout, archiving
+//! the keys with their [`Serializable`] encoding instead of the extended
+//! coordinates of their points.
+
+use crate::{
+    JubJubExtended, JubJubScalar, KeyDerivation, ProtocolVersion, PublicKey,
+    PublicSpendKey, StealthAddress,
+};
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use bytecheck::{CheckBytes, Error, ErrorBox};
+use dusk_bytes::Serializable;
+use dusk_jubjub::{BlsScalar, EDWARDS_D};
+use rkyv::with::{ArchiveWith, DeserializeWith, SerializeWith};
+use rkyv::{Archive, Archived, Deserialize, Fallible, Infallible};
+
+/// Invariant violated by an archived key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidArchive {
+    /// A scalar or a coordinate is not reduced modulo its field order
+    NonCanonical,
+    /// The `z` coordinate of a point is zero
+    ZeroProjection,
+    /// The `t1` and `t2` coordinates of a point do not match `u·v/z`
+    InconsistentCoordinates,
+    /// A point does not satisfy the curve equation
+    NotOnCurve,
+    /// The compact encoding of a key cannot be decoded
+    InvalidEncoding,
+}
+
+impl fmt::Display for InvalidArchive {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NonCanonical => write!(f, "non canonical field element"),
+            Self::ZeroProjection => write!(f, "zero z coordinate"),
+            Self::InconsistentCoordinates => {
+                write!(f, "inconsistent extended coordinates")
+            }
+            Self::NotOnCurve => write!(f, "point not on the curve"),
+            Self::InvalidEncoding => write!(f, "invalid compact encoding"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidArchive {}
+
+/// Checks the bytes of an archived point, and that they encode a valid point
+///
+/// # Safety
+///
+/// The pointer must be aligned and point to enough bytes to represent the
+/// archived point.
+pub(crate) unsafe fn check_point<C: ?Sized>(
+    value: *const Archived<JubJubExtended>,
+    context: &mut C,
+) -> Result<(), ErrorBox<dyn Error>> {
+    let p = check_field::<JubJubExtended, C>(value, context)?;
+    validate_point(&p).map_err(|e| ErrorBox::new(e) as ErrorBox<dyn Error>)
+}
+
+/// Checks the bytes of an archived scalar, and that they encode a reduced
+/// scalar
+///
+/// # Safety
+///
+/// The pointer must be aligned and point to enough bytes to represent the
+/// archived scalar.
+pub(crate) unsafe fn check_scalar<C: ?Sized>(
+    value: *const Archived<JubJubScalar>,
+    context: &mut C,
+) -> Result<(), ErrorBox<dyn Error>> {
+    let s = check_field::<JubJubScalar, C>(value, context)?;
+    validate_scalar(&s).map_err(|e| ErrorBox::new(e) as ErrorBox<dyn Error>)
+}
+
+unsafe fn check_field<T, C>(
+    value: *const T::Archived,
+    context: &mut C,
+) -> Result<T, ErrorBox<dyn Error>>
+where
+    T: Archive,
+    T::Archived: CheckBytes<C> + Deserialize<T, Infallible>,
+    C: ?Sized,
+{
+    let archived = T::Archived::check_bytes(value, context)
+        .map_err(|e| ErrorBox::new(e) as ErrorBox<dyn Error>)?;
+
+    match archived.deserialize(&mut Infallible) {
+        Ok(field) => Ok(field),
+        Err(e) => match e {},
+    }
+}
+
+/// The Montgomery limbs of a field element are reduced if and only if they
+/// survive a round trip through the canonical encoding
+fn is_canonical(s: &BlsScalar) -> bool {
+    <BlsScalar as Serializable<32>>::from_bytes(&s.to_bytes())
+        .map(|c| c == *s)
+        .unwrap_or(false)
+}
+
+fn validate_scalar(s: &JubJubScalar) -> Result<(), InvalidArchive> {
+    let canonical: Option<JubJubScalar> =
+        JubJubScalar::from_bytes(&s.to_bytes()).into();
+
+    match canonical == Some(*s) {
+        true => Ok(()),
+        false => Err(InvalidArchive::NonCanonical),
+    }
+}
+
+fn validate_point(p: &JubJubExtended) -> Result<(), InvalidArchive> {
+    let (u, v, z) = (p.get_u(), p.get_v(), p.get_z());
+    let (t1, t2) = (p.get_t1(), p.get_t2());
+
+    if ![u, v, z, t1, t2].iter().all(is_canonical) {
+        return Err(InvalidArchive::NonCanonical);
+    }
+
+    if z == BlsScalar::zero() {
+        return Err(InvalidArchive::ZeroProjection);
+    }
+
+    // T = t1·t2 = U·V/Z
+    if t1 * t2 * z != u * v {
+        return Err(InvalidArchive::InconsistentCoordinates);
+    }
+
+    // (V² - U²)·Z² = Z⁴ + d·U²·V²
+    let (uu, vv, zz) = (u.square(), v.square(), z.square());
+    if (vv - uu) * zz != zz.square() + EDWARDS_D * uu * vv {
+        return Err(InvalidArchive::NotOnCurve);
+    }
+
+    Ok(())
+}
+
+/// Archive wrapper storing a key with its [`Serializable`] encoding
+///
+/// The points of the default archived layout hold their five extended
+/// coordinates, so a [`PublicSpendKey`] or a [`StealthAddress`] takes 320
+/// bytes. Annotating a field with `#[with(Compact)]` archives it instead as
+/// the 64 bytes of its compressed encoding, at the cost of decompressing the
+/// points on access.
+///
+/// The compact form is validated by its `CheckBytes`. An archive accessed
+/// without checking may still hold an invalid encoding, so deserializing it
+/// fails with [`InvalidArchive::InvalidEncoding`], and requires a
+/// deserializer whose error converts from [`InvalidArchive`].
+#[derive(Debug)]
+pub struct Compact;
+
+/// Archived form of a key wrapped in [`Compact`]
+#[repr(transparent)]
+pub struct ArchivedCompact<T, const N: usize> {
+    bytes: [u8; N],
+    _key: PhantomData<T>,
+}
+
+impl<T, const N: usize> ArchivedCompact<T, N>
+where
+    T: Serializable<N>,
+{
+    /// Encodes a key in the compact layout
+    pub fn new(key: &T) -> Self {
+        Self {
+            bytes: key.to_bytes(),
+            _key: PhantomData,
+        }
+    }
+
+    /// Re-encodes a key archived with the default layout in the compact
+    /// layout
+    pub fn migrate(archived: &T::Archived) -> Self
+    where
+        T: Archive,
+        T::Archived: Deserialize<T, Infallible>,
+    {
+        match archived.deserialize(&mut Infallible) {
+            Ok(key) => Self::new(&key),
+            Err(e) => match e {},
+        }
+    }
+
+    /// Gets the encoding of the key
+    pub const fn as_bytes(&self) -> &[u8; N] {
+        &self.bytes
+    }
+
+    /// Decodes the key
+    pub fn decode(&self) -> Result<T, T::Error> {
+        T::from_bytes(&self.bytes)
+    }
+}
+
+impl<T, C, const N: usize> CheckBytes<C> for ArchivedCompact<T, N>
+where
+    T: Serializable<N>,
+    C: ?Sized,
+{
+    type Error = InvalidArchive;
+
+    unsafe fn check_bytes<'a>(
+        value: *const Self,
+        _: &mut C,
+    ) -> Result<&'a Self, Self::Error> {
+        let archived = &*value;
+
+        match archived.decode() {
+            Ok(_) => Ok(archived),
+            Err(_) => Err(InvalidArchive::InvalidEncoding),
+        }
+    }
+}
+
+macro_rules! impl_compact {
+    ($ty:ty, $size:literal) => {
+        impl ArchiveWith<$ty> for Compact {
+            type Archived = ArchivedCompact<$ty, $size>;
+            type Resolver = ();
+
+            unsafe fn resolve_with(
+                field: &$ty,
+                _: usize,
+                _: (),
+                out: *mut Self::Archived,
+            ) {
+                out.write(ArchivedCompact::new(field));
+            }
+        }
+
+        impl<S: Fallible + ?Sized> SerializeWith<$ty, S> for Compact {
+            fn serialize_with(_: &$ty, _: &mut S) -> Result<(), S::Error> {
+                Ok(())
+            }
+        }
+
+        impl<D> DeserializeWith<ArchivedCompact<$ty, $size>, $ty, D> for Compact
+        where
+            D: Fallible + ?Sized,
+            D::Error: From<InvalidArchive>,
+        {
+            fn deserialize_with(
+                field: &ArchivedCompact<$ty, $size>,
+                _: &mut D,
+            ) -> Result<$ty, D::Error> {
+                field
+                    .decode()
+                    .map_err(|_| InvalidArchive::InvalidEncoding.into())
+            }
+        }
+    };
+}
+
+impl_compact!(PublicKey, 32);
+impl_compact!(PublicSpendKey, 64);
+impl_compact!(StealthAddress, 64);
+
+/// Archive wrapper storing a [`KeyDerivation`] as the identifier of its
+/// [`ProtocolVersion`]
+///
+/// The identifier is validated by the `CheckBytes` of the archived type
+/// holding it. An archive accessed without checking may hold an unknown
+/// identifier, so deserializing it fails with
+/// [`InvalidArchive::InvalidEncoding`].
+pub(crate) struct VersionId;
+
+impl ArchiveWith<KeyDerivation> for VersionId {
+    type Archived = u8;
+    type Resolver = ();
+
+    unsafe fn resolve_with(
+        field: &KeyDerivation,
+        _: usize,
+        _: (),
+        out: *mut Self::Archived,
+    ) {
+        out.write(field.version().id());
+    }
+}
+
+impl<S: Fallible + ?Sized> SerializeWith<KeyDerivation, S> for VersionId {
+    fn serialize_with(_: &KeyDerivation, _: &mut S) -> Result<(), S::Error> {
+        Ok(())
+    }
+}
+
+impl<D> DeserializeWith<u8, KeyDerivation, D> for VersionId
+where
+    D: Fallible + ?Sized,
+    D::Error: From<InvalidArchive>,
+{
+    fn deserialize_with(
+        field: &u8,
+        _: &mut D,
+    ) -> Result<KeyDerivation, D::Error> {
+        match ProtocolVersion::from_id(*field) {
+            Some(version) => Ok(KeyDerivation::new(version)),
+            None => Err(InvalidArchive::InvalidEncoding.into()),
+        }
+    }
+}
@@ -0,0 +1,172 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use crate::{permutation, JubJubAffine, JubJubExtended, JubJubScalar};
+
+use dusk_jubjub::BlsScalar;
+use dusk_poseidon::sponge::truncated;
+
+/// Version of the key derivation protocol
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ProtocolVersion {
+    /// Original derivation, where the one-time stealth key is the bare
+    /// truncated hash of the shared secret
+    #[default]
+    Legacy,
+    /// Every derivation, including the one-time stealth key, is bound to a
+    /// domain tag and to the protocol version
+    V1,
+}
+
+impl ProtocolVersion {
+    /// Numeric identifier of the version
+    pub const fn id(&self) -> u8 {
+        match self {
+            Self::Legacy => 0,
+            Self::V1 => 1,
+        }
+    }
+
+    /// Gets the version matching the given identifier, if any
+    pub const fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(Self::Legacy),
+            1 => Some(Self::V1),
+            _ => None,
+        }
+    }
+}
+
+/// Purpose of a hash derived from a shared secret
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DerivationDomain {
+    /// Scalar `H(a·R)` of the one-time stealth key `PKr = H(a·R)·G + B`
+    StealthKey,
+    /// Short tag allowing a view key to discard foreign outputs early
+    ViewTag,
+    /// Key encrypting data attached to an output
+    EncryptionKey,
+    /// Tweak deriving a subaddress from a spend key
+    SubaddressTweak,
+}
+
+impl DerivationDomain {
+    /// Tag of the domain, as the ASCII encoding of a short name
+    const fn tag(&self) -> BlsScalar {
+        let tag = match self {
+            // "stealth"
+            Self::StealthKey => 0x0068_746c_6165_7473,
+            // "viewtag"
+            Self::ViewTag => 0x0067_6174_7765_6976,
+            // "encrypt"
+            Self::EncryptionKey => 0x0074_7079_7263_6e65,
+            // "subaddr"
+            Self::SubaddressTweak => 0x0072_6464_6162_7573,
+        };
+
+        BlsScalar::from_raw([tag, 0, 0, 0])
+    }
+}
+
+/// Domain separated hashing of shared secrets into scalars
+///
+/// Every purpose is bound to its own [`DerivationDomain`], so that the
+/// scalar computed for one purpose reveals nothing about the others. The
+/// [`ProtocolVersion`] is part of every hash: the [`Legacy`] version keeps
+/// deriving the one-time stealth keys as the bare truncated hash of the
+/// shared secret, so existing outputs are still recognized.
+///
+/// [`Legacy`]: ProtocolVersion::Legacy
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyDerivation {
+    version: ProtocolVersion,
+}
+
+impl KeyDerivation {
+    /// Derivation compatible with the outputs generated before the
+    /// introduction of the domain separation
+    pub const LEGACY: Self = Self::new(ProtocolVersion::Legacy);
+
+    /// Fully domain separated derivation
+    pub const V1: Self = Self::new(ProtocolVersion::V1);
+
+    /// Creates a new derivation for the given protocol version
+    pub const fn new(version: ProtocolVersion) -> Self {
+        Self { version }
+    }
+
+    /// Gets the protocol version
+    pub const fn version(&self) -> ProtocolVersion {
+        self.version
+    }
+
+    /// Hashes a point into a scalar for the given purpose
+    pub fn hash(
+        &self,
+        domain: DerivationDomain,
+        p: &JubJubExtended,
+    ) -> JubJubScalar {
+        if self.version == ProtocolVersion::Legacy
+            && domain == DerivationDomain::StealthKey
+        {
+            return permutation::hash(p);
+        }
+
+        let [u, v] = p.to_hash_inputs();
+        let version = BlsScalar::from(self.version.id() as u64);
+
+        truncated::hash(&[domain.tag(), version, u, v])
+    }
+
+    /// Hashes a point in affine coordinates into a scalar for the given
+    /// purpose, sparing the inversion when the point is already normalized
+    pub(crate) fn hash_affine(
+        &self,
+        domain: DerivationDomain,
+        p: &JubJubAffine,
+    ) -> JubJubScalar {
+        if self.version == ProtocolVersion::Legacy
+            && domain == DerivationDomain::StealthKey
+        {
+            return permutation::hash_affine(p);
+        }
+
+        let version = BlsScalar::from(self.version.id() as u64);
+
+        truncated::hash(&[domain.tag(), version, p.get_u(), p.get_v()])
+    }
+
+    /// Derives the scalar `H(a·R)` of a one-time stealth key from the shared
+    /// secret `a·R`
+    pub fn stealth_key(&self, shared: &JubJubExtended) -> JubJubScalar {
+        self.hash(DerivationDomain::StealthKey, shared)
+    }
+
+    /// Derives the scalar `H(a·R)` of a one-time stealth key from the
+    /// normalized shared secret `a·R`
+    pub(crate) fn stealth_key_affine(
+        &self,
+        shared: &JubJubAffine,
+    ) -> JubJubScalar {
+        self.hash_affine(DerivationDomain::StealthKey, shared)
+    }
+
+    /// Derives the view tag of an output from its shared secret
+    pub fn view_tag(&self, shared: &JubJubExtended) -> u8 {
+        self.hash(DerivationDomain::ViewTag, shared).to_bytes()[0]
+    }
+
+    /// Derives the key encrypting the data attached to an output from its
+    /// shared secret
+    pub fn encryption_key(&self, shared: &JubJubExtended) -> JubJubScalar {
+        self.hash(DerivationDomain::EncryptionKey, shared)
+    }
+
+    /// Derives the tweak of a subaddress from the given point
+    pub fn subaddress_tweak(&self, p: &JubJubExtended) -> JubJubScalar {
+        self.hash(DerivationDomain::SubaddressTweak, p)
+    }
+}
@@ -0,0 +1,103 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use core::fmt;
+
+use dusk_bytes::{BadLength, Error, InvalidChar};
+
+/// Error returned when decoding a key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyError {
+    /// The bytes are not the canonical encoding of a JubJub scalar
+    InvalidScalar,
+    /// The bytes do not encode a point of the JubJub curve
+    OffCurvePoint,
+    /// The point lies in the small order subgroup of the curve
+    SmallOrderPoint,
+    /// The encoding does not have the expected length
+    WrongLength {
+        /// Length of the given encoding
+        found: usize,
+        /// Expected length
+        expected: usize,
+    },
+    /// The encoding holds another type of key
+    WrongKeyType,
+    /// The key belongs to another network
+    WrongNetwork,
+    /// The checksum of the encoding does not match its content
+    BadChecksum,
+    /// The string is not a valid hex encoding
+    InvalidChar {
+        /// The invalid character
+        ch: char,
+        /// Index of the character in the string
+        index: usize,
+    },
+    /// Any other malformed encoding
+    InvalidData,
+}
+
+impl fmt::Display for KeyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidScalar => write!(f, "invalid scalar"),
+            Self::OffCurvePoint => write!(f, "point not on the curve"),
+            Self::SmallOrderPoint => write!(f, "point of small order"),
+            Self::WrongLength { found, expected } => {
+                write!(f, "wrong length {found}, expected {expected}")
+            }
+            Self::WrongKeyType => write!(f, "wrong key type"),
+            Self::WrongNetwork => write!(f, "wrong network"),
+            Self::BadChecksum => write!(f, "bad checksum"),
+            Self::InvalidChar { ch, index } => {
+                write!(f, "invalid character {ch:?} at index {index}")
+            }
+            Self::InvalidData => write!(f, "invalid data"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for KeyError {}
+
+impl BadLength for KeyError {
+    fn bad_length(found: usize, expected: usize) -> Self {
+        Self::WrongLength { found, expected }
+    }
+}
+
+impl InvalidChar for KeyError {
+    fn invalid_char(ch: char, index: usize) -> Self {
+        Self::InvalidChar { ch, index }
+    }
+}
+
+impl From<Error> for KeyError {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::BadLength { found, expected } => {
+                Self::WrongLength { found, expected }
+            }
+            Error::InvalidChar { ch, index } => Self::InvalidChar { ch, index },
+            Error::InvalidData => Self::InvalidData,
+        }
+    }
+}
+
+impl From<KeyError> for Error {
+    fn from(err: KeyError) -> Self {
+        match err {
+            KeyError::WrongLength { found, expected } => {
+                Self::BadLength { found, expected }
+            }
+            KeyError::InvalidChar { ch, index } => {
+                Self::InvalidChar { ch, index }
+            }
+            _ => Self::InvalidData,
+        }
+    }
+}
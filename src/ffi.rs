@@ -0,0 +1,269 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! C bindings of the key operations
+//!
+//! Every function reads and writes the [`Serializable`] encoding of the keys
+//! through pointers to fixed size buffers, whose sizes are exported as
+//! constants, and reports failures with a [`Status`] code. Output buffers
+//! are written only on success.
+//!
+//! The header `include/dusk_pki.h` is generated from this module with
+//! cbindgen, prefixing the constants and the types with `DUSK_PKI_` and
+//! `DuskPki`. The static and dynamic C libraries exporting the bindings are
+//! built by the `dusk-pki-ffi` crate in `ffi/`.
+
+use crate::{
+    JubJubScalar, KeyError, PublicSpendKey, SecretKey, SecretSpendKey,
+    StealthAddress, ViewKey,
+};
+
+use dusk_bytes::Serializable;
+use rand_chacha::rand_core::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+
+/// Size of the seed of a secret spend key
+pub const SEED_SIZE: usize = 32;
+/// Size of an encoded scalar
+pub const SCALAR_SIZE: usize = 32;
+/// Size of an encoded secret key
+pub const SECRET_KEY_SIZE: usize = 32;
+/// Size of an encoded secret spend key
+pub const SECRET_SPEND_KEY_SIZE: usize = 64;
+/// Size of an encoded public spend key
+pub const PUBLIC_SPEND_KEY_SIZE: usize = 64;
+/// Size of an encoded view key
+pub const VIEW_KEY_SIZE: usize = 64;
+/// Size of an encoded stealth address
+pub const STEALTH_ADDRESS_SIZE: usize = 64;
+
+/// Result of a call
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The call succeeded
+    Ok = 0,
+    /// A pointer argument is null
+    NullPointer = 1,
+    /// A scalar is not canonically encoded
+    InvalidScalar = 2,
+    /// A point is not on the curve
+    OffCurvePoint = 3,
+    /// A point is of small order
+    SmallOrderPoint = 4,
+    /// The encoding of a key is invalid
+    InvalidData = 5,
+}
+
+impl From<KeyError> for Status {
+    fn from(err: KeyError) -> Self {
+        match err {
+            KeyError::InvalidScalar => Self::InvalidScalar,
+            KeyError::OffCurvePoint => Self::OffCurvePoint,
+            KeyError::SmallOrderPoint => Self::SmallOrderPoint,
+            _ => Self::InvalidData,
+        }
+    }
+}
+
+/// Generates a secret spend key from a seed of `SEED_SIZE` bytes
+///
+/// The same seed always generates the same key.
+///
+/// # Safety
+///
+/// `seed` must point to `SEED_SIZE` readable bytes, and `ssk` to
+/// `SECRET_SPEND_KEY_SIZE` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn dusk_pki_ssk_from_seed(
+    seed: *const u8,
+    ssk: *mut u8,
+) -> Status {
+    call(|| {
+        let seed = *input::<SEED_SIZE>(seed)?;
+        let out = output::<SECRET_SPEND_KEY_SIZE>(ssk)?;
+
+        let mut rng = ChaCha20Rng::from_seed(seed);
+        *out = SecretSpendKey::random(&mut rng).to_bytes();
+
+        Ok(())
+    })
+}
+
+/// Reduces `2 * SCALAR_SIZE` uniformly random bytes into a scalar, suitable
+/// as the `r` of [`dusk_pki_gen_stealth_address`]
+///
+/// # Safety
+///
+/// `wide` must point to `2 * SCALAR_SIZE` readable bytes, and `scalar` to
+/// `SCALAR_SIZE` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn dusk_pki_scalar_from_wide(
+    wide: *const u8,
+    scalar: *mut u8,
+) -> Status {
+    call(|| {
+        let wide = input::<{ 2 * SCALAR_SIZE }>(wide)?;
+        let out = output::<SCALAR_SIZE>(scalar)?;
+
+        *out = JubJubScalar::from_bytes_wide(wide).to_bytes();
+
+        Ok(())
+    })
+}
+
+/// Derives the view key of a secret spend key
+///
+/// # Safety
+///
+/// `ssk` must point to `SECRET_SPEND_KEY_SIZE` readable bytes, and `vk` to
+/// `VIEW_KEY_SIZE` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn dusk_pki_view_key(
+    ssk: *const u8,
+    vk: *mut u8,
+) -> Status {
+    call(|| {
+        let ssk = decode::<SecretSpendKey, SECRET_SPEND_KEY_SIZE>(ssk)?;
+        let out = output::<VIEW_KEY_SIZE>(vk)?;
+
+        *out = ssk.view_key().to_bytes();
+
+        Ok(())
+    })
+}
+
+/// Derives the public spend key of a secret spend key
+///
+/// # Safety
+///
+/// `ssk` must point to `SECRET_SPEND_KEY_SIZE` readable bytes, and `psk` to
+/// `PUBLIC_SPEND_KEY_SIZE` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn dusk_pki_public_spend_key(
+    ssk: *const u8,
+    psk: *mut u8,
+) -> Status {
+    call(|| {
+        let ssk = decode::<SecretSpendKey, SECRET_SPEND_KEY_SIZE>(ssk)?;
+        let out = output::<PUBLIC_SPEND_KEY_SIZE>(psk)?;
+
+        *out = ssk.public_spend_key().to_bytes();
+
+        Ok(())
+    })
+}
+
+/// Generates the stealth address `PKr = H(A · r) · G + B` of a public spend
+/// key
+///
+/// # Safety
+///
+/// `psk` must point to `PUBLIC_SPEND_KEY_SIZE` readable bytes, `r` to
+/// `SCALAR_SIZE` readable bytes, and `sa` to `STEALTH_ADDRESS_SIZE` writable
+/// bytes.
+#[no_mangle]
+pub unsafe extern "C" fn dusk_pki_gen_stealth_address(
+    psk: *const u8,
+    r: *const u8,
+    sa: *mut u8,
+) -> Status {
+    call(|| {
+        let psk = decode::<PublicSpendKey, PUBLIC_SPEND_KEY_SIZE>(psk)?;
+        let r = input::<SCALAR_SIZE>(r)?;
+        let r = Option::from(JubJubScalar::from_bytes(r))
+            .ok_or(Status::InvalidScalar)?;
+        let out = output::<STEALTH_ADDRESS_SIZE>(sa)?;
+
+        *out = psk.gen_stealth_address(&r).to_bytes();
+
+        Ok(())
+    })
+}
+
+/// Checks whether a stealth address is owned by a view key
+///
+/// # Safety
+///
+/// `vk` must point to `VIEW_KEY_SIZE` readable bytes, `sa` to
+/// `STEALTH_ADDRESS_SIZE` readable bytes, and `owned` to a writable `bool`.
+#[no_mangle]
+pub unsafe extern "C" fn dusk_pki_owns(
+    vk: *const u8,
+    sa: *const u8,
+    owned: *mut bool,
+) -> Status {
+    call(|| {
+        let vk = decode::<ViewKey, VIEW_KEY_SIZE>(vk)?;
+        let sa = decode::<StealthAddress, STEALTH_ADDRESS_SIZE>(sa)?;
+        let owned = owned.as_mut().ok_or(Status::NullPointer)?;
+
+        *owned = vk.owns(&sa);
+
+        Ok(())
+    })
+}
+
+/// Derives the one-time secret key `sk_r = H(a · R) + b` of a stealth
+/// address
+///
+/// # Safety
+///
+/// `ssk` must point to `SECRET_SPEND_KEY_SIZE` readable bytes, `sa` to
+/// `STEALTH_ADDRESS_SIZE` readable bytes, and `sk_r` to `SECRET_KEY_SIZE`
+/// writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn dusk_pki_sk_r(
+    ssk: *const u8,
+    sa: *const u8,
+    sk_r: *mut u8,
+) -> Status {
+    call(|| {
+        let ssk = decode::<SecretSpendKey, SECRET_SPEND_KEY_SIZE>(ssk)?;
+        let sa = decode::<StealthAddress, STEALTH_ADDRESS_SIZE>(sa)?;
+        let out = output::<SECRET_KEY_SIZE>(sk_r)?;
+
+        *out = ssk.sk_r(&sa).to_bytes();
+
+        Ok(())
+    })
+}
+
+fn call(f: impl FnOnce() -> Result<(), Status>) -> Status {
+    match f() {
+        Ok(()) => Status::Ok,
+        Err(status) => status,
+    }
+}
+
+unsafe fn input<'a, const N: usize>(
+    ptr: *const u8,
+) -> Result<&'a [u8; N], Status> {
+    ptr.cast::<[u8; N]>().as_ref().ok_or(Status::NullPointer)
+}
+
+unsafe fn output<'a, const N: usize>(
+    ptr: *mut u8,
+) -> Result<&'a mut [u8; N], Status> {
+    ptr.cast::<[u8; N]>().as_mut().ok_or(Status::NullPointer)
+}
+
+unsafe fn decode<T, const N: usize>(ptr: *const u8) -> Result<T, Status>
+where
+    T: Serializable<N, Error = KeyError>,
+{
+    Ok(T::from_bytes(input::<N>(ptr)?)?)
+}
+
+// The sizes are spelled out for cbindgen, which cannot evaluate the
+// associated constants
+const _: () = {
+    assert!(SECRET_KEY_SIZE == SecretKey::SIZE);
+    assert!(SECRET_SPEND_KEY_SIZE == SecretSpendKey::SIZE);
+    assert!(PUBLIC_SPEND_KEY_SIZE == PublicSpendKey::SIZE);
+    assert!(VIEW_KEY_SIZE == ViewKey::SIZE);
+    assert!(STEALTH_ADDRESS_SIZE == StealthAddress::SIZE);
+};
@@ -0,0 +1,78 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Multiplication of the generator `G` by a secret scalar
+//!
+//! The scalar is split in 4-bit windows, and the table holds the multiples
+//! `d · 16^i · G` of every digit `d` of every window `i`, so the product is
+//! the sum of one entry per window: 63 mixed additions and no doubling,
+//! against the 252 doublings and additions of the generic ladder. The entry
+//! of every window is selected in constant time.
+//!
+//! The table is built at compile time from the coordinates in `table.rs`,
+//! generated and checked by `tests/fixed_base.rs`.
+
+use crate::{JubJubExtended, JubJubScalar};
+
+use dusk_jubjub::{AffineNielsPoint, BlsScalar, JubJubAffine};
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+
+mod table;
+
+/// Number of bits of a window
+const WINDOW_BITS: usize = 4;
+
+/// Number of digits of a window
+const DIGITS: usize = 1 << WINDOW_BITS;
+
+/// Number of windows, covering the 252 bits of a scalar
+const WINDOWS: usize = 63;
+
+static TABLE: [[AffineNielsPoint; DIGITS]; WINDOWS] = build(&table::MULTIPLES);
+
+/// Builds the table from the affine coordinates of the non-zero multiples
+const fn build(
+    multiples: &[[[u64; 4]; 2]; WINDOWS * (DIGITS - 1)],
+) -> [[AffineNielsPoint; DIGITS]; WINDOWS] {
+    let mut table = [[AffineNielsPoint::identity(); DIGITS]; WINDOWS];
+
+    let mut i = 0;
+    while i < WINDOWS {
+        let mut d = 1;
+        while d < DIGITS {
+            let [u, v] = multiples[i * (DIGITS - 1) + d - 1];
+            let p = JubJubAffine::from_raw_unchecked(
+                BlsScalar::from_raw(u),
+                BlsScalar::from_raw(v),
+            );
+            table[i][d] = p.to_niels();
+            d += 1;
+        }
+        i += 1;
+    }
+
+    table
+}
+
+/// Computes `s · G`
+pub(crate) fn mul_generator(s: &JubJubScalar) -> JubJubExtended {
+    let bytes = s.to_bytes();
+
+    let mut acc = JubJubExtended::identity();
+
+    TABLE.iter().enumerate().for_each(|(i, window)| {
+        let digit = (bytes[i / 2] >> (WINDOW_BITS * (i % 2))) & 0x0f;
+
+        let mut entry = AffineNielsPoint::identity();
+        window.iter().enumerate().for_each(|(d, p)| {
+            entry.conditional_assign(p, digit.ct_eq(&(d as u8)));
+        });
+
+        acc += entry;
+    });
+
+    acc
+}
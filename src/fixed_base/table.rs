@@ -0,0 +1,961 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Affine coordinates `[u, v]` of the multiples `d · 16^i · G`, for every
+//! window `i` and non-zero digit `d`
+//!
+//! Generated by `tests/fixed_base.rs`, do not edit.
+
+// Only read when building the table at compile time
+#[allow(clippy::large_const_arrays)]
+#[rustfmt::skip]
+pub(super) const MULTIPLES: [[[u64; 4]; 2]; 945] = [
+    [[0x4df7b7ffec7beaca, 0x2e3ebb21fd6c54ed, 0xf1fbf02d0fd6cce6, 0x3fd2814c43ac65a6], [0x0000000000000012, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]],
+    [[0x80eee554cba976b4, 0xcaf3ad858ea04c0c, 0x8654a8c770137476, 0x3406866c17fc106f], [0x5ff2d4023d8c2e5f, 0xf59f8a8c277fdbe7, 0xacd365a062345c7b, 0x477abd3191d9c67e]],
+    [[0x6ff29ccfc8721673, 0x8c6bd932e3288425, 0xf3ab723f0a0c86d1, 0x4e7d425467582402], [0x4cdcfa0fb390ea65, 0xffa2cfcbb64ca56d, 0x6e870dd6f278bae1, 0x120a54568c366964]],
+    [[0x0cb2050058618cf3, 0x9e8eacc6eaec84e7, 0x3a623f7b4927ea8b, 0x5c04fb190826f2c4], [0xf4b633c64622faa5, 0x9aeba1cf6db2c547, 0x3ee41cc1d9710d89, 0x6193d76c552fa2b7]],
+    [[0xbd5fd1e9219ec531, 0xa1e65965cacb65dd, 0x866da36a2483210e, 0x247e39c4befa9263], [0xf3b953b148bd62d1, 0x1f192911bbea4ceb, 0x2abe79f6d597ed15, 0x6332c7decb0a3db5]],
+    [[0x74e15f48954bee49, 0xdbc599df4a23bed9, 0x21fca588d6d4182f, 0x002160769e6465d3], [0x394a6b3ccfc0980a, 0xdddfe9a168c04a9b, 0x8ff99f12927785ee, 0x1aac94899aa2b76e]],
+    [[0x7ff490adb40ba97d, 0x2a32b8ed8d642f73, 0xd7a2c9554d1e9a18, 0x5be1df023c2e9ebc], [0xcf055597585b43c9, 0x162678c9a2a59420, 0x22950cabc4638536, 0x69626e011cd0ef5d]],
+    [[0xf7d457cd47928d7d, 0x3536e4483edc880b, 0xc90545deb13d03ae, 0x4ed30131f493d0c0], [0xf824758eb7f3e7c0, 0xf891212b51e9da5f, 0x9fa61985dce763a4, 0x28dbd377c5faa8a8]],
+    [[0xc07af795400d670b, 0x7cad329e6dce6fa1, 0x56e90adb981500b4, 0x6e0541e4b2e86607], [0x4a4d1285f8ad1cb8, 0x4aae7a6bb4c2f140, 0x564312ba5608d965, 0x06715c937bdb7102]],
+    [[0x4b0f7f5ea57e6ef4, 0xf8662555b1f89da5, 0x2c868a352200027e, 0x6a784659e6fee781], [0xc01e4758dc15b09a, 0x59dab72205cac0b8, 0x22f2c8ae8f3fb33e, 0x41ef60cb4e9d01d2]],
+    [[0x34ba93431a6eba68, 0xf78f771c3c4bd4e3, 0x7ced6c92390ff4a9, 0x5bb48827b74a5e8f], [0xb338d21964e3f893, 0xfc1ec462f66ae4fa, 0x021301b5afd75f2b, 0x1406865ae8a79ec9]],
+    [[0x960dc530436a969e, 0x47346275e056987b, 0x060c4b09997c9b8d, 0x17a7bf5665f60ff1], [0x2555ca3db50e6005, 0x2825ca48676f90da, 0x60b0416ad723b07d, 0x4db10308efa250ed]],
+    [[0x8b4d76e3fd0bd074, 0xc7d6bbda8db041a6, 0xc1f852be36c4b070, 0x025fdc2947544cbd], [0x440095ae0704b44e, 0x20de5b13839b7302, 0x61467f10a757da71, 0x43511a89c12a7220]],
+    [[0x0754cd1003e9ba99, 0x7c2d484f2927d0a1, 0x24fec08edd49a62d, 0x0f0fb4dcf5ea8896], [0xc1ec24c428b1eece, 0x2c797faaa3899481, 0xa4b2e9de1290424e, 0x285125e5d52b4afe]],
+    [[0x9c3723f6e43819b2, 0x07a85503f74deacc, 0x9a391350a9d223fb, 0x4cbfb40934dbf8b3], [0x5d642c2c9e4eaf90, 0xdbe0441ddecc28a9, 0xd5a4fd6b2f18a159, 0x677f0eb7121619f7]],
+    [[0xdb7d6e7a3ca69f0f, 0x73d9e7c76e6afe97, 0x2f65f22b1f8a21c4, 0x6202543641992cec], [0x8ad8da0687c4c817, 0x5046c37500269b79, 0x3372f184ac4d10a3, 0x45228ae39c2cdb02]],
+    [[0xe135587df3035404, 0x295310288474a1f8, 0x98445eae625e4c67, 0x33740a9ada82dd6e], [0x195ad85e40f1a439, 0xbb13cd0d85eeecb8, 0xd1202cf9a5a1735b, 0x1852d62e78668a32]],
+    [[0xea8f359de359ffc7, 0x9925f3c0fb17c85a, 0x80c425e2206b0503, 0x03676bb9aba78b8b], [0xa449ac3596c36874, 0x00607bfd97dc5cd0, 0xf821c986741c6558, 0x249fc5b714fa2ce0]],
+    [[0x5a0554b3d06a40b7, 0x39220e2b3421dfb5, 0x0aa91bd145f86a7c, 0x206df6f6f868100c], [0xb717e1835fd248f3, 0xa29d5c8a11463248, 0x930fe8fca6a5c65f, 0x467eb9018e178649]],
+    [[0xf269674db13dcdee, 0x5b76cb8f2b83d0d4, 0x529b2bb6bccc1bbc, 0x70f18d74825a9dab], [0x32d82c335915255c, 0x256f9ef96bb6a835, 0xd21c5171e7f102c0, 0x2fdf3b74403f2e43]],
+    [[0xff6a5a0fb4f6fad5, 0x91c639c35073b330, 0x5cbbb932083d44c7, 0x045b8aeb560fa315], [0x071f0b68b5c0495f, 0x5886bb371ea97fec, 0x3457cc028ccfd57e, 0x085eaf8a798c5fc0]],
+    [[0x6bf58d9f27a8642f, 0x2890496cbbedcb09, 0x3a51d60406b4252b, 0x69a0e77c6041196c], [0x755511035ea6b8f4, 0xf14d413b2996b5e8, 0xfabe53dd3d32d0b4, 0x58e026c002794e3d]],
+    [[0xff5c06ec69800c63, 0x159cd9c293f24a09, 0xe94019f12f4a50f1, 0x613f291faa6f257d], [0x41886a12f3a58653, 0x9b1684599bf996d9, 0xa381166289c1bed4, 0x3bb61ebe411fc9c4]],
+    [[0x1b4f6650b1fd3d1a, 0xc067211822c3bc92, 0x844bf3e1abebc011, 0x62b5d0dad123c6f6], [0xf1f496f1c25a29b3, 0x5f4b3570a0f4d0bc, 0x46216492103b77b1, 0x6f33b41982c03131]],
+    [[0x55eac7da5594579d, 0x91f1a1cd1f6a7f80, 0x98facafa617c80d3, 0x5d77396aa8cb1496], [0x4ee62c8a4bc45708, 0xf814bc4821f47451, 0x650e91b1a16a2e6f, 0x22d622d74c3829b1]],
+    [[0x08bbb254ca06ccfb, 0x5571f44ba19a3c79, 0xa855756a08fc7ed2, 0x5458563e3e019205], [0xf2f6c78f36235a09, 0x45f5bc9c34a8c2d7, 0xefa1c8494348ebe7, 0x10d7804c66f1257c]],
+    [[0x810fab79809339b8, 0x5ab8cc024dcb5ef4, 0xa8e010d78ad82df0, 0x6e6f05cd379f556a], [0x18e3b0096b2cd9d1, 0x1c53e34626cfb714, 0xf6439ce899a5290b, 0x6f416f8c7e3291fb]],
+    [[0x85ec9c5567f2052b, 0x8718149da56a55c6, 0x8b122023e700d0f7, 0x5ffc0e623c10a32b], [0x98d28c975c17aa31, 0x1a9497ee80613d3b, 0x097c1222da2be4ba, 0x11d0f1755f3ffbc8]],
+    [[0x03f7a3504c332903, 0x163963346dc4c9b4, 0x6eb5e5f84110d0a3, 0x397d7bfe3cf73692], [0x5cc535648c82a058, 0x86c631aaf233b544, 0xd18d80ba5618c55a, 0x343dec5a23015b23]],
+    [[0x4d0d7cba598f3b0a, 0x45df1389c17ffe97, 0x5413189696023362, 0x1838ad499df1bef9], [0x7b4273a9c79216e2, 0x9c5edf29029708ca, 0xce974879423b2ed4, 0x21bbe8f2828d7d42]],
+    [[0x572030f31c51879f, 0xfa111e36bbc25f9d, 0x7c46d4a6744c836e, 0x043a4c5c8fe9ba6c], [0x0372cbce40bf32f8, 0x220d49a34e07cac5, 0x6585af5fcc34b3ee, 0x1ba873886ec93657]],
+    [[0x84280933760f2e68, 0xd186f209504d0262, 0xc8101c3edd547fa8, 0x3862007078ee358f], [0xafefe986b3d070c0, 0xdb73b3a3fdf013aa, 0xa2182d6464293b40, 0x3d2506f723bef3dc]],
+    [[0x644dd7f998f1815a, 0x396b3735e5fa21b4, 0x29006e7d4252141e, 0x21b4dbe7c0afc8ca], [0x3a5e134a4b271e40, 0x6d9f271470d3516b, 0xb28134dc58963662, 0x58a2f9d620f73678]],
+    [[0x1afa459d63222512, 0xb1feae47218e8b7f, 0x77004e007e24fa1b, 0x09d1fe87702cc608], [0x7f5da2af75020e9b, 0xe1019d1b236a669a, 0x8cb9498c11177505, 0x639bdb1f31576880]],
+    [[0x81015693517a16d2, 0x4255256f6a0784cf, 0xcd53c5437f731289, 0x495e3624cf9bddd5], [0xb278d7750bf0cce4, 0xaa8f7715a4b54ca0, 0x175c907f0eb5490e, 0x73405a26a9fa4554]],
+    [[0xf21f8e445c35fc7b, 0xad9cf48b55b77d37, 0x242bdab141728301, 0x00efc98f0f6abbef], [0x667ad099271797e9, 0x43c89118041013a1, 0xdf3599e2ec9d5478, 0x311bb80c72b0e7a9]],
+    [[0x312b3c5a429e905f, 0xc8e0f9b1b11e2eaf, 0x4687a213da6908c8, 0x3ed90eed41a14675], [0xdc0994b8b8ac6ecb, 0x67bd83235ce6d40c, 0x736c7a4b7e6ae40a, 0x6f1d10526a620824]],
+    [[0x6b5be4384a15371b, 0xe1b1b191cfdf0140, 0x48b25bbba06815d2, 0x297cfd30d8a4c09a], [0x007018f22e9ff2a9, 0x3c1f4b772bd1abd9, 0xc4d1af8d283cd458, 0x261633fa57e883ed]],
+    [[0xa7beb8fb3472a4a1, 0xbcf0bc8533397811, 0x52c4190052cbd521, 0x63248c46b489a8fd], [0x1cf1dffc46eb872a, 0x36ba602bed5fd684, 0xf8834ce89319bf18, 0x1551c15659b962e1]],
+    [[0x6348cd12a4be1594, 0x6249a1e8af1f2b3c, 0x83cd02656b492bb9, 0x05ffc6412689c410], [0x07308cfc69fc2cd7, 0xbca4fe3946e6a529, 0x624da289d50104c2, 0x2bb6384c9894030d]],
+    [[0xd7a3461f3075ff06, 0xddae1e664925aa32, 0xb953b349cc6921cf, 0x192e1b5340b120e9], [0x0de1fd9a459012fb, 0x99ffdbe1d33a2904, 0xb7e061731364404f, 0x19eee9b0420bb922]],
+    [[0xf7defcfc30f5ae19, 0xecf02acf17cf6262, 0x6c2a9398a3c57958, 0x2e9e5f66555a5721], [0x3c79f6a18ef8cb90, 0x599cc207495329df, 0xd23a453d2155d3e9, 0x2ab5b276b3e9f668]],
+    [[0xfdb397ceccd9929c, 0x45ccec210e6d0945, 0x5840d5ee26919530, 0x6cf50f865f536b38], [0x7755a6086ff2034b, 0xc0fef2906f968d7a, 0x01b0d75a91a8777a, 0x5f0074785c6ac6b7]],
+    [[0x5b3a81a23457f106, 0x2ea28a4861715469, 0xcb7d6701c305c8d0, 0x6d39fad6c0cbbd60], [0xfc4e76fd45ac04f4, 0x115d8a2c70e69e78, 0xb68f0a9386fea453, 0x3642bc9d6ffc2be0]],
+    [[0x54c91d342bfa80ee, 0x8eb5f2052678bcc0, 0xa772373f3b693737, 0x06f09427646ab35f], [0xde556b2eb2c2eccc, 0xa22b149b133a6839, 0xe196a796ee1137ed, 0x4d1b505f1a29adf2]],
+    [[0x6e6adce5c6dcc453, 0xb65f7ce77b6b38e9, 0x0b9eda0178ad9719, 0x07b6747a69418c00], [0xcf7c0b71de6b8766, 0x1ff334bff52020c3, 0x23f02eee99b2a668, 0x426c05e5ea013edb]],
+    [[0x6794d4ef7a03aecb, 0xd2afc423b35c0ffe, 0x2f24cdb3c570ffd4, 0x5e6a9767759b466d], [0xa4c7fe31f2bd763b, 0x2a2a10bb54b4c2e2, 0x65a7219249fdf8d2, 0x0937c576845a7759]],
+    [[0xa48434d8d39540aa, 0xf7207a5d52110da3, 0x9fcd82060f8e3bf3, 0x694873fdafb4e6c7], [0xfc2b77d469c2b228, 0xdbc9ca2894cc825b, 0x5ef1729a511d330b, 0x3970061c19af3112]],
+    [[0xadfec520ba2ac006, 0xe7c6e6503eae6d80, 0x8dfe1246825ee9d5, 0x6cd7668e031df023], [0x2e12e534778b197c, 0x1e20c55157cc2fe4, 0xfa84c1ababc3b2b3, 0x72744eed3cbb6d50]],
+    [[0x29698176ecb7cee1, 0x954ff9dbbeef4b25, 0x3a609f8fc6caca53, 0x52ea8bb2f032403f], [0x9619f346c6cf1575, 0x7d298294ea6f4d84, 0x094e0050fe7bde74, 0x0316f15d3abb690e]],
+    [[0xd7978aa0c3ae270d, 0x80393d3e521ce5bf, 0x4bf4905ee7b6391e, 0x421d09a18d2c0896], [0x3c07788d57f5ecc4, 0xe43333cd9508ad4e, 0xe62812cb863a1741, 0x29c7f6404bb83bf5]],
+    [[0xb46d116a41abd354, 0x16bbc4b23886dd75, 0x5e588d87abf13b16, 0x573ac09b5d09ba4e], [0x8b38f20182cc207a, 0xb2da725c4746f0cf, 0xbfa7362c6402a103, 0x19b16f275ddb2be4]],
+    [[0x10010eabe6ae1467, 0xcae8d33d21896899, 0xd8dbaf9521ea6584, 0x55e4bfe8d1cc8479], [0x52d1b13a39bfeb71, 0xd279734d3db77eac, 0x3236edad804a9ed4, 0x277c6db9c4ea9508]],
+    [[0xb92be9deb42c841d, 0xdd3d72bcfd86f3fa, 0xdba2a2573d7882b6, 0x30829bbed5a56fa8], [0x52749a285735707b, 0x946b8ab89c87f1a2, 0xd57de932166aa668, 0x284d0f3fa0476391]],
+    [[0xe12952daa07d239a, 0x71fe4dfc0c8f6dac, 0x7764aa65182fd58c, 0x383c96b7063e9da8], [0x25f1cd9b02da7f44, 0x579b42ed96d0b267, 0x12d665c49dc7cf2b, 0x0c63fdabaabf571d]],
+    [[0x0868134541572216, 0x61e29c0684c5733c, 0xf333b331920e8f4c, 0x1670bb13f6e6a619], [0xff33458fb1e011d6, 0x0cd4fffd95b642a9, 0x6e9fe492c471caee, 0x291e08bec09d23a0]],
+    [[0x57c1cc2ad5ffe3c5, 0x62d273827ebd7554, 0xae5415cf1b6c0b6e, 0x29a37da1d9558890], [0x5c954e1c1c5be135, 0x07bd02ebfca9ec6a, 0xac54445f99890c1b, 0x65acddd63caeb41b]],
+    [[0xca9cfa1ac7867ede, 0xa2a2492cc72d0b54, 0x8bd5eb0ce57b6fbc, 0x6aa609cf82f44592], [0x05eb2cce495f92e3, 0xa7af3dc0f5d4e680, 0x9887505609ed88e9, 0x5275ccefaeac0f6c]],
+    [[0xef726d342c312443, 0xba12016ee6b4bbb0, 0xbda7825aa472fbfb, 0x44fb2f93c7162695], [0x3ac894efabe9be21, 0xdd40a5515778be87, 0x52a706f42c7f566e, 0x29c97f737e5a24a1]],
+    [[0x7e71d192e9fa9bbc, 0x507d1f43f1201760, 0x724b916bd11abaa8, 0x0fe6305b1ba14679], [0xd85ca90d11686c71, 0x3887dcc0260b47ec, 0xf772f6d9b3053040, 0x24311deee91bb055]],
+    [[0x5732946513c9b409, 0x6f6877cd96d7e108, 0x77089ede78e384f5, 0x2d7c464dea5c00eb], [0x036e0a0866a09e19, 0xcdc05de9181b5796, 0x12d01f3104682ade, 0x6938fbd8c898c713]],
+    [[0xdf8045553ee99693, 0x9c06e2dcf467f695, 0xc3bad48e948a914f, 0x2244f2a712079ebd], [0x5a409a11fda8f5b1, 0x60cb7027fdc241a1, 0xde177306f7d15a36, 0x0c2b5b61206b0253]],
+    [[0x951e6f905bb6a785, 0xf2e7214b5ed9e9fb, 0xee52e0f7f069312b, 0x36a8295300de4035], [0x6a1fcbe50ff7a6de, 0xbd303a55a5a0edaf, 0x2a02c6054617d373, 0x644b3a0df81fe0bf]],
+    [[0x65740c99d512b802, 0xc18e23acb0f13972, 0x9521a1e5e3415ef3, 0x2a92467066b97f66], [0x38e6c0b5d2413cbb, 0xa55c3a7a37eafc26, 0x93205adc7d04d60a, 0x43fe3c555e555714]],
+    [[0xb1f4a809bb20485c, 0xa33d84bf400150d6, 0x0e34ffae549c11c1, 0x337c3c1ff92d8274], [0x93ac89665b41a972, 0x9c2cbaed7cae48e5, 0xd77d10ba02949bd8, 0x06e03953ae03a5aa]],
+    [[0x275e843928090fd2, 0x92d39b7babae9d4b, 0xe14171d0be9f6fa2, 0x33dd9ef785a97274], [0x3135a7b2c9db1367, 0xd8ee7cc6d53a1bcd, 0x7bacfa978c6192eb, 0x3a37ee258263b2b7]],
+    [[0x361275550cb5053a, 0xe42634b6a20240a8, 0xc1a572ba3af23b7a, 0x5b64ce907c11adb0], [0x05fdf81bb6b6a82a, 0x8089d4daf66dd678, 0x23cb09b744bfbfaa, 0x1edd5cbdf9f308af]],
+    [[0x0f76310268ff203a, 0xb615a000095968d6, 0x9e5468354a48d030, 0x0b176d418c97dc0f], [0xbf3368a9f41962fe, 0xd3f5329f42eeb3e8, 0xcdfdb1a1473ec017, 0x04494cba81ce67ab]],
+    [[0x601775cad2c1e388, 0x4cf8a3af9ca6d11e, 0x704cd215fb521674, 0x30275668073b7df6], [0x74d483b6feee009c, 0x6e2139cef4e7c26e, 0xe412950c9b6e909f, 0x4a8ffb6481ddd5b6]],
+    [[0xb2709346cdb6df1e, 0xd3dd5ac818f4dcd4, 0x3a0ca61ea02d4929, 0x090b71d160af24a1], [0x6c149045a87d0004, 0x6ffb85bad01f88a1, 0xdae55500666aee06, 0x4ae85449c801e661]],
+    [[0x62304130ab7186c7, 0x205f1480fba00427, 0xefd1fa3be199934e, 0x198f9ce575cb5a7e], [0xbfc2e3298510c1e8, 0x17c260d9ca273dbb, 0x0583155f5bb45ff1, 0x1f2b80bd39d10a05]],
+    [[0xb0f45201d862ebde, 0x3ff940dfc39d42f0, 0x5ea3b46f5f869f3d, 0x625e00cace2d4225], [0xb6f13393c5c458c9, 0xb08dd8d44de576f0, 0x0618414f3c2e688b, 0x173363fd4b00da63]],
+    [[0x315331ce43a81d0a, 0xcceeec32c570c943, 0x129bf9766af4a97f, 0x57c87c98e91526b5], [0x9b7a327730b50171, 0x178cce9a28a86011, 0x8d9d39c6b5048876, 0x6ac7aa53a7b1d395]],
+    [[0x4218bdc5e5323974, 0xdaf4116702697a7a, 0x03c31b70a9db6d6a, 0x20ac09c2c3039a98], [0xff2f4de7f4a17b73, 0xbeac70fdae263cf3, 0x1ed6126959c09f5b, 0x3e3145c39d08f034]],
+    [[0xb71974fdd8d720e2, 0x374807e687b82000, 0x3e2d45f110b7910d, 0x0d46f06a6b977893], [0xe4ad5ef8498ebdf9, 0x246fab7955341033, 0x32541ecfabd36385, 0x0415f7f3ab426e0c]],
+    [[0xa5c52680856449dd, 0x03b5cbb4073b8edf, 0x031148464104491f, 0x35a1d29d30839695], [0x43af21076af96f25, 0xc9aebfadcf305ee8, 0xa0a4a0f3cba004c1, 0x5af9c9775895c400]],
+    [[0x15c8d9c9c1a124bf, 0x0309711246d3106c, 0x0c275018271d9a68, 0x5c4cfd4a4cbd34bc], [0xd855342373782d8e, 0x443f7474719b0a90, 0x9ab130aa90689c10, 0x26c4e80a01e6630e]],
+    [[0x39f6237add58061c, 0xa19df23e0f4b8d0b, 0xe23e2d7d8fc37917, 0x6618d6a9bdc12b49], [0x11b353cc39072a86, 0x7a524a22b9dfc9f6, 0x01d83f5bc1d319c3, 0x36a66020fbfaa268]],
+    [[0x3d3df1d28f8747ff, 0xca38a66aa399a02d, 0x580dd691e674b954, 0x49888cb853760ecc], [0x239db78dc53ae70a, 0x30db00b1370c3129, 0x4865132578355b98, 0x13a48448e818fb67]],
+    [[0xa67a1ea406eaca05, 0xe016dc6434ae84f8, 0xca17e6c89a5431e8, 0x203d01763caad1eb], [0x2391880d2f0d02b5, 0xb0e2c545d5a1c127, 0x0a7ef8be8f2d78f1, 0x5faa65e8f4e58c58]],
+    [[0x7075d776afe7ccc5, 0xc9ce455647220cf0, 0x66b0e90108808924, 0x135604aa025a8218], [0x1ebdb331a5b68cf9, 0xa6d5fb821293aac6, 0x248a3da2ce1d1ba4, 0x429e8edd26aaf38f]],
+    [[0x3b3c48e7d1a4041e, 0xd17e099fdad268de, 0x015cb6369e9fa25d, 0x646f381094b2ae62], [0x5c9b6b9b9ec915d5, 0xc08afc27785ef4a5, 0x46afa7c996224632, 0x1e52ee0d7a446ae3]],
+    [[0x48ed71ae51d0fa09, 0xfbce1dc0963954f7, 0xf4561e65279c3f79, 0x250da2247b7021c5], [0x8846240ebee2c4a3, 0xbf02ab2bf29c61b1, 0x5358c9b1e604ada7, 0x09aab49b79a46a6c]],
+    [[0x7835b58c46873a8d, 0x89f19d296f8e0283, 0x1d090fe265223910, 0x193c2b58d4f5ae0c], [0xd40cdca29477dd9a, 0x90bbf964f4f2fd12, 0x2d37f6f95f6bda15, 0x4a92d8d977f706f8]],
+    [[0x7125e910a0332523, 0x8f4f78f9a364beea, 0xd132d41ffbe796b8, 0x679c2a6cecf68e45], [0xd13ce0fb7e4eadca, 0x37e3a4b88e880d44, 0x008996031786f7ef, 0x36bef3774d538faf]],
+    [[0x15a6bc302ba0a4e2, 0xb77bff19d05db9bc, 0x720bb26b55119931, 0x5149c9e96072d21f], [0x022bc59412e182ce, 0x15242f3381e736c7, 0x72b7d5179c5794ca, 0x0ddf95efc11492c6]],
+    [[0x37ab68205318bb4c, 0x1772344788ecf620, 0x4ac3bfc4f0257b81, 0x6545fd14ff28a334], [0xfc0b436feee223e9, 0x493de62bfe4b0115, 0x852cb882a7053a88, 0x6ff4cfacf7e0c9b6]],
+    [[0x3d32380b97afca3a, 0xfdfc6fc8d4b17c9e, 0xb70cbe3324a67270, 0x6f388fa480eb8711], [0xcbb0fd17082a6120, 0x5f30000ce9992f49, 0x4d8a097b9bf0daff, 0x155f7e77aa0daef6]],
+    [[0xe6cc7ef78c044a5c, 0x75bf52c18aeeb6f7, 0x6ca03ada1c7876fd, 0x60ea3597d2fa923f], [0x622ae46a48e8f3da, 0xd958405632585b37, 0xebca068d16d75ef2, 0x6e2340629fef79a2]],
+    [[0x246a0467c407cc9f, 0x15744c4f3f584e2e, 0x4ffcca4bd88f5f29, 0x3a1b1693d94f38c1], [0xa60020ec62077679, 0xab67064ef43ea3ac, 0x6852e4562f95adcc, 0x39a73761a3ac53fd]],
+    [[0xa246abe49ef8e9d5, 0x22f973ed4a2601a2, 0x91a73f1a8634510b, 0x33a215f3782ecc85], [0xa3a6469c1def2f9e, 0xae5bfd28834cb469, 0xc3ad4b25eead6813, 0x3680d7e9774690da]],
+    [[0xc17cf296c91cc232, 0xf0ec66d4dcec2574, 0x762bc993f41216fb, 0x623334235c112c78], [0x4ba61d8852846ac0, 0xc4826ee1e05182e8, 0x3de897d4731fc0e3, 0x31e393ed415e4d4e]],
+    [[0x3bfc831f0714737a, 0x1bba91faff14e184, 0xd4cd61789b58540c, 0x25fe5b801553b457], [0x60f4d95caddf1fda, 0xbeb58c57c142105c, 0x459f9aacbc91fb24, 0x0ce29300634e8414]],
+    [[0xb2805f87381ac10d, 0x5e3a11b5286b6249, 0x8cc5ad80275fc790, 0x44c71ca8c94d6187], [0x6286b0a44149e6c1, 0x6b742ac538073138, 0xd8738ed1746bfdd4, 0x2eaf437fbbf3424f]],
+    [[0x81474bae9ec3fe78, 0xece01872540dfef6, 0xd7511a52ab049710, 0x338e3089ebfca945], [0x688e1accc8bc82be, 0x0a41329d67d65c1c, 0x92e829df6518d47b, 0x0848125cb055d730]],
+    [[0xc8dd1edfc649eb3a, 0x371bbc4e284d0327, 0x9a62ffc73c7b650e, 0x598578f8062c292c], [0x1e20901194309257, 0x33aa55795c5191fe, 0xa4da9e32e38a6c5d, 0x6398dc43aac68422]],
+    [[0xb893c7197778a58b, 0x7fd6d6c166bc031c, 0x10e6f365c70ea55f, 0x72753f6834e8fb9c], [0xf07c95bea8fb1efa, 0xf8944c6db2e72cbd, 0x14b59c357b02ffb7, 0x4cd23c316186835d]],
+    [[0x83ef2ae529c55165, 0x131830048393acd6, 0xbf81bca0f1813d1e, 0x5ffa9cf9e735ddc2], [0x0212e36766b27c8e, 0x1ac981df320ff0da, 0x3365c7b044462589, 0x64d95f5cd0a4b904]],
+    [[0x3bb39b609161fbdd, 0x7d531ec333beb384, 0x5cd16dfedbccee1c, 0x718916b7b953d352], [0xb3cfa391ff26e8c3, 0x9bb814c0b06d51bb, 0x1bdfaec6aac889f6, 0x1582403e8dc47588]],
+    [[0x24ab2883213c77fa, 0x63b2d40b420b18a6, 0x4b62887068fe6b96, 0x35a5153caaac0cc0], [0x284b50fde53b1ec1, 0x63a7d71876732565, 0x70d8e4397788bebc, 0x5c1542ad2b3afee2]],
+    [[0x2eae8cf27fd5fb80, 0x3f8192b496434208, 0xa56cce39325ed741, 0x117a3b7d98304094], [0xe2dbc089aa0279e4, 0xfcfeaebfcda8e037, 0xb2184c8fd963fb39, 0x32d34ec164e650eb]],
+    [[0x25ee9857ea5094bc, 0xcff55b2280a18c1d, 0x6b082f5b88775d5d, 0x161995ec62f49000], [0xef9ae8739cb17e99, 0x95d2d179730b22ba, 0x5dbf0215791ec656, 0x5902eef2f8873f03]],
+    [[0xc377cfcfc1f27fc3, 0xb1a569a5ddd13f17, 0x7697601318254111, 0x73985a8640910798], [0x34feb6f2680f4daf, 0x7b4433b85d022739, 0xcd36dabad705ca3b, 0x6e6d39c733157f65]],
+    [[0x1bed354ce221d298, 0x8331bbcef1c099cf, 0x36e6c7f74e4e2d9a, 0x71ea62198f795afa], [0xab3f0346c6c20b72, 0x036810214ae2e97a, 0xa6d28ad9ae33fd1b, 0x418510c4f8244838]],
+    [[0x6b1798e54eff04c2, 0x933006c0506d7231, 0x84c235a2db6c665f, 0x20749f2922359521], [0x12a00b5e9739c1ac, 0x7f3a2d8339059037, 0xcc3219a535a0d1fa, 0x2edb13f1ebdc251c]],
+    [[0x7345657be5a7bfcf, 0xa91f95594af46067, 0x02dd61b1e61829e6, 0x3819e536a1d54dd1], [0x7c62f6046402f64a, 0x15c172d92d581b4e, 0x02a6102b0715765f, 0x3fbe86ce3d4f670c]],
+    [[0x73137e4cfab5ec1d, 0x2ed979e6a0f6c4cd, 0xc8dbbd66418ec8e4, 0x26f07d5adfcc9daa], [0x4309d4091b708ca1, 0x941c4d4acde8362a, 0xcd9a6ca5ef8c407e, 0x02b526f397689781]],
+    [[0x560e9f1fac5c14cf, 0x52ecab8849bcfc9b, 0x26cb45f1e4694173, 0x66ffb82e777d9ac3], [0xc3bb38d661c0a41c, 0x191a230e099eeea2, 0x6f51843153f522ef, 0x5e4ebcbc58d6cb78]],
+    [[0xd52d1f5dded35dad, 0xebafc82c4074a850, 0xbd1830abe3ad5baa, 0x622891f183902d54], [0x93b39129372fca75, 0x59ea82fcd72d9928, 0x671d29c6b3602620, 0x49fc5bb58da043b4]],
+    [[0xd2e285b643d07544, 0x9198ddbeb0d5e10f, 0xaeba3c906a24d2a2, 0x03744bc0427d3237], [0x608ae1b1238f87eb, 0x8f194c7ff28d5551, 0x467e8a5fabc7baf9, 0x1ae6d429576ca717]],
+    [[0x4732520a85391be1, 0x91d04357136796bb, 0x1190d446aa79c668, 0x3abeeb57bbe9136e], [0xe654ea40ffcf2c5e, 0x841527a39d98974b, 0x95c443c39e6b8679, 0x56310e7310cbd43e]],
+    [[0x7edb6e09f7c340d8, 0x3b5e653fb1440bda, 0xe63893909d469449, 0x33559fbc71a461e8], [0x79c651bc95d8ea99, 0x52ef9f2b3aabb7bc, 0x958add883f36873a, 0x42ba5135e0e0803c]],
+    [[0x564a6fc172e41537, 0x55d1834df23c343f, 0x35f1592b6cdf4acb, 0x426e681298d8c77c], [0xa9782568edea8782, 0x77e8152f925eb020, 0x51d8c8ab4497bd23, 0x019b71931d18eda9]],
+    [[0x7a57d72f0baa3316, 0x570f5dc7568eb5f3, 0xb5745c48d45d4ce4, 0x1b9effd3288791b5], [0x01e7df7143814641, 0x251e320c97ea97db, 0x1f5837b4f959a5b5, 0x5908b925d8eaff81]],
+    [[0xf365919d16e6a7b4, 0x3eec75d6dd052a7e, 0xb9987d0cda629571, 0x402d098d7cb6b30d], [0x0eade38ced8110f5, 0x262d851bb3710e85, 0x8fbf5d42484397d1, 0x221aa1551fa8c503]],
+    [[0x873dd7aa858787aa, 0x26e201a4ae9ee690, 0xf411f102744cf693, 0x5c1c9608cb2d4b1a], [0x5cc839f18773a988, 0x6f66aaef6be09d13, 0xde3fbdde7d01cddb, 0x3afdafe1d0f0456d]],
+    [[0x75eb74bfbf1fc749, 0x4b901ceaadf3bfa1, 0x83e910b474fb01da, 0x19782666765d6cbb], [0xde2678b513ccd4c4, 0x742b1460154f9b34, 0x9e40ca7e3782b31a, 0x4b70b3d8611535bf]],
+    [[0xe03f7f5b6f9d2e1e, 0x7549bcb19540d829, 0xf3a31b644ed76339, 0x0337389bbba79a3d], [0x266a7e7130c7bbdf, 0xe2f80139a03e1d58, 0xc25132c00564a9f3, 0x22f80b1736a507cf]],
+    [[0xc814f51d17db596c, 0xfa3c880b67a9a82c, 0x22d6c29353f04e2b, 0x638e3d1ad33d3502], [0xb4507262afbb12d1, 0x7d982f760381b53f, 0x39b2c67ebce1711d, 0x34a4cede0b5cbe1b]],
+    [[0x4f79b5570776818a, 0x64a2c9a09d80b4d8, 0xd1c99cb964668307, 0x4710d9e3fe2f0602], [0xdeae35199ccf99d9, 0x348f536992f743d2, 0x120564a8720a80e6, 0x3e74814b9d963114]],
+    [[0x7a936bde3fe9b4e7, 0x8695cc959f327d51, 0x7d819d35097e7d8b, 0x0cbcaf497b3fa268], [0xfd29cd87747a3e73, 0xb3abcda724b82f20, 0xd8cec79cf66507d3, 0x0260909b61900879]],
+    [[0x36da31ca85b7d3bd, 0xfc0a44ad5151cf45, 0x3360d4f66fbd1198, 0x08eca409fc123f13], [0x9217a8c5d2e0e1a4, 0x7c2b8108f61ca257, 0xed1a480396e7da82, 0x6fd71bfb92d27ba4]],
+    [[0x992294fe70e7fe2d, 0x7f6bfc15889c3d38, 0xf952c29a8e46c88e, 0x663bfedb0f6fd0e0], [0xdf48bf1ac2e3581c, 0xab06fe9c04fe8e9a, 0x97413ff18e7979c4, 0x6ef77c5b6d19b0a1]],
+    [[0x8fffbcc3665f3877, 0x6d4bc02e804192c0, 0x0a0d66206ed5aa74, 0x08b3fcabf1022395], [0x4c4db6cb083ed208, 0x72848ac4ec671fc4, 0x11ae6f65bd2dfd1a, 0x254aaf226b429a7b]],
+    [[0xaf4a166e7ea42514, 0xc1c77e426dc09cb3, 0xa0c70fe6382cc8da, 0x5f4dfdfb46ee2bfe], [0xd0484db3922496ed, 0xa09c17e9c8bbf14a, 0x8f330132f27cad77, 0x64f4c5a8c54ca7b5]],
+    [[0x357b337078b3db68, 0xedbbad504bd2fd13, 0xbcf11fbbe225c549, 0x317e4d640a2a5d4b], [0xcedbcd3d0c9d82b5, 0x22799f13fe5550b8, 0x36e04c5e0c1df6bd, 0x2fc611542060ae15]],
+    [[0x06d3e4c0aa3953ab, 0xfd7f37f9535cd765, 0x4e76c3615be7f11e, 0x6f93ea3cb115df3d], [0x3deeb9dbb3379398, 0x5631f9228aefdf45, 0x1b67c11e5e905d01, 0x6a4aebe6d3834cb5]],
+    [[0x7e91c1c4be68522d, 0x04629f4ee8734ff2, 0xf46229ae988436d0, 0x11c6d9c6f6b4f51c], [0xe14473909f3b40f2, 0x7d3fa71f6386788a, 0x5093768f82a72523, 0x3cb5b4e42223be95]],
+    [[0x98b8e2c3d49ac9ae, 0x9d8ddcc0758873b7, 0xb659c9c4d97f9369, 0x20f49f873b47f2e7], [0xbfb4ef927dbccd01, 0x601b5c981bd259a2, 0x834e8b0058a15b64, 0x6288604dc56d3531]],
+    [[0x827e10bda4bd82f3, 0xfba045719654ecaa, 0x6b0694123c805ecf, 0x11ee28cbd32c5ef5], [0x030a4efb14e498fd, 0xc457e89a13c6fe2e, 0x7465558ea16dad79, 0x03d8887334b00c88]],
+    [[0xbbc64786f10dda20, 0x55b10c4e2e2c0a82, 0xe363b8bdbe615f3d, 0x06fb641f4f64f6fb], [0x41d5e9258c9d3403, 0x0f29d0f8f83e1e7b, 0x49b2aceef52a1b1c, 0x60250eefda173efa]],
+    [[0xdf3692829e70c92d, 0x5acbe25e209e3155, 0xec2442b1e05ce525, 0x45f6e5729e5dbeb4], [0x1e4aba170195e07f, 0x43136a97870cab2a, 0x0cddcf962613d084, 0x6a529cad60e4f737]],
+    [[0x96ab729f0a4cdf77, 0x3ddd11c66e2bcc91, 0x2b8ee018ac946fc0, 0x02966952a91adf46], [0x775ab0ae3d9a2316, 0x47c34d55da400b94, 0x6ddbef65512d572f, 0x23a7d1c9576b866e]],
+    [[0x310331b6739a1f01, 0x97fbf29105eb06fb, 0x1f9a7658ea002405, 0x5c5459b70e47351d], [0x8fb11a459dd2c193, 0xe88dd23ca3038b63, 0xa1f11faf5e469ee2, 0x2ab450f4a6c3a10c]],
+    [[0xab947cb12e83cc78, 0x15429ddde641366f, 0x594c2ef12316fe61, 0x50d7de4d6e873c3e], [0xf62e05120672e28c, 0x76625ba4a42804e3, 0xac2256def0dc5d93, 0x3930da8f7615af50]],
+    [[0xe76055738c575332, 0x21c0de31650e13a9, 0x2ab37fe2f18e0f6f, 0x24f2818e9009ecdb], [0xa4dc4ead39d038c6, 0x0fa811a7c5cb3d64, 0x32a5169ab561afa0, 0x341878d411c5eca4]],
+    [[0x47cb48a9b31a82c3, 0x5269c42dfb1d2e38, 0x46fd917a19d1eb3e, 0x04e71fcf475b0b7f], [0x209eab10e76dbf3b, 0x41071eecf9b7ab7f, 0xf593d79795f8d638, 0x4ebcf93d7fd1eaa6]],
+    [[0x855e86fb36d43fae, 0xb3f6dd2b3edb5e9d, 0x635941e3501cfeeb, 0x1511174e2dd241d2], [0x94871e1d10eb89d3, 0x20551cbfcb4995d8, 0x848669e0621904bf, 0x37f857f0d8fcc5c7]],
+    [[0xcc43cb86dda5a8b2, 0xf390c902986bd46a, 0x38292cc36d2cea87, 0x6f573f0c2db674c6], [0x2e1a80fca94045df, 0xf3daa9c3afbcb42b, 0x877ab8a875dc7abb, 0x135f1b8fab209fec]],
+    [[0x88b28ccd02984b7c, 0x96e8bad8ba73cfe7, 0x3938c645565cecd2, 0x541c0954109195dc], [0x26bb5223319cecc7, 0x12fa9fd17611faec, 0x81c97e0a0f163322, 0x11040106f6adc66f]],
+    [[0xf0ee7e15de969cce, 0x6a2d989c19be8222, 0x88c47c691d73ad64, 0x0bcfcc7599fc85b4], [0xdf13a936df478f16, 0x88982c106fa0d82f, 0x4d33040a3dcb9b7e, 0x33b9bba09636e7df]],
+    [[0x6b05889f3d4c5d55, 0x1fc84bcc93401628, 0xc5cbe47fbae11c5e, 0x017642b6fbb718d1], [0x1acb9b3b414df044, 0x78d116c0c188fecc, 0xec78e1557d28b673, 0x2ca0a9656b906b05]],
+    [[0x24028290c0a29387, 0x35c0289a5437c050, 0x4a29fed4c3d9ac91, 0x6ddad939950c6492], [0x9a74d3a86017962e, 0x165a0b4abe5e0850, 0x1dcc3cdf100b4b01, 0x0c6ad3e118ee7e8b]],
+    [[0x55ec6c28f21a9c54, 0x05e80a820187eade, 0xbb7b395fd2e69199, 0x29b66e3a20fc676d], [0xa8f79a116205f878, 0x542883c0b84e9f2c, 0xc98fb217689b4035, 0x0e8dd2cf31206ac0]],
+    [[0x28f3829b0aa95d40, 0xffc0b0aea7ee7136, 0x3e74fee107b7c857, 0x1fc1cbbde0a463eb], [0xcab77992dcaffc0e, 0xefe63e8ca1f0fcd9, 0xc8403af6a0394697, 0x1b6ec2c2df059710]],
+    [[0x9c70eee2a2a188c0, 0xc20f571a92181956, 0xe3ac1fa65ec6b890, 0x1b233d22dd6bd1aa], [0xea66ce8b682fc24e, 0x82c660777b3c9da8, 0x14ff6bb0efd430c2, 0x046fc2de1474f74a]],
+    [[0x8731245275913908, 0xbc4533a353dc5607, 0xa49782147eca5dc9, 0x21177c044c876eaf], [0xf9faf96aa70a0aa7, 0xad5179bf0b97bd0c, 0xb273051a85dd9910, 0x6b82f2b18b56823e]],
+    [[0x97929a5aafceb0ce, 0x2a6bea28d928957d, 0xfb40d4b2105fcb9a, 0x56630cf45df877be], [0x574953121e6afb26, 0x4992e751b2c780d3, 0x7ba0ccb8bbd8bf68, 0x21241e58fdc1c473]],
+    [[0xb0fb149243d998d2, 0xc823082308fe06fe, 0xb1d41ff2b7c6d35e, 0x54650b640e7442ff], [0x186e2c89e7a0952a, 0x8a01bdcd66bd8c1a, 0x1f0b036ffc213d97, 0x5ec5f1db612e0c49]],
+    [[0x48de76a0a37cabec, 0x3127376c03d84cf2, 0xf1d7b9cd381a5c1d, 0x474898557bf8b453], [0x17b0051500b9c461, 0x46b3dd911f857dc0, 0x326c5c7338fe6d16, 0x6af648c8b2662b70]],
+    [[0xc8e0fdc2e4a8e2e4, 0xd105d94672160c55, 0xc5ec1215f6cba56c, 0x2297bac78a6f14e5], [0x1a9a3ef60030021d, 0x4cb945959a8c8257, 0x1cb38239e40ffb74, 0x4dff1bc7c5ac4667]],
+    [[0xc7e2b5531a1002fa, 0x96d7affa631c40b2, 0xd123091d64d2cbaa, 0x2ca7bee880332f8a], [0x7f7a166371b09979, 0xdce9eec9154ede16, 0x7c229ea309c96f0d, 0x0bf4818e76f55e8e]],
+    [[0x534beaf1d1665825, 0xf4715755acd6dd94, 0xf5ba0f2a19e3be26, 0x0a9c39295210a97e], [0x659c71235bb84c58, 0xafe55a511517c895, 0xfb9d9847516dd9f6, 0x23c5506470191340]],
+    [[0xec911674c313b1ff, 0xf907217f9d921bda, 0x6650c02f213660f1, 0x2d7492a4041365fa], [0xcad840e73ba96c74, 0xf86f0356c27aa256, 0x7ee559c557af0317, 0x0e64e1110948d18a]],
+    [[0x9b3974faa15b3683, 0x30b93bc23f807f10, 0x8ef5bed23cc7aa78, 0x5782a71aa856ff98], [0x0d4bb44fdfc50e90, 0x1592e225d334c0a4, 0xa570e93d99a16e02, 0x6c4b615253b32305]],
+    [[0x8777348381f23c07, 0x500f5d6b6ab72b48, 0xb6832f9da841597d, 0x0b9149c1300c519e], [0xbb07904b203ce026, 0x1407c3185d275783, 0x7ed96deaca2f8fbd, 0x08a6367f0e27d225]],
+    [[0x23a06f53beb6c950, 0xa4258d3ef39753c1, 0x02f6a71284a4dc2d, 0x56dedc98818bd522], [0xd0c88ba890e78f99, 0x41d423f792eea878, 0x07deeb0e0957124c, 0x702025038cc1cbc5]],
+    [[0x8bf0991cc78aafb3, 0x0fb3f4350d33541f, 0xf99d10fc21ce1079, 0x45c90408bb8165f7], [0x280f7ba99011037c, 0x0dfb8b32ba1e5dab, 0x9c644b2d6e82ad0c, 0x6f62b72de1ef457e]],
+    [[0x26e8c581fa5929e3, 0x71dcb044ff101dde, 0xaa1a560e96562f52, 0x6e16fbd8b7c269a9], [0x50ded4031f9158cd, 0x710677f117c0b9ed, 0xb3df4d8aa2f33347, 0x142246827660063a]],
+    [[0x76cfbd97c6b27b3f, 0x8979babf2ad1fd6b, 0xb1203a42858f3f12, 0x64ddd1760c2fc472], [0x063d5c98e9e28fa3, 0xcc15a29f5b342a26, 0x99c042b42848c8e6, 0x10c3e0be50ebf18a]],
+    [[0x524f19eed30333bd, 0x3c84c7d1a68c68ff, 0x1291bc2eafc50a9e, 0x5f31acafb24e9f1e], [0xd3a4dab1f7578c7b, 0x0ed546fd16d3cb25, 0xd1d8b761883857cc, 0x7080a291f7732223]],
+    [[0x755e5832dded68ed, 0xa75af259e798d18c, 0x43244c047ebc4357, 0x6e85ae054724be44], [0x7820dc3c1df2f716, 0x0b7204b2bfc607a3, 0x4297bfd780a92045, 0x119d75652754663b]],
+    [[0xda62eb9dfd175b0d, 0xafb83e22a742d485, 0x79e2f174368f8d61, 0x5e3276592ca33960], [0xcd8e5ffadd482f80, 0x95b8958e4c996d3a, 0x532d345dc1aec25c, 0x049bbba4e8ca7bb8]],
+    [[0x5ed8f439f9a2e1dc, 0xebb7c4523dc7846e, 0xfb898fb9eafccc72, 0x5cc5f608303ed3ee], [0xb0178fb1c3cd0eaf, 0xbae185b65a689bad, 0x6a7ec3b637b18eab, 0x1fdbe0a514bb4a36]],
+    [[0xecf115633579cb89, 0x075b961465934d00, 0xb4afce34c2ac0708, 0x50bc423fb0985bf3], [0x52b452b29c21f79a, 0x1dd8438719f1df6f, 0x76324dbdd6ab15ef, 0x1dee661f485d73ac]],
+    [[0xc9f81d44f8ff1cde, 0x86dfda56e2691785, 0x4a57f1935ca45b0e, 0x52ffe78664d7762f], [0x5f4353b9aa05c36c, 0x2e0e4e7689effa27, 0x0ba1fdea7aeac265, 0x2ddb8546b27f9a7d]],
+    [[0x951ab7175e089c16, 0x63134cf9af79945f, 0x44dd2edb5f1d2d53, 0x1edbf89737cf1bb2], [0x364e7406015a4865, 0x558dcc5a4404dfbf, 0xa4665ad1bd93aa9e, 0x34089dba46da96cc]],
+    [[0x30401a987aef843a, 0x3decf36b3c59e64d, 0x5b65a5c327927988, 0x3151660291ddaf9a], [0xafbf680a5b405e14, 0x74bb7901704c6e4c, 0xab986c7a7d487690, 0x3c726ea6d22e2bd8]],
+    [[0x9638e89d8d464066, 0xc0d504672a460e44, 0x8ce2737bda489928, 0x111f2fc2c2c9a98f], [0x6b0c45e53d74575d, 0x192a441a700e0e6b, 0x9b9bf92f72509e7c, 0x35c8b45d45fa4067]],
+    [[0x860f3fc699ab130a, 0xe218798fb8e577e7, 0x59152b50f2c40585, 0x1288d5f97d9a19a0], [0xd1badc3755a94fa6, 0xf491e43b0bd8d399, 0x3c3c6add26bfeea4, 0x5a76166d29c4817c]],
+    [[0x2702a6efbfa0dcc5, 0xa9933bf67d3a848e, 0x4f168f1697b79a80, 0x181177f73e0c0212], [0x08b3a2bcf7fa39c4, 0x089b0cb873d29ea6, 0xf80c84837197b430, 0x10638ddeec61254c]],
+    [[0x8b12003b084993d7, 0x1327edd4567ed133, 0x9c674797676803c8, 0x73016c1b31587bb1], [0xf582a85b18b80342, 0x55dc1d59d81086e0, 0x9d31f90d32696770, 0x60a945c751925cdc]],
+    [[0xd4c94de1d4b0049e, 0x589754d1ab26647b, 0xd036dffae04d7a1a, 0x68cfc0ca9c64bb19], [0x295e6acc235e9422, 0x7ef02d3197557125, 0x4d914dd847927bff, 0x5334135c031d7d73]],
+    [[0x56e3243163fbafbf, 0x9bfd83a706def0d7, 0xb551570f5c1e41b5, 0x71adbd89ce047c9a], [0xca62d68620188021, 0x2e2789f93ca758a1, 0x961e525e27f01ace, 0x494e88dcfb461ebd]],
+    [[0x123d4a2203ce87da, 0xa08232424e2a564c, 0x61da3e1f264356d5, 0x52153508d266a62a], [0x608d3ef0ada0b3d5, 0x254044085ca5859f, 0x7750dd578013f147, 0x6ad8cf20ad46e5ad]],
+    [[0x3fed8a5c51920274, 0x356f83f443c12a8f, 0x98f61acfee07ecef, 0x02c459df94336e10], [0x3546928e361b1391, 0x5a614f8e6d920483, 0xaf4220bcf5cba8c1, 0x3e501be33d9ce494]],
+    [[0xb6007cedd0ced4a7, 0x4f2e0d6cf4ab916f, 0x179412c8a48dd5cc, 0x0a3445d61489bd1f], [0x781fefbb84e69851, 0x3f73586a2dfe43a2, 0xd56b51ea3d1f4da8, 0x6891c0a59711a45b]],
+    [[0x3a77d00262d3a50f, 0xc67a60c89ee24e5c, 0x35ecbe71fe5934e0, 0x2707044ac496171c], [0x2f91cbca99841cbd, 0x76d2aae4131d69b4, 0x5d985eadc2e39f56, 0x1bbb0fd9071e70b9]],
+    [[0x63bb28501bfa4128, 0x3bf8a759407572bc, 0xd835b58f09f29bd9, 0x69777068b8c543a3], [0x9d97b95463149497, 0xf8e0f4ac4cbb52cf, 0x1e11785db18533d5, 0x623f87965aafe32e]],
+    [[0x4726106c4d264aa1, 0xed543a6e16589dfd, 0x7cf3f25af8fa7a85, 0x0dc008b3fbe43f62], [0xf4f0d116e65056cc, 0xd504cddbb53a7d85, 0x7f9fbb8cafe3cc8f, 0x0bd580cd831572e9]],
+    [[0x1216020a6521a7c3, 0xa92ae04d673fbea1, 0x9b7027fa5d7c39dc, 0x4ea40cf2b7d73847], [0xbf1a128ceb17c400, 0xbec2c382a5d2a3b5, 0x47cf2766143107c5, 0x0781abddc7b65b17]],
+    [[0x34d9551ab6f7f562, 0x8b09adf1e710eed0, 0xc65329e68f0e85f7, 0x6938ea4eb138eb12], [0x6264973bc006201d, 0x35ebfa7677e52b7f, 0x315bdb29ae95803b, 0x4962da8e88648320]],
+    [[0x3893a7997cbbacd1, 0x8ee973c559121a0b, 0x1385cdc70428cc74, 0x1512ca70bcf0d57e], [0xaf1afbbeeb284caa, 0xceabaefebc58dce3, 0xe0a58f1110c45490, 0x266f0deef339c89f]],
+    [[0xa9f0e14bf1052b61, 0x44f1e4f0ff138f12, 0x1da2e0ea6ba3c80f, 0x200c284d981d30ec], [0x681f10b22d7fd1b5, 0xd67358dbc9abd454, 0xceaa2bdb17a63b19, 0x1484276ef0911304]],
+    [[0x233c5f953cfe33bc, 0x3fad0bad3db58dbb, 0xb96532c1ac408b53, 0x1587d17b12193416], [0xbc9f2adbb317f876, 0x78a3fbd2ce701067, 0xb773a0252649c8e9, 0x1842ed3ad3262500]],
+    [[0xefb5f765e0ef2fc7, 0x42c0cd379124cde3, 0x5918a5588cfa0d2b, 0x4b65e585922f0ed8], [0x891e11034d90755b, 0x287682fc2b962f5c, 0xa12bcc80fec69de0, 0x14fbacd45b6c0391]],
+    [[0x0019fb04d71e06da, 0x0ac99aa7e7157014, 0x5e0d0afdf26601af, 0x3ca49be424561174], [0xc6a41d8ca942e8fb, 0x107ee5003d5859f4, 0xba2f41eb2793a828, 0x3614988fdc194514]],
+    [[0x7f6f8115f0a1999e, 0x03884ece94b73cc6, 0xa2ab6d58efd62fdb, 0x6417b4bc274cdd95], [0x4d955e1ec8afd2dd, 0xcb813c3bf740b98e, 0xdd2430ccd139db9d, 0x68fdb11f1f656260]],
+    [[0x750da52189396fa8, 0x5bcd62076ce50211, 0xdee6770c7e8c34e5, 0x69b0d98f2309e35d], [0x363d604698603641, 0x364cef482c1913f1, 0xa504f3e8b7c5592a, 0x20c549ec5b936441]],
+    [[0x0450ec3404de7b46, 0x030d11b38468b362, 0x1322c1fe820b34ed, 0x075650579c2b1ad7], [0x31abb51ac847de97, 0x984997959c4ff3a7, 0x3cc87612001f8bc6, 0x148a40127dc435c6]],
+    [[0x13abea3489df9490, 0x6675e6b734a07672, 0xcf79d7b716b790ef, 0x5c7178fe1cd09953], [0x71963f10e1e541c7, 0x46f5477e9df0e65c, 0x9d918413e9051145, 0x6a0148c300ff9bcd]],
+    [[0xa41c47884cefc048, 0x58581f6f68887cef, 0xfee5331fffd71c10, 0x13bfb75a72c028b5], [0xb6474185ae826195, 0xee9c8c4358bdf7a2, 0xf86af722071db9ba, 0x017bf1c7b00879dd]],
+    [[0xddf1f2b6e55e6edf, 0x7d31fc9aab462c49, 0x35684b011e1b690f, 0x429dfc05dbfbdb42], [0x398016f2476aee89, 0x6d92d996fc45b680, 0x34f6c5bb15b1afd5, 0x248760adb680b6fe]],
+    [[0xaff5345506eb09de, 0xdb741fc40869e40c, 0x266deff4851f2979, 0x1acc847e8b26707a], [0x49b050af485614cd, 0xd3c9f9f7b9c4b666, 0xa614d1c7879a4d10, 0x5e21811b3df6ab87]],
+    [[0x569d77b9efb04abb, 0x5e016fac1f4ca0d3, 0x20ab4b9d96dabc97, 0x32d50f1eac8f5976], [0x7973518c948d4e20, 0xa9e53945328fd074, 0x143e1641f26a11d9, 0x691286b4fda0cbc4]],
+    [[0xbf76775eb4c8f583, 0x4999a1317d425d69, 0x296318f86a9f8615, 0x304cdff72711ebb1], [0xb113cba24d7a8491, 0x0fdf11c59b972a8c, 0xfc0778060728f8e5, 0x01cc04f742ec6b99]],
+    [[0x6b4179f2f2f5a915, 0xbce7ca0cab505ff0, 0xa87ec446fdb74505, 0x465772cb8d8d770e], [0xfe0bec50062c3544, 0xe87ee565c695475c, 0x91b2989d32ceaa41, 0x038502be9e6ede72]],
+    [[0xcb0c8a81c5f2192b, 0x9aa966af9bfeb332, 0xab0a416560806e29, 0x0560ea82aba4734e], [0x35a124e977ad9cd9, 0xc5305833cced89d9, 0x1a2bd373bda6f48c, 0x546a8650c19a486a]],
+    [[0xb685523c5f4352a3, 0x7eea72c674a564da, 0x240afd380be6f116, 0x2d2fb144895adc3d], [0x12745e06f72a9476, 0x616eecd317127036, 0x95255fcb01178b43, 0x53257a09fbfec1b1]],
+    [[0x9224232ff023a2bd, 0x098b5ccbd9c5ed93, 0x5b5436c07bb94262, 0x399c182e5e574f9a], [0x10a78ba48c14fcf9, 0xfe044cd38321c6fa, 0xca1515f618b06fcd, 0x715449681eefe382]],
+    [[0x1a49949b9245c144, 0xee6f1f5556ed1dd5, 0x587e43305da80092, 0x6b51b53820e75308], [0x20d68287a0a59d44, 0xe2f171a0eb94ecbb, 0x40c0599b96abfe4b, 0x08ea334f42233907]],
+    [[0x0af3b4df969676f0, 0x19c3bcf737f08311, 0x8c7555f44a5f2d83, 0x3cb35559f9e99804], [0x7dfb809351537ce4, 0x1161c7b5bee10c38, 0x3469e7df3bc53f05, 0x4acb6eb4fe01c0d2]],
+    [[0x34bf838efa132ab6, 0x77ee022988b1f44c, 0x22e3286384f54e50, 0x5c827c47a4cb7c6a], [0xb3747f6d637bf158, 0x0a190af2448b2a43, 0x7e49a6a8c90260a1, 0x26acf6a1661e8a25]],
+    [[0xda793117590b0cc8, 0x1a490e1a5c209578, 0x0176ca6423703674, 0x33c6d838f56cf4e3], [0xd94677e71bb1a84c, 0xdcb51191efe8e401, 0x28e0064846979214, 0x5ac046f46f707a03]],
+    [[0x1df0bd2616ad15a6, 0xb8c52d20e18d924b, 0x5771077c90d516d6, 0x2a847eee8fa138b5], [0xa7d776010ac6876d, 0x73878809b58a118c, 0x074a70c1fde99b94, 0x51fbd2c147b42871]],
+    [[0x153fbbca7dcbbcdd, 0x76b278867abd9811, 0xdf2144644d8ceca3, 0x0e809549411ed45d], [0xc405279b1689d8f2, 0xebd9c939f70a7a42, 0x99736993eb4a061a, 0x0bf043d008bd504e]],
+    [[0x9e3ead32a3f48069, 0x57ec052760e58d81, 0xdf26253c9fe2ab05, 0x41332e9ccaa6146a], [0x978a4bdb4968acd9, 0x9e88d53e27242fd3, 0x2f273c0f15228314, 0x5ad3636e08a30e22]],
+    [[0x04243a1064e76cf6, 0x13a8e9a0e296b18b, 0x11d665cf5b9633ab, 0x5d523abc261931e5], [0xcf1977837b040f3b, 0x56d7b8c8532cbdbe, 0x5f9b7484e2309593, 0x33053ec2c034bdc4]],
+    [[0x695a29eb3853ac54, 0xd15bfd187656acd8, 0x0f6fd2f61a7aebdb, 0x4a9c67eafca7b2ab], [0xcd24424de8a0d06f, 0x88a5df69186028e0, 0x34cff18a9177f41d, 0x0577cad70fe400f0]],
+    [[0x3379398d223a2f5c, 0x211fbbe1efe6965f, 0xab1c5e9069a4940f, 0x28af1af3538772c3], [0x3079e1ea065f347e, 0x8d6483d0600e140c, 0x89e5ac9662c47d54, 0x51ace35cdff5eb2e]],
+    [[0xe1d5f7cebb24ae02, 0xbaee9a21949dc8c5, 0x68267f4f8ecb12f8, 0x28ba7e39587ffb64], [0xdbae09da2ab253bd, 0xefb556cfa29aea2c, 0x0e71fe4fd53d8b81, 0x016f5ec12949889b]],
+    [[0x8cf8b8e9e7c762f5, 0xee06f57a2a4b69e3, 0x76d01caca7c52368, 0x4eecf924e10ce3a6], [0x6ffb518a6b90b5c8, 0xde28065bdd2c537d, 0xd659498d3ebb1709, 0x57f66928fd6cabdf]],
+    [[0x4844f8eba88485c9, 0xa714bd493f27769b, 0xccdbe803f473d3c9, 0x13234d49efca5092], [0xa2b82f3e0264720d, 0xf171805d1aeb72b2, 0x48bbc3d47b80d2fe, 0x59f87cadd0da15f0]],
+    [[0x9080f5123d7069d1, 0xfee0f15e5abee103, 0xe8c783b459a759a8, 0x34d8ad0346d0b78a], [0x50533112f228a6af, 0x79f5de91a70c9596, 0x5631bef200ea7a58, 0x5bc43fcea95a0552]],
+    [[0x04dfb8bece066c18, 0x8c87cd21fe20cfd6, 0x82237adad1cc6763, 0x06033fd87c6bd70c], [0xc139329ba172ef25, 0xe9411030dab2f8c0, 0x8640f5c89e70546d, 0x5668afc78df3bbd7]],
+    [[0x965ff53bedc78896, 0xc793c7391124c3d1, 0x717ecc802556c611, 0x422186f1ed100707], [0xe852a1a0c99341f4, 0x00e439c37ef54bdd, 0x324236bf7e07d255, 0x2e8922eeb769f030]],
+    [[0x0c207fdf1e23ae55, 0xb5f3e91ffcd58288, 0xbd7b5df46fe9d895, 0x2dbc3a85f5946c88], [0xf6edf20ad4ef793f, 0x55b82502f2a013d4, 0x8edb85c08bcb0832, 0x59b9ad4bf32d0cdf]],
+    [[0xec0bb8e02ec3bbfe, 0x5176666f1ee6bcb6, 0x8e2774999d9738d1, 0x39f51246500e2292], [0x5fffe4d8dca2a6fc, 0x675ae216742c37fe, 0x70faa7a025af1f2f, 0x6bf6b5112f38ea84]],
+    [[0x5284bf3de7f0b6b8, 0x31da0e21aa1b916d, 0x002623399519e29e, 0x4b1b460680a71db3], [0xef5afd058854e448, 0x53334be62e853715, 0xeaaa1769e65d993c, 0x0b23d95785ed5a46]],
+    [[0x671c0756895a212f, 0x3243bd60dbb0634e, 0x252d4f104cd1433e, 0x5ff4be0d796e940a], [0xf256403818d99e5e, 0x07d0c5dd0150c5a3, 0x3cdac6d10969210b, 0x3119c89b8c7961ca]],
+    [[0x3389bbaa78d1299e, 0xddfd3d9f145c50fe, 0x5b7c98ab5d5d9596, 0x6bf112ca7180043f], [0xe08813e9726107a0, 0xca27d5c9fc51d720, 0xb6236724aefa4402, 0x56b1ef1896d76f8f]],
+    [[0xb995dce3b2597fcf, 0x67ea10c3d108a05f, 0x50eac2e47379881c, 0x2261cb867caa5abe], [0x94aa83e4d4ae1400, 0xb8beefa6e2781125, 0x46fd06886ab0b589, 0x53dc756096b75758]],
+    [[0x034066d18fa1a35a, 0xbad2ebcd96842fbd, 0x539e6cefbe2714b3, 0x4001755b5fc75ddf], [0x1a3c68a87b4fbe1c, 0x6c0bdb088694801d, 0x10eb540fb256b079, 0x2ed865d16bd07f0b]],
+    [[0xe98d4f5e5a0c9863, 0x630c712f980af6d7, 0xfcf9102a465dd075, 0x53ca0f79b7b85eb3], [0x697109590cd3de53, 0x751c7e7bf26572d3, 0x15577c5e66f2b8b7, 0x55977cddd0c4d232]],
+    [[0x5647e272f5ad1921, 0xc6b4adc214721806, 0x9fde25d403f59d38, 0x605137bb102d23ea], [0x7368be1c3dcf202b, 0xc03ea3a8a233bcd2, 0x9123ba4f00e5a1d9, 0x0118c9ec33f245ea]],
+    [[0x297173a9bef7ce24, 0x32c1d2e74ce7b560, 0x4c418113cbd7f7a0, 0x4342198945a55ee7], [0xea0501868f0fcfe5, 0xb2df21b60075464e, 0xcde07336738d3eaf, 0x44fe37d373789276]],
+    [[0xa8695a8ef06b3e7b, 0xd51bdcd7bfeaf7d9, 0xc364ed78df3e9d82, 0x4e4a87b2749ad039], [0x58c11a35610d0706, 0x960466fed3f3d377, 0xd6b4e703db64f81f, 0x21441daf9ea59eee]],
+    [[0xf81d3952637b4f0d, 0x625dde1cf82baaba, 0x4dc82aa24217a107, 0x5623b0b1d6721658], [0x1ebdb32febe26421, 0xec09093e272f042c, 0xb9eda8c47285fd40, 0x41e709b26bfc6a99]],
+    [[0xf3be3da145894556, 0x02cfa7f375a3f50a, 0xc264ae963cdfcc5d, 0x6df1c509755ee445], [0x79ccb669239ced66, 0x29e12d59221287e8, 0xc8c57f53f5f45903, 0x4ad2245b5340c185]],
+    [[0x0c025063ceb518b0, 0xd221968ab64ef5d7, 0x9c072c95f58d6994, 0x030af5c9a4c0e1b6], [0x4e3849b125336d98, 0xcfd27cfffa959cd7, 0x9748f5e71e9ad2f9, 0x43d954ed90c6e5d2]],
+    [[0xa29165d6b1319010, 0x68c82e40afb982e2, 0x953aa4ef36db947c, 0x1b2830e9424262f7], [0x3f71b426561afaf2, 0xe30b52df81bbc758, 0x21ff086ffe7e67be, 0x6dce87da8029a6c9]],
+    [[0x7aed5e61bcda6d59, 0x1c2b9556922b290e, 0x6aae920a9935a9df, 0x6614f0ae10f314a5], [0x759c4408e6cf1baf, 0x601f3e33ea611255, 0x1f8cddd69918cbca, 0x36707738c4d0cb8d]],
+    [[0x8701907f2e6544bd, 0xad2c1eb71a3f23ef, 0x3b53df93883b2932, 0x0eec0cf9e728d6fd], [0xfad0d05ecf1e0123, 0x73279bd6d49bc655, 0x2370f5c43027886a, 0x29e2e48aab3fc338]],
+    [[0x8885e47f9c72d452, 0x47be307474a9ae86, 0x3a96b778f46211b6, 0x03e949a9182d3d77], [0xc79aa4fdb5109d35, 0x860baf538f7955d6, 0xf181436ff745b4ee, 0x652087272977d2a3]],
+    [[0x167381a694d0164c, 0x74b8c2ea3e173386, 0x81264f81546ca5a1, 0x1584c62a3988ee25], [0x4dfb0f986296a7ca, 0xd599b955fa46261b, 0x4274d23d00eb0c69, 0x6f46505b12c94f77]],
+    [[0x60a271d41e462a2a, 0x18dd7b151dc50b01, 0xdba6ce8473737d2f, 0x514e588e7e465c20], [0xdf5eea55a273297d, 0x2bd70b57048f70da, 0xf875e311c86fdde9, 0x684fcd4b8272e2bf]],
+    [[0xa6e552a5c65ff118, 0x25b5695c171ecd5f, 0x7a7bb088b4fb0578, 0x489b2589b65ee586], [0xb7305d4308a081c7, 0x9e29fd0a5e95b763, 0x4f62a456a36a64bb, 0x240638899656775b]],
+    [[0xc92da6a97d8c635e, 0x101275c1a46dd997, 0xcaf2b74e870958f4, 0x02d379812623dd1f], [0xd5e324d4787a6794, 0x4e6606c564f5662b, 0xae26f749d0d36741, 0x14ec79418beab7f5]],
+    [[0xd3ec0e76f7870a20, 0x4b962e9ec7dcee19, 0x9f4e37c1ee35ae0c, 0x38c77fdfcb77a599], [0xd53f48207cbb9684, 0xb29b56a4de57d461, 0x833ccc5f3d9cf894, 0x59f39231197ab497]],
+    [[0xea146cc0322de3c7, 0xba369a091eaf4ac6, 0xd3c0f590d242bc9e, 0x3e02da691b557b7d], [0x04e8e61d2c663d22, 0x893fa36388b4d12c, 0xa394263d2df33b22, 0x22984f232af9925a]],
+    [[0x8a85362dd9464d6b, 0x08694ed0c0ac7a36, 0x856c7ac7f4a0f56b, 0x1a1a60ac726f3313], [0x15574d2be2f3889e, 0xbf1b50570cc49e0b, 0xa7a6c741867969c0, 0x55ff86e93182b08b]],
+    [[0x30311adf72adb7fe, 0xb470ce39dad9ada4, 0xd250cf9d496d40fe, 0x4985ba83c18012c2], [0x8c0379a33d7715af, 0xa8bb74360dd7bc07, 0xcb8c6a013a92d812, 0x42ec3180e55359ab]],
+    [[0x7ff32f1c7751513d, 0xf8bb3a8d684a495f, 0x04be71433d4febe0, 0x159e876f3ae92510], [0x2b233e5b0eda71dd, 0xeac98359c7a355de, 0x8c273e77549a5556, 0x08e6f9580cc84660]],
+    [[0x399c6282d04b6b56, 0x666f6e4f4e6a30e2, 0xee20d3fa20cb8f17, 0x559b2e1e482c9bbe], [0x99f893e56936cfd2, 0x565963c3a7e3bb49, 0x535a292ded3eb2de, 0x34a9de3c86bae296]],
+    [[0x647a81b7c354862b, 0x2f33a7c7fbfbe140, 0x7d2d615dd623f8a1, 0x2ec45f330bf68f63], [0x1f1c28f606430449, 0xb619601e884fb3ab, 0xe817c221e377ada8, 0x5958dd177bf4956e]],
+    [[0x997ecbdbbb881bfc, 0x8b26c505e3d0bf41, 0xc09492991edb19d0, 0x49884822146e3877], [0x352e2d2cc042f8d5, 0xdc591af883bd8dd4, 0x7646ca79fa022814, 0x392997b13d326f04]],
+    [[0x169e82355321b430, 0x1149a37a536c8586, 0xb52b5b2c8f9c9ee0, 0x4ae4b7e55c1957ef], [0xbb9796081cc6dcc5, 0xcbafd7d75e2684a9, 0xb2ef3a3e819ead5b, 0x05b1c6e5d567fa17]],
+    [[0x4e50c34f053f28fe, 0xd500063fe92f6e52, 0x4285277c87fef5bc, 0x19fa58a1452d77c8], [0x7a5cf0f5b28a30dd, 0x22fd9e158d015e6c, 0x872c52e32bb9ba1c, 0x22ee8e59b1440e06]],
+    [[0x1bb05af11aace12f, 0x0586fdfe8236be78, 0x31e6ff3045d89ee1, 0x3d72062773958189], [0xd53d6d36e4ee1828, 0xf03944e796fb4e20, 0xe340328592b0c53b, 0x551b30539cca19d6]],
+    [[0x0b1c875dd8ce07b6, 0xa98e3053610feb8b, 0xb6270d7297f4b70a, 0x6d1cff5416c35f21], [0x364cfb4822e4d948, 0xb851dfc1ce0982cb, 0xa13d012b086dd185, 0x5073def09e94991a]],
+    [[0xbdcde1c1765559bf, 0xe84afebc3fa4d146, 0xb6126ef82fb7e5da, 0x1234d2bee5141849], [0x27c1d7007a37c7ae, 0xeee17a799ca458cc, 0xc2d23b2958be324c, 0x6cf1ebe36dd2d255]],
+    [[0x71a2709162fd4de1, 0x4367b15d8364d7e3, 0xa01b006ea5febbe9, 0x636996e7a33697d1], [0x315505bc48625c1a, 0x89cb4d1b9f56a89f, 0xfb01ab1a533d919d, 0x6a1abfef43d81cff]],
+    [[0xab39eec750929bcd, 0x45b9e75639eeac89, 0x8671e779225ce100, 0x469fbdf7c2be25ac], [0x460213bb4b7d497e, 0x9834cc12049040f6, 0x66d9326dd26eb694, 0x4b2c74cf63de50e7]],
+    [[0xaf496c79ea25814c, 0xb4e851466e760f2e, 0x92be3e08f53f9962, 0x19f6416fd9ca7fc3], [0x87cda28299eec2d1, 0xef8adbd97919364d, 0x7feb3230f43ad7d8, 0x4b27338ab1f9c1a8]],
+    [[0x0872fbaeac5689ff, 0x0a6ac0e0b6114785, 0xfff1e762ac649968, 0x5d10942ac14a1191], [0x22454afa4fa27882, 0x6b5c5b34e2b13926, 0x09db3f85ced55c15, 0x090a5ed91c9ebf67]],
+    [[0x9726105c2d5ceffa, 0x213bc30822de1b08, 0x747587a1e8f181f9, 0x32380c575262f1c1], [0xfd1f9ce15e018353, 0x4f796fba90fa7bc7, 0x76b3270be5eb0479, 0x4705938e86a11297]],
+    [[0x350236641c7b5531, 0x960618d8d3de5e6c, 0xe1bed5ec263debba, 0x0356245ee85cb8af], [0x2e44b98033d4075a, 0x65577bf997e24974, 0xb92c229a4c83fba3, 0x2b32f4151bc5773d]],
+    [[0x3641b69c75e75df4, 0x727650c6238bc065, 0x11af1fd5f58d6552, 0x677727514a7a4d04], [0xa90716179a30840b, 0xcbc7fc39d5eaf57f, 0xed84aa23d8734ce1, 0x05933cb841594461]],
+    [[0x1a7a8ddb2be49ebc, 0x91a5b14a27a5b19b, 0xaa8710372c5bc1f0, 0x6b49a8b4490b6beb], [0x3398a21ef253d0e2, 0xc313d627bd67b557, 0x13169791a7710c5f, 0x3e2f4f55fc84d8aa]],
+    [[0x81d1e2c7d85e7f42, 0x7d6cfa3446ee6551, 0x2e5eb00d3230cce6, 0x5678acc149c85ce4], [0x091e586e3802ef2b, 0x2f55096c8cfd1afc, 0xb038f94c3273ae07, 0x172862909ad2fd89]],
+    [[0xbfacfeb00050b167, 0x6ab22b3a0bfeff55, 0xc0b09f257053ca0c, 0x438074762bba35de], [0xc4b61da134786cb7, 0xd0e88991c61dc6f4, 0x626486630af108ef, 0x5aa2eb472ad1ebbe]],
+    [[0x7083d43a315f5399, 0x088ab52aef74eb3e, 0x1eb3fa085a20399f, 0x5ac35be3dedebb91], [0x77e2b65a31fd18a9, 0xe68afe8982414cc0, 0xacc1330da8421502, 0x25ec4f0bb106e929]],
+    [[0xc466282cdf3dc0b4, 0x51b4aedef1524034, 0xd8c9cf94eec8e2e4, 0x037dc2f1fbc5d5eb], [0x638df778af388514, 0x497a0be7b42ad296, 0x7b8475f518b57bab, 0x219eda8df14c5131]],
+    [[0x0c443a5ffcf8f787, 0x27aee4de0a8b7ec4, 0x44fba51cd76826bc, 0x341cd433faf97901], [0x67d7107d940ec47d, 0xc7d4ba8546b55a14, 0xca14050d4f8538f2, 0x3fbf4368a5768434]],
+    [[0x93fd056b1aec703b, 0x336f5903bfcbc059, 0x306f3ebf95b25161, 0x357b91ba3b7acc62], [0x86578596039146db, 0x84da8252ce87e9ea, 0x7e3ca79423b06ff4, 0x3114786fe690a466]],
+    [[0xddc33092981cd2ff, 0x7bba1dcca42eaa14, 0x8eb2839c547bcb69, 0x220e1af09e558246], [0x931bc763a35174e5, 0x49a63cfcb87e6994, 0x2cc1067c18496d07, 0x1b134b45f6724084]],
+    [[0xcc7d5bb8b4874faa, 0xc262f61b11cccb85, 0x914b4cf88008a7fe, 0x1bdaa48c686e00b8], [0xc218d5c449f895dd, 0x239b043414bb870d, 0x5adf01277cb618a0, 0x04500c7c7af84e7b]],
+    [[0x01d138a43cd65e7e, 0xf99a0f08363d1ba6, 0xf1dd41b236590140, 0x62bf11f505f8f9d7], [0xd83668f4e47508dc, 0x4d575895c618440b, 0x57eb484fd31238bf, 0x2a8f62bd0c6ec55e]],
+    [[0xe3e5edd3c6204d41, 0x262dd7e8dfd6fa73, 0x5bbc53f594d148cd, 0x5c52fe5fcb1b9534], [0xdd30fbc5cebe8f36, 0x311cb5e2be6662a0, 0x0a258f7e29d30b82, 0x2c226f1f5b680fe6]],
+    [[0x66efe2f86afced9a, 0x6ebef6af502d99fd, 0xd145136479619771, 0x657e38cac79371d7], [0xf554084f33bb4be2, 0xe31551ef4d986f3f, 0xc954ce5dd91d50dc, 0x0f8549aaa50fcf59]],
+    [[0x6890b99a4b341007, 0xce35b116daf25201, 0x2c7f584946938afb, 0x22dab2885662d0a6], [0x7cf6d6b535c03682, 0x47a560e0d0f81366, 0x3db73ea31504d9e9, 0x024527be29e8a682]],
+    [[0x366e3117b2d73d2c, 0xfeae0139882b184e, 0x2cd37b947727455b, 0x20ee6895789ca5f4], [0x3ef5fac6c6ad06a7, 0xa2d7ce23b2eb0b37, 0xfa793fe253ca4f38, 0x2c24773f6157c3ea]],
+    [[0x128e82e41a23c410, 0x245d98faab2de371, 0xd2ef87a1208a0d70, 0x5498a62d11050ba0], [0x2060c7a5e07804ce, 0xe12e540aa5d7e426, 0x92b41fb6c572ef10, 0x0833232f8c7d4c02]],
+    [[0xc62ddcdf35b742ee, 0x17fe1fbd591983ee, 0xa188ebfc04567935, 0x668c3027d8ce9630], [0x9d62f6b45ef76b67, 0x4d3dd07608dd6aaf, 0x7de4f6ac3ee8ee20, 0x6a382313238f976c]],
+    [[0xc46272c6f5368e94, 0x108de2df2f31b9d2, 0x7f4a754606352d36, 0x6906f5e67ba8f119], [0x7a1b4fdbb3602344, 0xcb9afee07230c963, 0xd78a1418e30fdd95, 0x4809733a274135f8]],
+    [[0x736416d7af714918, 0x431a1d557fdaa517, 0x0643f9f1f83b8fb5, 0x4e067a971950f689], [0xd998817cbd62564e, 0xf0e71bb1ba87c272, 0x138a2f12f82e6f3f, 0x4648d99140243c09]],
+    [[0x7ecc966af2d60c57, 0x9320838fc0876281, 0x5ec62d9abaa3ddd4, 0x59e54e5783cb1f2b], [0x8f006a95d405eacc, 0x9496418712fb3094, 0xb4d1e31343250b20, 0x730a36fea95d809d]],
+    [[0x04cd26b6ab27240d, 0x63702a9158541f9d, 0x17ca7a8695c934df, 0x6912d199bb5fcd60], [0x32e0d5e5a39c9ff0, 0xf79cf08c00dc886a, 0x644cc1d2af40a7d3, 0x1456e20575010cb0]],
+    [[0x136b68772c454e79, 0xc0e0fc6048edb32a, 0x0f3ab92fa5b6ed26, 0x186f0be95168a4fb], [0x4054bfca24214d7d, 0xff2ebe769a75f710, 0xc68f4106ff2c5474, 0x01eaf3f88864779d]],
+    [[0xd4fcbd5afd54e9ba, 0xf6f6032a778c6b6d, 0xb0c220f45bc08548, 0x29df5b1761d9173e], [0x809cc8384ef0e360, 0x09e916cb5f0d8c99, 0x015150cd5ddf136a, 0x03d12760705edee1]],
+    [[0x4cd9b51ff3b3d41e, 0x1fb5fb9e4fc78fca, 0x8c7256b03f3ad291, 0x2712548cddc07bc9], [0xb671ace8cd8c06c9, 0xdbc251b62f9d408f, 0x0d47eebd4707e9f1, 0x4c53ec3cbf6a3b15]],
+    [[0x6c41a9bf43145cd7, 0xe824ec160f67126f, 0x483d146e8f2f3339, 0x606b1a47fc23648a], [0x9c856c9e963ed7c4, 0x9078c5e56aba14a6, 0x7a7aa3301c74c69f, 0x459add9c026cf6ed]],
+    [[0xb641c36d34998d04, 0x8a1cc6a38d3422fb, 0x6209bb38600d534e, 0x0a48842b8dcb9cc1], [0x95856007e422e8e7, 0x4597fd814caf3a47, 0x4c438cb241643eae, 0x13c17c88dd87cf5f]],
+    [[0x509a15254a810fc1, 0xe47ffe0efbcb8d97, 0x0e71be4bdf93449c, 0x68e28459d03e77c5], [0xef36dfc4bc6919ce, 0xaa4eda4ee70c8fa1, 0x953cb23b806c3578, 0x3598e898e07f3759]],
+    [[0x66d17a409f303b62, 0x36852efc5fca8fa0, 0x96ccf35755b2bc65, 0x683ae3315833d579], [0x8bef656a50310386, 0xe72cf15fe336ae7a, 0x5bc75139ab86a77a, 0x4bb897b8f18a726b]],
+    [[0x67946f1f4ff45387, 0x83773601f1cce949, 0x471f4e54931efcb7, 0x62987ece5de8170a], [0x7c4c1af502c4dd87, 0xd3f37c83884815f4, 0x61e0ddbf5a317579, 0x0690491b8a206706]],
+    [[0xbfc46ed87fa95356, 0x0f5a4c213e7943d3, 0x95b6df154182de42, 0x289b4cf08f1b15c3], [0x6f576920f17b2fbf, 0xfff5a485fe012f11, 0xf2110b3993e618f0, 0x21f7e3cd6d67a9aa]],
+    [[0x3f14a9e8f2c2a833, 0xfc60c564a85170fc, 0xd251526a2b23610a, 0x2c9fcf7300aeaed8], [0xe0480554189c278a, 0xffb37759d7097e6c, 0x136f5440ca85a891, 0x6aad51186dbd7c0a]],
+    [[0xc708b9224bb4bfa6, 0x0a15ece5e8cca777, 0xa49f4fd733220223, 0x473b4f34d2d9be14], [0x950941f6f6b8111d, 0xfc04106cb9f8e321, 0x60dc00c73c16c639, 0x59b45756e8659fab]],
+    [[0x18bf9ee4788a1085, 0x7d311628e17fa343, 0x7ac2daa3c10ed543, 0x1158024c151afcb5], [0x128def8e430c085f, 0x063371aa697c00b5, 0x784162be41ec9a14, 0x6d0a75551ff04434]],
+    [[0x96f4aac05d5ee909, 0xebd30b03cfa422f5, 0xe9154652520bbb86, 0x32eaf65280bb8815], [0x187da0fb720fcaa5, 0x89f034c2d32c12fe, 0x251ca24f2c16bb43, 0x31033824bb7c0433]],
+    [[0xe4c7470a49330676, 0x22df94f4d675567d, 0x4cc1d4c2519f7b8d, 0x059b57c7d7f7f5f0], [0xe252bf8f5b4c952d, 0x58bc42bf98a2412d, 0xf5771854eeb64eed, 0x26eb4657b978291d]],
+    [[0xc631aa834c8d7255, 0xebbbd1c263fa7ff1, 0x0ad9beece5df5208, 0x6f06940a8d4d6baa], [0xabd41d4e6c0dc9cc, 0x288d0b934089e80b, 0x370b7787d98afdce, 0x4f2e4c2454063ac2]],
+    [[0x01976fba99d48df6, 0x02a6af429bd0018e, 0x0cad8f891c24fdf1, 0x401810b9e36cdbfc], [0xdec788e38cd47864, 0xc15184c90aa0eb46, 0x265cb56fcf245319, 0x6489d2bb7d0e06db]],
+    [[0x62f5d4bbbebdd17b, 0x7548dcb27bdcc0df, 0xc99e67bdcf33fb13, 0x3d81b8786cfa5d64], [0x7bf4649006724032, 0x4903be648057a39e, 0x4be88dbd758ffbc6, 0x53ddfcfd53ba397e]],
+    [[0x8be7655f85374438, 0x7ecac9ed744edcb6, 0xa29b19ea28cb6f73, 0x2932afe56b147285], [0x73c6df711c530809, 0x683423c5ecf7b15d, 0x2aa8912993ca5aa0, 0x5a0b7f4dd6365bb2]],
+    [[0x7f2a81edc7fe1736, 0x2d35be5669c20699, 0x5a7ec4a18946a007, 0x591c75d8de9c776e], [0x2b000e0e5aab1301, 0xdee811556a869248, 0x490d8aabfcce1877, 0x5c5485254f34a929]],
+    [[0xe36b63d5190447ac, 0x74ec712ca04adda9, 0x2d268a57fc33b96d, 0x4befa67ceb9ca6ed], [0x086603e8a9b59788, 0xa7cb8a2cc4b216e2, 0x8bf7106d5e981316, 0x2f4ea4545e915950]],
+    [[0xd21af2e9e44905af, 0xb8dbc10f2fb9a666, 0x629f2ac9b24cfce5, 0x514559c625c629f5], [0x8216df67bdbb9580, 0x060ecb8dcfb1f5f3, 0x915dc6573eadab27, 0x1dc7198684764377]],
+    [[0xcefbe80931b4a1a3, 0x4808097c3c92eb38, 0x3f512a905071e47b, 0x27e84ab61f8a4146], [0x8e200126f2304d2a, 0xc32cb291c6fe1765, 0x8e48d411c081148d, 0x6253794924f0cd32]],
+    [[0x6f84121951fd9c27, 0x332fc94a868b4216, 0x4a4e7d45ca4599c5, 0x3eaa47ebf3b44f92], [0x06ce2d45f7ef5526, 0x933519030b3fa56f, 0xd662c026906d2bb4, 0x1664d641ba6c85c6]],
+    [[0x112e4c341b448492, 0x767595f70b45a1a6, 0x1b9916905cafe9b4, 0x2f183f711ce0d250], [0xacfd769e8d18309f, 0xdf606cc10610bd38, 0x51a0478d6f55c8d2, 0x1f7b862dfa34bb1b]],
+    [[0xe0fc2ad7d531251e, 0x092a3e7fb0c438c2, 0x938fb020315a61c2, 0x0adff0b52b79a5a0], [0x370755a3ae9dd5c0, 0x190e2cfd94dafc0a, 0xf40a5dbe1efdc57b, 0x089db2ac374fce79]],
+    [[0x1f9527fcd32933f9, 0x36bf1ded5c2071bf, 0xcf77fef9a7ad111f, 0x0e1da7e400c81f63], [0x3be8c4b9ecbafdc0, 0x98f948170a6d61bf, 0xd5f3b344169f8b18, 0x512792a05e3dd3e7]],
+    [[0x1cf6f671979cb3eb, 0x07a5adfae3a72bd1, 0x386d4d285f256166, 0x0917e48924333d90], [0xc6c1ee0ec48f7db2, 0x9b196ebcf7cae543, 0x6ded9fcdcdf9e6b8, 0x6fda1f40b1cf80a3]],
+    [[0xea162b7c362870b5, 0x34290069d6320f5a, 0x843539ff24753a73, 0x157190f2e619fb10], [0x52f214f868004dbe, 0xc993cb327cadd38b, 0x7744e4974208f5b2, 0x5a356c7beec15afe]],
+    [[0x656d31759e955693, 0xbf3d6faa5592608a, 0x18ea9d1ef92b61af, 0x0d012fdfb7a75b31], [0x32266cce9f96616e, 0xf3f7943212ffda63, 0xcef0eab9f213e58b, 0x00b76776736330d9]],
+    [[0xafda238ee6b39126, 0x63f27e491690344f, 0xc20074ec86c9cd36, 0x5fea025f3aa01e6e], [0x65510892c1145ab7, 0x5172e47806b0cde0, 0x913878470e8b5ed2, 0x405993b98046ef49]],
+    [[0x1ebb44321f8c3928, 0x89074c6242fb8df3, 0x111568a3185ad40e, 0x6cb42b0bc25938db], [0x4edeb0d8d1df3bb0, 0x1c96f620d14f30cd, 0x9e03f8b81931074b, 0x317a5cebf8bed9e9]],
+    [[0xd5f694c7e5a6657c, 0xca690747e3e72421, 0xa2bba3133c1cf71b, 0x3405f665d84c4cc6], [0x4a6f95c525ae2f47, 0x335404dfbcf51a37, 0x288180e110ce687c, 0x120f6b93c0c50858]],
+    [[0x4aa7d6aa79fdb94e, 0x65327540da84f564, 0x1be697c43abf92bf, 0x1a7fce46427c031e], [0xce25a1accdb7f198, 0x5a6b57b6a8c5e267, 0x398a390f9baf6739, 0x6d863a1035c72ccd]],
+    [[0xffff6c0293da2355, 0xeb9c8ea953b2bb48, 0x12a541905a2e2506, 0x2c23720f4c323181], [0xbda3674be8a833ae, 0x331961bb6d0426f1, 0x85b5105118823ba6, 0x51b36fd6c68a510c]],
+    [[0x3c1aa4b272800caf, 0x4a9d56a29a0fd92c, 0xd6ec9e1d63118cf5, 0x4b24edbea27b3fde], [0x31ea46543e1b6390, 0xdae650c0de9947d3, 0xfff1050ba4dcc599, 0x13a01437cf98bbcb]],
+    [[0x71022f704ae94b5f, 0xa345dcfe333ee841, 0xf06cd779a18d65c5, 0x19bd0beb0b6e360a], [0x41db0e60fd977e14, 0xe411f646e46372db, 0x730965008bb2099e, 0x1c1a67e1e1561fa4]],
+    [[0xdf69e1fb05eee7f5, 0x8e8adf23c7c8c961, 0xf441765aadb41eea, 0x0245cf7f2de58e7a], [0x9fa4df2387f1e1ef, 0x6e3bb9ab62c0633b, 0x22664351432fea28, 0x12e3fb737713c442]],
+    [[0x3fb2a274dc34e662, 0xfff41d62e8ad7b32, 0x072a98f6a1c8ffbb, 0x2199b7fd0ea56dc2], [0x387c3c0e3db450a1, 0xc4605f55eca8005b, 0xf97fe8600da1fd8d, 0x59ba9406c75f208d]],
+    [[0xb54ec53fe71dd142, 0x8d26aad61b093ab5, 0xa5fdeae0bda3f0f8, 0x6ccd6c928de37764], [0x2497c14f8c8bb2fa, 0xd08ccfe3fd2076b4, 0x9dd260ff76395aaf, 0x46bc14ab6404329a]],
+    [[0x3f337759ba984c00, 0x03b05c997ca263b5, 0x1a021a989fa8bf74, 0x602d1fa6b78da153], [0x75b9deb148aa16c0, 0xc3a85b1ff93d6317, 0x3fcab236b327c519, 0x58807c411675b3e1]],
+    [[0x39f5206437a50f20, 0xd6b0839c5c88ba3b, 0xcbc00cb41c2fd5c1, 0x11e8441b953b4ac6], [0x5c1ea33aa2ffac58, 0x88338e45f21c7eca, 0x0199958a1d36543a, 0x2d2889f876086565]],
+    [[0x7cc771d123fea1f9, 0xa67036dd71b4535f, 0xd53e3f0260915dc2, 0x1ada77065a2199b4], [0x665e5a4431242e0c, 0xe9449e772c3a721e, 0x9c4c6ed6641c0263, 0x346f8360c43b2479]],
+    [[0x6784e75c3178aeef, 0x8e189c31031ad70c, 0xb52fc409035b4b0c, 0x626e69a071eced37], [0xe8dbcd0731c31d26, 0x01cc25979fc2c13e, 0xa402ca1a6291200f, 0x3220f873bd7c563b]],
+    [[0x23a4c104d9288b0f, 0xb9c5499d82ffd6c9, 0x7b7239b80e9eb905, 0x6f85f2902d8178ce], [0x824afc4c3c084523, 0xde9a3e4d1b02a507, 0xca7aa7003df8c3a7, 0x33fd823ec9a3c727]],
+    [[0x54dfb029f552f7c1, 0x319ec77491028772, 0xdb6fc5fd6e01d156, 0x350096ccdb2561e2], [0xbb1f75c5dfff0357, 0xbf0360aded549749, 0xc3b4aebd8ac71abb, 0x3c96fee9d0bce57c]],
+    [[0xb3d6f68850f57cbf, 0x99e90e73aaaaa50c, 0xfb6afa32f5485662, 0x2a769b66fe873d85], [0xbf0163999d59d856, 0x29737ea8463d6393, 0x6b4e4219e79cddea, 0x3e66b0577d83af61]],
+    [[0x507181ad5636e6b9, 0x1d4208f5cf275ad8, 0x056689fa071986a9, 0x1bd65bfc18ab8b3a], [0x352a4ee9729d7e07, 0x4beb5b4726d641f6, 0x40d1589d13ab6589, 0x48f8c65e0ba9c176]],
+    [[0x92cbe35c31e9f0df, 0x0f5a0549523de58e, 0x4339738cbbf7db6b, 0x69ebfedc2966946c], [0x7bd852c2295012e7, 0xd41241bbfef8c03e, 0x38261def061c3365, 0x62c9c1c89d39efb5]],
+    [[0x10fd6b664d462423, 0x4eae16eb027f75ef, 0x359e0a1389f44803, 0x428a0bb02ba87a06], [0x3350e70c844630b8, 0x8ddeeec316303aaa, 0xbcf1c8458ce30b26, 0x52ac00db99c72d1e]],
+    [[0x46a5285b1ab38b63, 0x86a018e90ae1386c, 0xcc713ddcff461d7c, 0x39ffedf8d77cc446], [0x21080cc5a0bed599, 0xa33b86300bde1edc, 0xb9909214ce5bde6e, 0x10d7ea0b5a0adc7c]],
+    [[0x29029eec56a719ab, 0x368dd39deec4ebfa, 0xb2f87fefe5332b65, 0x61f11a862e95060f], [0x99c387512dd8b4d1, 0xb857a4e518611e73, 0x589f3ca0ae1ee3fd, 0x5a4578dedfac1538]],
+    [[0x0f7e4c56b664bd46, 0x4079ad1c048e5791, 0xee5a509de5755141, 0x55cd3396c5a7ebe6], [0x2f71564b73dc291b, 0x3d54a99e08af9744, 0xeb439810971b264b, 0x548946e0fb84ad0b]],
+    [[0x2af351193fbd0fbc, 0x3dd9c8125fbfe6b5, 0xabd4b24127b55bc8, 0x2b2928d04ccb29b6], [0x884d9499be22f9d2, 0x78dbd7abf724e7af, 0x548616647fe1d14d, 0x294e12142a027531]],
+    [[0x01eca496c3498180, 0x3bffec67725d2340, 0x7a6332cde8c7825f, 0x41bf6f0fe2d4663b], [0x8849d67300c63a57, 0x390df14a742de38b, 0x98deaf5724c65a88, 0x05779315d7cf1714]],
+    [[0xad4b2db50b5393cd, 0x639dddbd0f589c68, 0xd68208c2516d597d, 0x4f7f40b9de782918], [0x272f261e913d2710, 0x498974d0f53f8f46, 0x9623fae04fba1af2, 0x41504deb13d1637b]],
+    [[0x9d73cf2cc1f81d59, 0xfbfe119e636a5945, 0x025af292af803e81, 0x0c8b184b7ce37072], [0x14b4a143a4fe3dd1, 0xae3d8080a3f6b0c4, 0x1b927fdadf1ca19d, 0x2ae28e2ebbb7a324]],
+    [[0xe796b839537b4692, 0x42ebe88d89c5b6c5, 0x1b99f37fd20ebe0d, 0x3c66ade1a97777ed], [0x0574002f476759dd, 0xab9c918b05854573, 0x72cc0c4d6815365a, 0x147170ec514481bb]],
+    [[0xf917199f80033513, 0xf1b07de9a96e0bfd, 0xb7e3326ac8aeb565, 0x537397007669e4d9], [0x21fa8a6d7686e668, 0x8a07c250e0b41d6c, 0x9f73dad97110bec5, 0x19a436a62f43c104]],
+    [[0xa2ad5c132a96ce25, 0x97b7bb984654772c, 0xe109251b78d356a6, 0x1c351fd1bf77d588], [0x8fb379415e0257b3, 0xd86b8c4efeadd567, 0xb29d0eb14e067d1f, 0x4a258df7e97527d1]],
+    [[0x7a8911e3a6146676, 0x34ff6448525e598d, 0x98a9fabc78fb771e, 0x4c863bd5c584e285], [0x942f8089cb1d3d87, 0xbbda33e5e2568ddd, 0x9198f56282b74325, 0x1f9c082ceb541053]],
+    [[0x9d47ce825d7edd28, 0x2e5c983cbfcd161e, 0xb385a3a6e3c8e8b4, 0x2df543b469cf5b4d], [0xe75a32918189912b, 0x7febeefe42dacc0b, 0x2399e927aab427ef, 0x736325c29371b177]],
+    [[0xaebbf85a4db19061, 0xaba565843dd1d1ad, 0x04da15224b7cbecb, 0x3dd8847f0c09a38a], [0xa06ad389c8deac84, 0x6f768cec13a83ae8, 0xa17a5d9e2a3131b8, 0x07b10de608f7eaad]],
+    [[0x470fbdda6121b1b7, 0x9880f99c556e968d, 0xa3f96b379e03fa4a, 0x5c4d8cda3e8c09c2], [0x84635dead1764bd6, 0xa98742ee524ffe08, 0x45e981ae74d15c4a, 0x453a0a953f6194f8]],
+    [[0x3fa2170af864b600, 0x535f56cad80e3fbb, 0x5b05f14bf60f51d8, 0x2448e30361c7370c], [0x7d9620520932e291, 0xdd99b5e02d4daa8f, 0xec8cc9bff01cdb70, 0x693f59c3f311d950]],
+    [[0xbdf7a88659c2518f, 0x4c8ea12996ffaeed, 0x7e170ba76989be0c, 0x3b1bb33e22d4cdb7], [0x0067681d16c84d44, 0x55387726d368cf91, 0x9f10790fe629c81b, 0x545c8f039ec54272]],
+    [[0x87a88e22a9e3bf49, 0x5efed39dea49774a, 0xcb9b7fb2d6e48823, 0x16972b4e72146b08], [0x79c0f45e6d82bf14, 0xacc70f5f0d0d2bbc, 0x30ce2e1bca77a8f5, 0x1bd39b0b3cc80028]],
+    [[0xa1fc0a7c3d5ac789, 0x40fa73cd031514df, 0xc46b04cedfce9c0f, 0x4bf784526bb1f25c], [0x1ab8b101ccb925ed, 0x8bca9829c4e7f5ba, 0x0c82c47cf5d8001f, 0x135470e2a95db238]],
+    [[0x0bc7fb9ec2302a46, 0xb8814590bb3b8562, 0xcf3f633d2fe43feb, 0x6a0b88dbcf12310b], [0xde55f83d597472aa, 0x48bc782bdc726fa0, 0x55bd420af88676e5, 0x5f19564433a6cc64]],
+    [[0x6be5911806c9cf6b, 0xae228336c9f80d62, 0xd52bdfe6680f9225, 0x5f211cc8112d2ea9], [0xae8aa10a614c15cd, 0x946baa407e8be238, 0xb11504563efbc4f5, 0x388867aaefe06a38]],
+    [[0x58a4c1abf1ff8cc8, 0x3cc63dafbf0cf11a, 0x18bf730dd47e9ae6, 0x5388c8795c765c6b], [0xc9f01c698c044f53, 0x9b31561d008806b2, 0x4c58dddc33c8bafd, 0x0db42688525c59b6]],
+    [[0x7f25f099be45da9e, 0xa9d2872f9d8dd964, 0xa06a17583e709846, 0x44470e3c8bf1dbe2], [0x3975b2c1767c99dc, 0x8183884e415337ec, 0xacc2d6287d0d80f0, 0x19aaf3dbc6491373]],
+    [[0x5b6d3609e3fde9d2, 0xfed982acec075068, 0x006708fd88b3a1f9, 0x431e914469c4a8a3], [0x0ad74a72e82a0ab3, 0x80e386b17c076743, 0xe7bc8cb954bd0f30, 0x04c3a9fa4f36cb70]],
+    [[0xa730b954ba9dd804, 0xbf19ef84b2b0ae07, 0xe023b5caccd48043, 0x594860c3fbaf7f31], [0x5ebb2bb37dd658aa, 0xcd7bd10c78ddd25a, 0xdf1334b9c6d5fb4c, 0x604f355ecc429065]],
+    [[0x31495cdd581867c3, 0xd9256eb8503a245b, 0x87e831a8214b7bbd, 0x0611fe489182f318], [0xad7dd527e104c630, 0xcdc0ce6c75470eff, 0x8e8fa72befc0da64, 0x0a1afcaa23dc5373]],
+    [[0x0f5c399763e07f93, 0xfc399bd6dc5e696b, 0x093e6ba6ca72afb4, 0x4680104037520267], [0xd3dc5dd2a89d9959, 0x5f6836e449ea01de, 0x940183fac8c3e9dc, 0x31cdcefe6d4b457b]],
+    [[0x55637d11cdb4ea25, 0x43c7bd4cafeb3273, 0x1d9436dc56709e2f, 0x5ae68fc231ac947c], [0xc416e4516a4bf872, 0xf4a08b93d0cd4636, 0x4ca845d2edaa4717, 0x4670527846d329a5]],
+    [[0xa2f668725583195c, 0xb9a34f984105c5e8, 0xa521a680051956db, 0x0f19534178c90907], [0x537ed1e795e2a580, 0x7e51bbd5e596bfd6, 0xbda1e1ee4f123a55, 0x0632fa2d340f7c98]],
+    [[0x2fb16de2b4376877, 0x313a00d4242e114c, 0xba3a7149da59798d, 0x67bd6776db2512d5], [0x3e46dceb0da8c820, 0xc3f34842a8b81966, 0xa66762b200689cd4, 0x187496f56a9ea14c]],
+    [[0xe712bef3f10c8ab0, 0xac3ed722b6a55d1d, 0xc4f7087660b07332, 0x1607f2b15551701b], [0x22842e66a75a532b, 0xa0e4d4bd9a39c6ce, 0x7aa7489d6db5e354, 0x3ac56748ec9199f1]],
+    [[0x50168e712d2afcdf, 0x1b6cb662469bfe59, 0x40ede4ff292aa6c1, 0x4362a6b794538b62], [0x99763f17a4712beb, 0x1262b316f6e53087, 0x260890b4b99c7ca1, 0x384a922a443e759b]],
+    [[0xbe290d5325ed0c47, 0x7cb96f207fbde84a, 0x9b8a2538cf8bfd7f, 0x66346704094d94d6], [0x014cdad2dd87d0c6, 0xde077b51e34a6402, 0x82e366fcbc2ebd76, 0x1a6f743f69d89c07]],
+    [[0x142a7fcbef2e3be4, 0x26b1177d1c678a05, 0x687d3af85c60352a, 0x26950cb5395c7b8e], [0x5020b3a0d19f0bb4, 0x34dc533f66916212, 0xedb61fdb14b3b7fe, 0x2fad8c3e7b217454]],
+    [[0xb4f5618c2343811d, 0xabfbbc0fc30e5b54, 0xd736005fff6f3e32, 0x673adc9471d2ddfd], [0x71997270aaa7e8be, 0xe9abbd0844c00c46, 0xad98b5583580cc9e, 0x24551d3e5f5551e4]],
+    [[0x871e3d7d27395187, 0xcb76aa60d862977d, 0xb117f1c04ac649e3, 0x57c5396a6ce4120b], [0x6782716de76a3de2, 0x8c34294771f81c3b, 0x7acac0becce94930, 0x68b2d124bb0dae53]],
+    [[0x4a8f37322782127b, 0xae5da93f08510d44, 0x695f98b63a574d33, 0x5e8a1f27cd3cd429], [0x50dfc65a5a00cef2, 0x953639efe00200c5, 0x1d4756a9b9e056fc, 0x0460c0292def6430]],
+    [[0xae862ceedd7e97ca, 0x57683e07f67da44c, 0x7e2fc32a2ba0c658, 0x68dea610de294632], [0xf2b5b763551b7a7f, 0xe0ec110d1fb4ca06, 0x8b6f73c364a305ed, 0x349972abffa89401]],
+    [[0x4f5721bbce28184c, 0xb8bf325ed940b027, 0x392dca2e6de29018, 0x6e0631706fbb9b57], [0x123a3eeae422cfb7, 0x88a0dcdb5b470fec, 0xfa9e5137562d3310, 0x2fc385f14e17df43]],
+    [[0x9deff9ad95e7f45e, 0x99eefd13dcce3f89, 0xb29ca8e930ba76b6, 0x64b71d0973016421], [0x3f24a88fce06234e, 0xe561c6ac5f8c998d, 0x2a893f221ab8013f, 0x51276de0d4df0812]],
+    [[0xc5fba41f514f62cc, 0x743e16df7a8b6fda, 0x3f02973b7db15c3d, 0x33ef2581f9e207d6], [0x552b487cde6a33b0, 0xacbd441d9bfbb201, 0xf8bd43c0fd0c48f1, 0x5ca535f43ce50e1d]],
+    [[0xc5dd4c409b0f4e3f, 0x8af34cbb591f4fe4, 0x6a7d51fe3ab0b400, 0x1a1f0beb8e3ce1d3], [0x86bdad5a7a371f55, 0x0bdda682c780652c, 0x06bef09b5dc98ba7, 0x66eedf806de8ac27]],
+    [[0x608dcf9b695eb971, 0xe6094e7551d293eb, 0x36db1fba4135ce9c, 0x0719645f43c3cf11], [0x3f9005dd9950bbae, 0xcaa9bcc8bd69b67c, 0x17aa1301309cf832, 0x647076f1f89368a1]],
+    [[0x502841a95062d39d, 0x74c4caeb1c6f7a1f, 0x529c0dc903f64abd, 0x54d3d34e4983a4c2], [0x090e1ab87dee96f4, 0xf932d5c2a265ea68, 0x099509a49152b3c7, 0x60adfbaa72806abd]],
+    [[0x0070d30e5d116a3d, 0x383e6aa027e88dca, 0x25a777ca7d90948e, 0x09b40984e81b60c1], [0x97f066ad49b96756, 0x3827518769ef4ef6, 0xefb2d29728ec35cd, 0x6a2929697b29eaad]],
+    [[0x89484f56c1a03e2e, 0x5b59dbcc5ede4f8b, 0x3c348a883e3881ae, 0x37c95708802d80d8], [0x5a138fd493a6fd4c, 0xecaf40803b448396, 0xd2620d4580c0ba70, 0x139defe5ec1ace97]],
+    [[0x9d17540660ca737b, 0x688020dc9eb985bc, 0x769eb4df54283073, 0x4d18abba1d81097f], [0xb57bb6f570955336, 0xc59eb1e199890b85, 0xae68a0bc76ff3662, 0x3790ca8e6aedf670]],
+    [[0xb4ded519c2bb8eba, 0xdf247fde41bbcda8, 0xdf8956d2bfc82315, 0x00db6e986dee09ba], [0x4204f7a9224928fe, 0x7e3f2416c6182d32, 0xacf377b5539c8ae8, 0x426b405808e6bb52]],
+    [[0x55a2fa4b6c131eff, 0x267560338d6efb0d, 0xe06f33e132a3729d, 0x69648dcdd337f721], [0x8e52500f06dba62f, 0xa596164fefd17ba2, 0xd0dd9779cc4b46e7, 0x19993d20a33e032d]],
+    [[0x9cb70d6e7a901c0c, 0xbde477ca1c5d96e6, 0x80bb4b8244241502, 0x5f48409cba9e4e22], [0xbface16abe23242e, 0xc4d22dacec19a96e, 0xe09df64740800fc4, 0x73681fe6c06de6b5]],
+    [[0xec0517fbefe258b4, 0xa5342d78e4603683, 0x96fa513f92ff71e8, 0x39140b71d8102689], [0x899079d5fd3a4a69, 0x81d9d500d01c9bd7, 0x5c85153130fd55c6, 0x53f8ae16628d2841]],
+    [[0x00b1206570fbcddc, 0x9e9b055e957f5bfc, 0xc09ddb01c7d4e143, 0x3a6316083f557880], [0x8453817829531379, 0x807f411ee110750b, 0xb5d6d245fafd06b0, 0x54b0f0bc012b29da]],
+    [[0x1ea628ba27bb28c8, 0x1a73a6fcdff6f1f9, 0x478df4e67de016ed, 0x2859d9490a6648f4], [0x34fa1cf0c6dcfc59, 0xae089da922f4216b, 0xecc85f5c4dd559aa, 0x6ddbf8420a2b650f]],
+    [[0xaa9ce126e080e8df, 0x07eb36fc3b422002, 0x21ff198b61ee2cad, 0x0ad532516c87753c], [0xaeb3da04c89dd1e8, 0x4c7a323753c5f060, 0x4b24bf3c97077d5a, 0x634d85bfae25fb96]],
+    [[0x5006f6e92527f1a2, 0x24807f3a109ccee9, 0x55c9da4d1e201672, 0x4f40da77a919fc1c], [0x28e3ff9adf343ba1, 0x09f998f5c9841087, 0x8585efbca0518ad3, 0x39989e80a34d0871]],
+    [[0x2ad530b35271629c, 0xc8c834b832ca0474, 0xcb1cdb5afe88b950, 0x49328109573742f8], [0x6c831083694336fc, 0x5d575769a440bf12, 0x77b7e7271f440a61, 0x04dcb6fbea2c08eb]],
+    [[0xa72b7e8a528f71c8, 0x1fb36fb2956e04ed, 0x4ec889608de8d5ba, 0x27deecc933f5e00f], [0x7dc24bb572c7ce9f, 0x94a104acabdff733, 0xe7d36a0393a8b2d9, 0x3055eabc43be71b6]],
+    [[0xdd6b49474b0f60a8, 0xe64162ea461512ec, 0xdbd9a1ce074b54b3, 0x0855e323701c842e], [0x6b55769b818cf5d5, 0x377b3d877adeb477, 0xd9483103144e1837, 0x5074403e1a3e6891]],
+    [[0x30b6a2cdee9a80d0, 0xcdf35d2c418ab60a, 0x7ebc0914f6c0d96f, 0x689b752e4c695873], [0xba831cd422f52f1f, 0xb1a7571286decbef, 0xd43b152a817757d3, 0x175a3d5d705abee1]],
+    [[0x3f9308f3b11d8cfe, 0xf3dafdab9427a6d1, 0xfc7feb5202c6373b, 0x10c5da961b870222], [0x04524ef3cd374070, 0x7334e8de08087089, 0xa94de226f1e96e9e, 0x614d8faebda3a54f]],
+    [[0x380de569b277da21, 0xd0355b2e1683621c, 0xf50da53779a1c7f9, 0x61bf300246b62efb], [0x94684d2861aee86c, 0xab4793541e18173d, 0x580e1783baf0d02c, 0x4e91f2dd1a87bf9a]],
+    [[0x21bd1abcbfd1164d, 0x3099cb2bf59eb059, 0xa6971125cc850fe4, 0x3303bdc790f12637], [0x724528230f7fe586, 0x3ffb700cc3a8c00d, 0xf2a66c69ed0b79b3, 0x5d54364fde0bea67]],
+    [[0x9fd59aa7e89ab40d, 0xbf90264fd15f5d01, 0xdb1fb935414024ee, 0x51da01b60507b155], [0x4022fb431ee92ca4, 0x14cb397e515a9f6f, 0x1be6830bf8a46c68, 0x2b9d630267c68050]],
+    [[0x498511c8936979d4, 0x406c1298dec8584c, 0x4b938580eb390c20, 0x3bb1ebc373343b3b], [0x8ca8a8158112e6b7, 0x4b8b5620ff5efed7, 0xa5e82061d3f3eed7, 0x5b24799dde1bd92e]],
+    [[0xcf9a26158abef08e, 0xfbe48fa12e036376, 0xc9677aba1d3c6fe8, 0x7056e27785b24bc2], [0x56a144794ab22692, 0x0bd82902d0093617, 0xcd9faa3c9bdd7005, 0x2c2b5003b204dc81]],
+    [[0x135fa82de90e578e, 0xc2cd08437af8bbee, 0x49adac998d7be55a, 0x59ee4a0bc9ddf321], [0x91e203f42ee6af58, 0xd2a79fc010e3d19a, 0x1f3599d7682b5930, 0x3961ac47a8aa95e6]],
+    [[0x5988f284420e4407, 0x43cc36ac462e62f2, 0xef13f46e0c46f938, 0x3af3e07ef5ba8b3c], [0x393f3178cde49ded, 0x136281e404902b11, 0x5d0736af1da186b8, 0x5c3dcea1f7c02976]],
+    [[0x73f77c7814385e50, 0x76200ecad8704173, 0xcb4b5d9c3d95f5ce, 0x45f0ce6c76470cd9], [0xd62eaaccd866b60e, 0xea891fee1d87077e, 0x0adc87afc5d53ac9, 0x6ecff3cae9108265]],
+    [[0x3ebb003b3c504a22, 0xaf197417cae4bb7c, 0xd0924910e57d038a, 0x15f160ca1d4a82cc], [0x616fd91b4d6aa6e2, 0x716d2ec3956b3509, 0x7e11368618185449, 0x44a64d0bfc542cfe]],
+    [[0x63a075475f3076e9, 0x86226045064713ff, 0xde75f46bb95c7f53, 0x359b594be52e141c], [0x45d23ff3acfb34d0, 0x19677599cc2fd937, 0x63c7e6c3ef2317ca, 0x2c8171a744c9b11d]],
+    [[0xabd2920b571107c3, 0x36ac604c50244e39, 0x3b0d8c08e5acb41d, 0x46190642ec396eae], [0xd66a5fdec18ca5df, 0x604eb7f9e262c1d2, 0xa8fa68f2997a9603, 0x543da41604e5a02b]],
+    [[0x87d0d74dcd5e5f96, 0xd4d6266b5716d77a, 0x9e71588219c992bc, 0x1c530b66d8581e8e], [0xd4659de625bfb147, 0xe1d91fa3abe9118b, 0x456ccd600a516454, 0x13b266f14464d871]],
+    [[0xf7cdf592df93d711, 0x5feaa3619472b189, 0x49f84687728a0e6b, 0x2881c70397cb8a21], [0x670610cb4e00f969, 0xb2b3e366e9af8f67, 0xefef4359bd06085e, 0x1a9ddd55a18344af]],
+    [[0xa14a92cddb8ceb0e, 0x30e1eab67daffb3f, 0xa34d956e74e78d37, 0x2ddd1e5297c739e6], [0x28475bce2a3c076c, 0x0a890cf415fab1b3, 0x067fb51b987028af, 0x28656e9b1244b4bc]],
+    [[0x5cdf37a85020c3f9, 0x202cac2e42b81dbc, 0xddb99b2958b614c0, 0x0ba70e99de6ba052], [0x6dfc988f5155215e, 0xdde96ec4fbde76be, 0x14d9be2bac9c6d74, 0x3e93d3e956efad05]],
+    [[0x7ca544eeb006d9e9, 0x342445eb01196fc6, 0xc226a17e9f633870, 0x0331b82dd9884c0f], [0xf6761f98d855690b, 0xa94c3300e2632583, 0x082f4eba74f4f009, 0x616ce0287350c43d]],
+    [[0x56d0dba2c6adbcc5, 0x075c674fb566adcb, 0xd8fcaec83c04b74c, 0x1c2bbd2e28212604], [0xb270e49079141ad8, 0x76c04ca7e85cca70, 0xbd51895cd64ff8de, 0x05809b7f4fc3958c]],
+    [[0xc43d54edcdfab631, 0xf1a8aeae37affc3f, 0x5fb458df2d08b075, 0x46aba8286faa3bc1], [0xf73f2584c060244b, 0xb3b99af55f92b384, 0x7c4c2ecd48ce12c0, 0x37a95f418be10a46]],
+    [[0xbd8287c0ae37d2df, 0xd5b7e7c81026bef1, 0x35f0940d57a78152, 0x381eef4054c2ad15], [0xbccba449c3fde580, 0x61e8ea928175b498, 0xed5acbe562c20294, 0x69bdff31b580d933]],
+    [[0x1353653120582fbf, 0x7863e85f551a3c4d, 0xe007f448354af65d, 0x004912755cc76556], [0x67f21fc6cae578e3, 0x19322fbcdb5cab71, 0xb957f7d4ea6eb889, 0x68fb2569056d2087]],
+    [[0x31e7ee7a0dd8f2d7, 0x1bbaa3970fa19be5, 0x5c989826f5a12dc3, 0x321493b6b0e39e89], [0x528a4b3ed1071b2d, 0xd10f6ab83ff4c4ea, 0xfe1b1caac74e8e0f, 0x42e98afc9814aba0]],
+    [[0xe937f0a1488c7d3e, 0x9fc66d79a0dd761f, 0xb1a46d4b13854eb8, 0x64ca3709fd98282b], [0xb80aa5b2a195683b, 0x67e29e3cd9043047, 0x2a0d78133fe96f61, 0x2eb6af2bbd74ce03]],
+    [[0xf74259dc6c86a0d0, 0x5f571295b1c57c3d, 0x5d561662e52db308, 0x5d84a6b0fa449f15], [0x71acf0121f146d9a, 0xa51a8dfcd8ba7faa, 0x195ee787b37fc6d7, 0x091123307d6fe08f]],
+    [[0xb52d6b19638ca418, 0x55ab64d1edbd557c, 0x1155079a75f14734, 0x5d19f9c8b94679d1], [0x2713831b485d765b, 0xc0f1a49ffde2e546, 0xa6f907c6441f9068, 0x0b68e02733faa77e]],
+    [[0xafc88746a4f31411, 0xd2d193bbad910a39, 0xd490a90a43b40d25, 0x6d309ed0b5417194], [0x1b231fcc834968be, 0xb92fa55739946515, 0x0da54f1b7d0d56cb, 0x0e967bb3dd9a44f6]],
+    [[0x7c9f605c91144613, 0x666fe552fc6fdc1d, 0xe1cb687c85eb7fd8, 0x58874c27df25caf9], [0x6fadefe457ba943e, 0x58d3fc56ffdacbd4, 0x9a62381b1f02eedb, 0x29501f72a656d126]],
+    [[0x39aadfc59b3f8ffb, 0x2cbf5a7ab39bd42a, 0x95e6039e39ad36d9, 0x33e521540ee06105], [0xaf8524f9e8423b0a, 0x0dec3a573579a191, 0x97eb7533d9a6a5ee, 0x15825a46d2f705e6]],
+    [[0x4cb617c8cb91d104, 0xe524ae52341b9431, 0xb0015a24e213fb7e, 0x049e0492d757f9be], [0xc873bcdc4b03c28e, 0x2e851df529ab42ec, 0x8766d063eff503e2, 0x2617eab34633f190]],
+    [[0xf0f936a58b3d6971, 0x7d1271c0d7f35d5d, 0x9f294115a8d9d098, 0x5edf2350eea3bcc8], [0xfd514cd1af47baad, 0x9e81b585775ab8ab, 0xf9cdf04ac8915109, 0x51bb07d814af6da1]],
+    [[0x59e4cfd6b11e3ed6, 0x300749caf0709f40, 0xb7a362617abac340, 0x35185ed4acade901], [0x1569c93b45cf080a, 0x4ea94e2011a8f0ab, 0xc8facc38b081914a, 0x4fdd861115cbbfe1]],
+    [[0x9d8eabe034fd1555, 0xa110d71bd39ac896, 0xc1474c48d3623768, 0x5c0f5f5c92bf4ced], [0xb9a69014174b05a5, 0x71c7f52401bf6cad, 0x23d6c958cf79eec5, 0x6559ca0149f241d1]],
+    [[0x8d5f32ab551d2e49, 0xa2b550da9ee7accc, 0x178fcc9fafad0642, 0x62da805f972e8f5f], [0xbfe71cf50dcb171e, 0x2e97823c2ac123ec, 0x0963f0a688547301, 0x1eb61e49f6e1dc4e]],
+    [[0x184c77afa205b524, 0x342ef933c348a995, 0x179e57a58864dd87, 0x34c30f907ce7033a], [0x6a227209ab5d7699, 0x5cedd4c0b6f4d20d, 0xcac41e421ff0549a, 0x5031b82cb091e81e]],
+    [[0x9355a922f2918d27, 0xcc46b91af377646e, 0xb7c7af941a1d44ca, 0x1e95228d787e196d], [0xee0ad8d4cb5eda73, 0x7329dad862b5951f, 0x6478768e2def648a, 0x1c67a063b4316c67]],
+    [[0x8124c9efb22660ed, 0x91360e698aaf62e9, 0xe854de7dbe8b056e, 0x53925d0253aba61c], [0xdc385362563db184, 0x9dcf647e4a0a03ed, 0x3b23f186ae5d648a, 0x31772fdd3aac69a2]],
+    [[0xdfa0caecfab63e63, 0xb6d66d6dcd5d3dbe, 0x573165606b041944, 0x3fa58258ff36f725], [0x3984d76a27352f41, 0xaaaa21f693d050e7, 0xcefcefb93420f288, 0x5fe33ce3e96d9983]],
+    [[0x453915b652df82f6, 0x27f00222526a9d7d, 0x8d20ff3701d1f702, 0x05a4b8dc38dcf61a], [0xd6c3253bed9e037f, 0xd7f1e4f791be2437, 0x41b825e17bcb6ed1, 0x2496fef8f3bf32d7]],
+    [[0x5cc2b6da18e8847c, 0x2faf6ea9cf38a543, 0x65e2046a8efd8d73, 0x3dee14cbc20c175c], [0x5c9992708e1a13ac, 0x36cdd4cdbead2d9c, 0x0089b00073d2ef50, 0x191700bcd864b715]],
+    [[0xe828f37515f8aea8, 0xbed44b4877c4bbb4, 0x61e25e95aca79205, 0x1252bc55ea7350e9], [0x52a990c05c0464a3, 0x4c96bec2847cbf4e, 0x1536fa50a027009a, 0x5910a4a5af04290a]],
+    [[0x556e691793be85a8, 0x6b1372ac1ca183a2, 0xac99890cf219165c, 0x448df86a6b2712c6], [0x4efcbca619efdceb, 0x857a45c9481982b6, 0xde8ee3fa8fc789c0, 0x6b914b0c743bf1a7]],
+    [[0xf12695c56dcb025a, 0xa0d1b32b0dcb3c27, 0x13dcf0012431216f, 0x5fe9763f267819ae], [0x5bc3efb14f9c3faf, 0xe380d4995b0631e2, 0x0ad2952874c26f4d, 0x27c738f940b86bf3]],
+    [[0xcf247abecd57684b, 0xf62d48337b37ec15, 0x69ea71da8cda7279, 0x0c48774866383dbe], [0x49f1a41e3bba098d, 0x481c062c3626a85b, 0x27db023ddeb81a8d, 0x016f43c24f05d19d]],
+    [[0x17dc46315d7ce9cf, 0x95fa505c445df434, 0xcb199d095c5b8cdf, 0x7247234fb945ecc4], [0x8045c1a8ebaf7620, 0xdc11d191d90b665d, 0x1a3527599a49b680, 0x6beaf0221061ed13]],
+    [[0xf3430c2d387f5b5e, 0x4a859b268cb77c1d, 0x9fa74c323163f915, 0x1fd79aa867634bdf], [0xaaeaa9ab51795ade, 0x0fdce694c9d213e1, 0x146d6de9b39751b6, 0x4ca8d428fdf68515]],
+    [[0x87a9b0db9c5ac174, 0x6328d7a3a7794204, 0x40b80e8d049fa7a3, 0x5cfe1fab63246770], [0xfaa72a7f2832411b, 0x2e2a3916e8b3472a, 0x99c25803c92e6c14, 0x6559cc7bb3cb59d3]],
+    [[0xc67ecb054329cc70, 0xc37032828014577a, 0x8b70f610cd5785c8, 0x11da2fd7eee7f4eb], [0x61fe4dcbe04cd799, 0x38e8c46d8fcfcf91, 0x5799b871b31c05ec, 0x1d7444252267c80f]],
+    [[0x6615c6cbbacb95ff, 0xa348881029b0b25c, 0xc0118cdd0137d5aa, 0x5510e3ccd37e49a7], [0x4d1e9f89dfec684b, 0x6c12e8cc80abcb68, 0x5e1f65378fbd4e24, 0x4596b0ee3b3802bb]],
+    [[0x1683ebbbe9ee7b7d, 0x9c2065a47240b29c, 0x2ce8892077d6a208, 0x2d58aa18ad2622f7], [0x9d905d6eaadb78bf, 0xb187716bca28f8a4, 0x391c4f66fd7cdb1f, 0x1d0d666f54271e8e]],
+    [[0x58a0cc5843f1cfde, 0x9d0a5d0052d1da1a, 0x93ecf5540d78f5a8, 0x63342f20a0c42226], [0x8f611188eddaa586, 0xb5afcaa11a58ef41, 0xc70c4c8771303d81, 0x0cc5e84023896a5b]],
+    [[0xdb44f0be587cd341, 0xfe5032af4d4f8938, 0x5b2feae462b1e7bf, 0x5232526934d4509b], [0xd76a6466f639c396, 0x577b12219b752943, 0x3135b4e329ecf552, 0x63ea1bc2c6969d7e]],
+    [[0x84c123aedafb2a6b, 0x30689d78cd9f4ae2, 0x386e9aa0482fc2f0, 0x739e8bee78f8e811], [0x894d64d957459c76, 0x01d87a6c57f31a7e, 0x40657761f3f09b45, 0x3d7b664bb8fd2126]],
+    [[0x1e99a5dcf88f2e14, 0x2a9b4778de1c6eb4, 0x01c131c54ed354c8, 0x255aea585b170707], [0x46433061db48add5, 0xd4a5aa676277f1bd, 0xfe041603347ebf7f, 0x0173d6669176d9a4]],
+    [[0x9c9c5c8e591f356c, 0xc3211436182ccb4e, 0x9f5bfdb361dc61ff, 0x51b794bded3a7fdb], [0x009769afaa704ddd, 0xf95a3295d5aea6b3, 0x7b59d5826dcab466, 0x3e2173351e7a024b]],
+    [[0x94bd5c67629b2b79, 0xeb89f58a379afa00, 0xbe3515d8c6e3bb85, 0x049b9adb25f20294], [0xbcf1eed1061fc02a, 0xb17621c85862cfc7, 0xffada65bd8da5791, 0x516948b9e9e3374a]],
+    [[0xdd4dccb845e04193, 0x9ec493ecd9f57c96, 0xaf058992c9dafddd, 0x3722ea6ccf2d669a], [0x22511bde770ecff4, 0x5e28c89359fc7d83, 0x18d99daecca2c397, 0x3a69b771b6a00cd9]],
+    [[0x699f8594f437515f, 0x52a53c45a883d5b8, 0x5a8b514d8ae3d378, 0x08194d074b8170a9], [0x2e13ce6068d95dd4, 0x32162f5ed79ec414, 0x91ef2bf76e7d5b43, 0x4695a16aabe279e1]],
+    [[0xfdc2b5258c288ebc, 0x6d14139f0a7ead70, 0xd8e7e02939718b61, 0x595976151949f70a], [0x3def23b7fbedcf27, 0x8f3138f174f220e2, 0xaac67e6dd4867a38, 0x3f8528252a9a5497]],
+    [[0xd93b4bf9ce584328, 0xa80e960ab5f05a40, 0x71c616a96eb10ed5, 0x068aafa31cfa178c], [0xcbeadc7d837adcff, 0xa8a969b745890c89, 0x2e58b5504aed543c, 0x199e21f2f10a84e9]],
+    [[0x3a287580c9c90a86, 0x1a909d5996730662, 0x6f402dcd7709604c, 0x4937a53e0aafd897], [0x68ad52f5a601fc85, 0xcbee86b07f99c03b, 0x34e83ff06ce6d443, 0x639bf50e81cdb280]],
+    [[0x84797ccf852a1b5e, 0x55a99e26aba37bce, 0xa07b467d76f1b2f0, 0x5c5461cfe6b1590a], [0x659e26a790559fb7, 0xec24bd7e9ee9e4d6, 0x50f01e117d2e3838, 0x55d8f7031207ead8]],
+    [[0xb38a55b7beeee0df, 0x48d087df1f04cd4d, 0x608916db3daa4104, 0x2da6eb23b03a8141], [0x7fe5eabbb24b8eed, 0x0f61895863528978, 0x19c68efb622b81aa, 0x3295250d8cff71f2]],
+    [[0x62e244f022c8cc4b, 0xaca816514ddfe109, 0x33e20924353b3e97, 0x6930bc2edb78bdd3], [0x6a6000df8af90e9d, 0x3a2af8298527dc1a, 0x5728c48059c75068, 0x198408a217db1019]],
+    [[0x6c0935946967d947, 0x81622a74713c4e5f, 0x3a8710292244bd4a, 0x0964edfd17fb5e22], [0x96da863017540da1, 0x49b77548cd89483b, 0x3b7e4435153ac6d3, 0x0d53bb29300170ca]],
+    [[0xc89b90588fb1ffda, 0xce1369342eabfee8, 0x297bf8a8d41689ca, 0x5c16c183717dc874], [0xc56397a57a1a04ce, 0xae858cf858d5abcf, 0xd21968149521f6d9, 0x176a90547afbccfb]],
+    [[0x3c81e13134a8dfd5, 0x7f065ac8b6abb4a3, 0x2041acd1635215f1, 0x114e1cc8f8699f4d], [0xe77bda15deb4a322, 0xac468390812dfea6, 0x544170ede88652c0, 0x34adbf6d9e296126]],
+    [[0x55d1e784bced005b, 0xeff20ebde4360bfa, 0x3a59d8c3def66e60, 0x2b849f81db1f1105], [0x3a269db296c9455c, 0xbf0215a1e2f0c389, 0xc1928fb4b8e24a7a, 0x3dec1ccc78af7043]],
+    [[0x09b8eae5260c9523, 0x1b9663f655a120cc, 0x0b0816357dd96f6d, 0x58d3a30f5b1b8a3f], [0x881da3ab7570a3e3, 0xdb77069c614af5ac, 0xbb8630581207fd37, 0x0d9c2c3165cf50d2]],
+    [[0xcff904ac55f347f0, 0x51173c03201eb428, 0x342e8f97446a8222, 0x394177bba8e7b8f3], [0x4eef09245583104a, 0x1efa24a5893cc30d, 0x6c006dc47ffe3ee8, 0x1cd1ee47f3edca6d]],
+    [[0x46f2fcccdb61bbbf, 0x22f73e2c572f3a19, 0xd8ce1d974715768c, 0x32c0339abdc7a8b7], [0x135bb3249f9157b3, 0x885a7a8eff3670e7, 0x02e297c6a392b888, 0x067d35599f462778]],
+    [[0xfc36137fd7db6dca, 0xf0e4c099bd45fbbb, 0xd27befa519caf5dd, 0x0f88e994a1e1ab0f], [0x1a4f98c20ac073fa, 0xa764a4c4145582b2, 0xed18f98f2921859a, 0x1f0091a6491a0f82]],
+    [[0x9ca88be0eeca880d, 0xfcad8cbad2e461b3, 0xccdaa0feb2f6af3c, 0x06c6059af8c36e19], [0x176a425cfae8814f, 0x90e7864271baf3d9, 0xb5e24bd68a2ec073, 0x66a781e382f1b2ab]],
+    [[0xd20afa8268b6bea4, 0xbbbfd5d37c01f710, 0xc99613adb6508ca8, 0x06602f03634bd8e2], [0x8f68b7a2071d41ad, 0xb0f71a0186ac8311, 0xb25469968a6045f1, 0x53ba45f7584815ce]],
+    [[0x1dc795752fa4ef64, 0x3bbde3881a0e9837, 0xc97cc8fa99f016a4, 0x701f182c26ebbf52], [0x50e7a8cc5220a19f, 0xa7917f4ea5356785, 0xfc837ebef722ed7d, 0x5b978d8a0411fc17]],
+    [[0x9e71ba6f8499ae86, 0xae11e1e7e18cc703, 0xc67754fa6cff5611, 0x09946f1150f8e072], [0x6a8887f54fe067ee, 0xc905e4583417cba5, 0xe74f39f4dcc9bf3a, 0x1a3e191f2957831f]],
+    [[0xa600f48096608b18, 0x46ae34a498f141ba, 0x87c7fa3dced54d73, 0x60f3d37393f7b223], [0x2c1df766a82248cf, 0x4ebd6531972099ed, 0xd96e6c82b1fabce1, 0x638fc5fb5d50e25c]],
+    [[0x714a4e1dd4119de1, 0x6e6524361f584184, 0xfda7272461ba41ff, 0x2c65113aaa22ae93], [0x0e32ac6443c94574, 0x7e7e11c0e12a6eb6, 0xd821103f7c06f759, 0x648efc978f3a3dbb]],
+    [[0xb5474ff568f60288, 0xa539f21318cae948, 0x94ebf7f56e913f17, 0x2405796e16695c2e], [0xda1d2178f2a81586, 0x730f1b3e36aa3e71, 0x7d84b3bb7d6d9d07, 0x1602d9ca26d59c62]],
+    [[0x125a078c6c73ba7a, 0x65ee350fc57a2522, 0x368a06313cec20b9, 0x4d955c5c89d71f9f], [0x3202fbae02b629a2, 0x9a98a4c2bf0ee621, 0x6d3f199c2961f1fc, 0x23c58ca8c3a72b11]],
+    [[0x6bd170f1ebb035f7, 0xdd962210d69de9d6, 0x25f74dd43f1d7bdf, 0x52779ecb9bba82c6], [0x6728bf177b89a675, 0x4a5a2e5b5c2166e9, 0x5ef78e8cd5e51e25, 0x34d95f974a12747f]],
+    [[0x6acb47e5f1bfe9a3, 0x5ddc3ce59386621b, 0x3f8d218082867ebd, 0x5056a27393972059], [0x9c1688b6d6fa3def, 0xea39088513f66d49, 0xc162c3c0d2fd082e, 0x6100a34388a306ea]],
+    [[0xb5cb9bd345969c53, 0x8604d84503f6e1f1, 0x03521d254dbf6c9b, 0x513a614342139045], [0x8b1d3de3980a92b9, 0xc61566e495fa9c9e, 0x1c2237ec86484a9f, 0x2dd9424c651159f9]],
+    [[0xb66e9eaffc818585, 0xa2668dc1b8d62776, 0x7d4b0fdc0327dea8, 0x01ed8c9b8848c441], [0xe9160834a7c646fb, 0x1dd8ef1e0017c387, 0x58c1e1f4f0a59ca6, 0x117d76ffadc76a4b]],
+    [[0xb874c2a192846d93, 0x08892b593aa8d23b, 0xdb2f67098ac41e7c, 0x6e9ef977e80a1723], [0x1ce0da71da1bcc6d, 0x572bbdda0ca19f06, 0xb54f255e24d45072, 0x6335425ae1699c9f]],
+    [[0x8cc1a51e5e85cb9e, 0x934592d41ea4e6fc, 0xb00fa47e9865b3ce, 0x5919f9fc21b63e51], [0x7b983b542ee874af, 0x0bed4ac737a7e14b, 0x3cc85dd574f24345, 0x35d0b4abc168e218]],
+    [[0x24abe93f4c5ae2a9, 0xe7b00f4a00b813d0, 0x7fcef77705cc9434, 0x6b54dbc1f695e73a], [0xdb4b83a1c76c4034, 0xdbc4162065334cae, 0x194f0eaeeb931dc5, 0x65017b035357aedc]],
+    [[0x68e071581f6d6808, 0xb4e033d3122379d0, 0x232c58cbe9eccd44, 0x3bd84687adb902ea], [0x63ca9cad4cc4a6b0, 0x57c8dce47d719553, 0x2db08d6f9ab563d5, 0x4a170f35f590125b]],
+    [[0xc47ca36d5231ba26, 0x12eab110e65d9604, 0x63d8643870b6a55a, 0x2df0c3d5dc989efc], [0xbb8c7f14923edb00, 0x0efa8160627812d7, 0x10017356e592bb88, 0x08a19c2af690ddf9]],
+    [[0x9219f76b869a5524, 0x005eff508af5819b, 0xd7653013e23e981c, 0x3ff666e41638f479], [0x5b1a8958bcfed545, 0x1ec0663f17c1354a, 0x073e44fe4cf21bbc, 0x5a08364347d7190d]],
+    [[0x039c4570dc530c76, 0x8049f8cac496e1b0, 0xfd117be6376cdfaa, 0x4f64fef97e39f2c9], [0x19c008f3b4775593, 0x56dc92d5d45fbbc4, 0x3acd49c01de51c68, 0x5666634bbfa5efba]],
+    [[0x0b62ec1adf0a3a7e, 0x047a1f57026f7336, 0x80b24d419b385ef0, 0x4e1bf7de00d37699], [0x1a5d8b5d90ffad8f, 0x3e0dbc5e681ad351, 0xa88b47ac52ec682e, 0x22c2ba55bd9b4786]],
+    [[0xe927b5e1ecc407ad, 0x2109f8bd67583b69, 0x6aa3c5c74c0b7ce1, 0x32444feee1642bfa], [0x3590751aace5aa98, 0x806e3e0ad34c031d, 0x30e40f137f9f8ff3, 0x4e82c4dd6f1a6665]],
+    [[0x70737859eacc9d58, 0xc71be3886c2ede0c, 0xcc5e33fca85d39fe, 0x390dcaea8f4e347f], [0x401a74e590d7c7cc, 0xfe981434c88f26e3, 0x50fec08e3da83810, 0x05a99020db22937b]],
+    [[0x2b0878307b99d49b, 0xd5e3693619048bde, 0x57184cf68daa44e7, 0x0d3712ecd67dbaeb], [0xed3e0efa277b4908, 0xbe606c9cd481f00d, 0x0f6656ec120b0a4d, 0x550373c5560495de]],
+    [[0xe14079f921e91cc8, 0xfb3bdfafd8389fb3, 0xdb50f638803044bd, 0x07c3283aad6388d7], [0xd4f090ff054c134c, 0x4c4595344a8d282f, 0xbee6f849b625b21a, 0x32b76926822fae95]],
+    [[0xa9e9ba9b21164311, 0x6d1b570a8bb02bcb, 0xf516aec026e25484, 0x081ed7b396ed12b2], [0xd36dbea62df934a6, 0x5afe8e0deb87d60a, 0xe24b7a69a0348c7f, 0x09e52ed91288ff22]],
+    [[0x1eb3549ab1e7d47b, 0x67e14473c0f539a1, 0xc13ccb08a02d1039, 0x371e581ec89dbb9d], [0xd4d4dbb3c8c0d544, 0xe89244acf62d7dde, 0x90dbc0f6a0418cc2, 0x1de03e61bcbc5d22]],
+    [[0x6049d949a71064b8, 0xcbecb8a564b0f769, 0xc06e2282b5360ed6, 0x6ad6971574393e9d], [0x57feb38c79376eac, 0x9daff187568fef5c, 0x922ed1664028157e, 0x647bff40a72215a2]],
+    [[0x3943019e28f4c243, 0x98ec44e3127309f5, 0xfd49e7eb063c9b51, 0x29dff744a331cc0f], [0xe7f3aa9e0dbcf329, 0x1d20383fb963baac, 0xbddbba329a03e027, 0x68909a9202e5a5a1]],
+    [[0x72c6271e817dcbb1, 0x8ee3c9848ae6aca8, 0x28486af6ec755d3f, 0x38e1113d6118e209], [0xd66e8cb44e58c5de, 0xa374b76be59b9514, 0x8a92c1e445ea16ba, 0x5cdc4ba5d6b9cd4d]],
+    [[0xbde671591dc5e22b, 0xc127f2590fb201aa, 0x16455016d2259a04, 0x1e5f530ff94a9206], [0x8525e4444d602bd8, 0x656feb83a79aa909, 0x3047e222fa2cc910, 0x6b101d85a2211460]],
+    [[0xae3af15abb393bfc, 0x2fee113ded1c2a07, 0x3abe4cb365a78d42, 0x56fd4732cad42958], [0x413f1ec493dc3130, 0xf87f01188e0e4f97, 0x45d7d51a52606cf9, 0x1fda3aff8b840a79]],
+    [[0x853489030af71afc, 0xbbee21572cce1fce, 0x13d030723860fc81, 0x09eceb7e6af32728], [0xb555732230f72de8, 0xf3f8d301fbd26d07, 0x1568009deda2b0fa, 0x33e83045cee588d9]],
+    [[0x1e1a3510d9bda2bf, 0xa7ed4f7d5908f3ff, 0xeafee7652cdc7f5b, 0x3f1d0698690c151b], [0x0820a5abbcb1ae14, 0x7eda42103fb28778, 0x5c316e1fe7ce91b8, 0x058935547b228a4e]],
+    [[0xfdb0fc91d43b6ee3, 0x1bc2b82ee2770c0b, 0xb7ff243f2eca8cf6, 0x2c2c9068c43f2b30], [0x36c5964b6050162b, 0x7321066e69d7538d, 0xc4d16401db9723eb, 0x34963bad642f3793]],
+    [[0x6170799fa852ee1c, 0x7d8f0e205da4b765, 0xac49ad4f88380711, 0x161d605dbc35dfbe], [0x7738de6e703b9d1a, 0x9ffbb92dc2f947c4, 0x63f4c57a0f0baba6, 0x0c521300eadca7a8]],
+    [[0x10852fdc4ce2ca6a, 0xfab65f5e9a96b6cb, 0x079251e805ae0026, 0x08c518f42778148c], [0x13030c3a18d74885, 0x9458643bbf497a8a, 0x3f7a11ba6e8d2789, 0x2db418e7fd72e059]],
+    [[0x975e1fe4cd151c09, 0xa0757aefc61a1293, 0x07a7aa22bdfd3c55, 0x0fc87768d13a1cbd], [0x7e9dce79311e020b, 0x76c06eb8388b17de, 0x74dfddd9b08cbed7, 0x4282d33cea8987a1]],
+    [[0xf8d7c7f8ec4fa0f2, 0xcdc15993e210b415, 0x79f299534a54796c, 0x3304398076207eb5], [0xfd9bc92f72a6061e, 0xa076377a3d977dbb, 0xdd100ed0896bcc87, 0x39f97232811622dd]],
+    [[0xccd2f23ed1051870, 0xac709e208e21a467, 0x4c81deab7c95c2b5, 0x6321527cfb9a41f4], [0xc66d91aeb354397d, 0xb41dffa6d350b679, 0x27ae8815863acdeb, 0x064493cf1e951f50]],
+    [[0x4aa66316c4bffe1a, 0x1a36945d89311796, 0x59903bdf34ab155e, 0x14cb8026d69d38bb], [0x23814d4c6f43933e, 0x70426da835c0a107, 0x91d54322c4c8ca63, 0x645a145b35fc1eb3]],
+    [[0xfcebe663eee38d93, 0x56d8c465e2ed0be2, 0xd84e99b2c14ea643, 0x36e8809f06f26a2e], [0x3bc27c6e04991dce, 0x2c4e132fdc2f8643, 0x2f71f257a569231e, 0x33a1b94528b37d70]],
+    [[0x0c73d86bc452223f, 0xa2881b1855dc0d87, 0xe67a51132ff50289, 0x3003444abafea3a3], [0x95e4df7fb630d8ba, 0x94799617d5cdbfc5, 0x69f128e90861d672, 0x6788ac188d9ab05e]],
+    [[0xc8c5f6a2f9644d38, 0xadf37821ab5db13f, 0x6b2e761b2fcdae60, 0x427da59a3807d207], [0xf55af4a9d7f625a7, 0x16f1b721859c6df9, 0x34dd934b31993059, 0x3dd463c899dd533c]],
+    [[0x2f2c764e98f4b452, 0x4361c315f3fa5ca1, 0xe0c06f8e87ac6c38, 0x60339f355af8f8de], [0xb79d1cd266785aa7, 0x1210573b1e36de42, 0x02fc886043cbd89d, 0x497660b79ad7f9d8]],
+    [[0xac021285f13f8f0e, 0x116718b327ba9d09, 0x7f62a73a4daaa180, 0x591a64013fb7e0fe], [0x71867df90b6d4801, 0xd0b467c6c213bea7, 0x8f87e542334eda1c, 0x4e23acaf6874a101]],
+    [[0xd6a429e1104f9fc6, 0xf8f98622bb806a41, 0x5a0433b682baaeb5, 0x6fa9ead46786941e], [0x5a35f43edaf5222a, 0xc20722f3fd570590, 0x54f3f574cb499b0d, 0x594a39579823264d]],
+    [[0x3d6830af70cdb9f6, 0x80a33a5a7054bc85, 0x799cd251aa4e79e9, 0x38d5f798406ff139], [0x5e75b5c0833fa666, 0x0c223256325087d2, 0x2991229078556434, 0x5363a4c435bb5e5f]],
+    [[0x73aae6657097258d, 0xe61750524f373e5c, 0x0f14d14ecae81c84, 0x182ad57ec56175eb], [0x2257b2cba6a42b8b, 0x9cc0b22624d2c824, 0x0af9e4b99c754453, 0x2f44315e0a4e797e]],
+    [[0x9f77877aaebac436, 0x919231ae6e23757c, 0xd15266a44fe8c0f5, 0x1e70b143dc0a4985], [0xce030619ee6444bb, 0x66f3bbc8363af20d, 0xfec8707b51f47e5e, 0x0b86ccb0c0b591d7]],
+    [[0xc81b2dc171393c78, 0x051913cf54b43ea4, 0xad3f3f74873f19e0, 0x69e832fd329dd833], [0x029180aa4d404a47, 0x332d9200e6da4dbf, 0xff85c4ba98fcf264, 0x0d14847b902a89a7]],
+    [[0x79ff8d4f6efab3e6, 0xca3b14d704ec1ee6, 0x3facd8599729025c, 0x22e42c0bb02f8291], [0x83dd85c3c3b99b76, 0x6198346c553b7df6, 0x5a30ef089a947948, 0x2c6c81ba9f1b7d97]],
+    [[0x38df29410145ed8a, 0xb848d943b7639fef, 0xc26fb63518b65f85, 0x0417659a5747aeed], [0x25a8cfb7ac57be3c, 0xe4b58863e33d8a12, 0x5b5452bc6a4b82ce, 0x1b42e3cc56e4fc0b]],
+    [[0xce87ee613d005bf1, 0x899084606686c0d4, 0xb9178f73ebdc09d3, 0x3476b3979aeae65d], [0xa24487487767152e, 0x3e0e90e3c01fc806, 0xa36ce26b827b917d, 0x44283db63db75005]],
+    [[0x213789c0d56dd49d, 0x4861117568d875f0, 0x2b7702957574d656, 0x404af3fcfb556c06], [0x248c30322f14a665, 0x9beee5f691bf2a8e, 0x76a8308f825a629d, 0x4d9bffd87b3826d7]],
+    [[0x810f77fa1144f5bf, 0x784580d868732754, 0x8ee303414cb83ca6, 0x29d5cbca5c089406], [0x800b1ec34873a57b, 0x0137f44a8f38d5e7, 0x21c87cec33bf1e2c, 0x69e7b7632dc9c352]],
+    [[0x0490cfd1ae5b16a5, 0x6491893ad119b667, 0x4a555dff1e551f75, 0x3a120e1057f13280], [0xff18bcfdff7b5a41, 0xf0ff1afa6d24f6fe, 0x59c057ef66fa07e3, 0x737b3f7b78a8b835]],
+    [[0x1605ea37362bb180, 0x65e0328818139354, 0xb1244ff0d5447b09, 0x50ffd03ce0a26508], [0x08356772d47ab499, 0x513e688d9037b7ef, 0x7f1c21ab2e4cea57, 0x0328f0df3dd8347a]],
+    [[0x7f07e250d738fdf5, 0x322a89f4b45339dd, 0xc74fbc487af6e5f4, 0x0fd8a8e65c8f9def], [0x300681b772a0a3e2, 0xcd3018e6b08fca9f, 0x4adae854919bcce2, 0x1a97fe6ae0c1fdd5]],
+    [[0x3a5e736b15f269b8, 0x1c49e3d4e4d92eda, 0xbbeb5e466bfeaedd, 0x6cd7560543f1e9e8], [0x52c7bd7e1c44db02, 0xb0d3af35e553537e, 0x43a2cdcecc0412b2, 0x6a4003dd05d9a212]],
+    [[0xb4705bec8bf2e57e, 0x550727cfa58d549d, 0xa7e4a3e23aaceaa4, 0x1cb15b0929a2c8fd], [0x984fb05ff60a970e, 0x9ef57aac7cbcec94, 0x8c07fb7a24214416, 0x2016dfd91587bc03]],
+    [[0xe790a35576025d12, 0x04bda4b4a6a8a649, 0xaaa380211402d2d0, 0x0d6c410daf76f1b5], [0x5000018ca2695e13, 0x476e9a05561f3b70, 0x7213e0b7d8c36d71, 0x1d6e3ad8b2b395e7]],
+    [[0x6967043bd1e3a3d0, 0x449ff6dcdacf4c14, 0xf3c75fc8c228140a, 0x346ec55b09aac37f], [0x9e7d06ffbc171d01, 0xac0dc95b23a4cb83, 0x34f7c9d11bbfc4ed, 0x4d6d05cee55f9d6a]],
+    [[0xa0dc1cc9b913f0e7, 0x1eecc364a897d732, 0x5f953fae446a852e, 0x042f937b1ac64438], [0x0f99c6347151312c, 0x6d8b66d4fe3b60c1, 0xe43aa832092b32b6, 0x62ed1c41a7c07ab5]],
+    [[0xfdb9046b47c71379, 0x452a5e845b09db5e, 0xf67cf6ec860a7082, 0x3e42170c2aa11964], [0xc7da07a3d0b58c0d, 0x6129a8af267e589f, 0xe6d23a2775c5c121, 0x151a57868002d64b]],
+    [[0x4d7c7e31531fd6da, 0x9866596e53f641c1, 0x035bce46b3565c78, 0x735525cc68f9d3df], [0x839cf662085d0172, 0x4c95b4d34450ab7e, 0x466d44a25a18d68f, 0x134b509c28089fbe]],
+    [[0x9c897c2aeb409564, 0x3b091f398d16e864, 0x8b9f78f0d785cbfd, 0x32f03cb4f8dbe67b], [0x0350984c7509c21b, 0x13aadba980f03759, 0xa7eb29c63f25b0a9, 0x177509f977c4ec93]],
+    [[0xd1dfdb244cd9c431, 0x0c52300c6a1d7faf, 0x90f0e94d6b308fb5, 0x67f5e20c24cb82d3], [0x95a7dc2ea0852618, 0x45e9d8b320d03b93, 0xc8143df8fdf07d1b, 0x19244bc5da4bd27e]],
+    [[0x040d3d1857b5850c, 0x7a93b85842a0e12c, 0x31232acb780b6d1c, 0x6fb5fba40dbe25dd], [0x58557646e9dda886, 0x02d409ac88870b92, 0x4a102675477d19a0, 0x4640ec15a331bf06]],
+    [[0xa143b4b25cb24ac8, 0xdf56771afc5abd4a, 0xa8b621c095cee09d, 0x59199e89c0b883f9], [0xd7d063ec3b976d68, 0xf104652650343988, 0xf18dae27ffd58b4a, 0x5c94e921687ebadc]],
+    [[0xc2d16fdfb9772038, 0x1e4a7fd020dc690e, 0xd3a28b217cd0ab72, 0x631d2100b9a9d7f9], [0x79fdb9009cb849da, 0xa19bdbc32fdb2367, 0x196950c7503fbd67, 0x116a8ef104fa1933]],
+    [[0x7dfd723c12decb26, 0x7e80fd5482f6b29b, 0x892aef1c78ef9609, 0x1e84d30165a129b5], [0x4e4432374d8919df, 0x38e408317fc91f8b, 0xdd590c641e4060a4, 0x4bea9dfb6f3716c0]],
+    [[0x1cba65a038891e25, 0x11d2b12cea994af1, 0x4e5d9573b5122f94, 0x2ab1bb477287b81d], [0x354c1cd58d7443a9, 0xfeb35e5422c0a361, 0x230cab90f658bed2, 0x583081d9917bc36b]],
+    [[0x6104f197ffe0c520, 0x4e01d7510fa29c0f, 0x165d5e9c61af7708, 0x22d8da180ff66c5e], [0x245ac086756f5fdd, 0x7e043ed991a8551c, 0x179d9a58b41a5c82, 0x538b935152fcb776]],
+    [[0x073c43f389813fa2, 0xe6ea5bd6df1a67f8, 0xe3a2e8aad34698c7, 0x4d6162cb14de4538], [0xfd30cd78c8e60aef, 0xa39c7c8c69c5fc71, 0x52a1aa4a608d481f, 0x44744e7d3499a637]],
+    [[0x560373d1e75b2472, 0xe1202b119de3d49b, 0x876910880b8a9ec8, 0x168cbcb3faba26c6], [0x2cb1592afdbcb7d1, 0x7fb68ba84817c275, 0x6c39c20be66690f6, 0x5174feab9547034d]],
+    [[0x4a37e055af2586c8, 0x8e41523a74cf37d5, 0x2b2fe6464843ac93, 0x5e10e413226c0277], [0x554d341d018bde51, 0x6b5fc9633869455d, 0x3d8213a9d6040da8, 0x06011b284cb53582]],
+    [[0x138e7a64ccefbefb, 0x3b7a3f386e9a4995, 0x5bfc00c4cf61f41c, 0x2313184b2408dbed], [0x603ba9be7fd55c14, 0xcc2adf45bfa627ca, 0xba1fe71360ddffce, 0x47581f28470ab066]],
+    [[0x299e729b771b15bf, 0x3e4e5cc7abddef50, 0xc85a0cab1dd1817c, 0x579c5f3aa46467b1], [0x3319f307050021ac, 0xf3bc75af05e1c98d, 0xa8b24d7b56dfc7b5, 0x0635b1ef58d44a8e]],
+    [[0xef61542a6494642c, 0x37ec4a2f492f6e9f, 0x18ef83498aeffda4, 0x1fc7bc25a987936a], [0xd0dfd0ab5f5f00c4, 0xe1db37b0cf4b3ceb, 0x657878ed61928870, 0x44917bb0a10a9c57]],
+    [[0x759e2eaa6f8e7b67, 0x6d0f6e3d9ab7137c, 0xd039c675de2a41b6, 0x35951cb6b08d6124], [0xa675d9565c766dee, 0x619e77d11bc3ab81, 0x06e602cc1c4556b9, 0x72f7bc090627688d]],
+    [[0x3560e1ac46c599d9, 0xc442c5c4b4050aab, 0x7fdccf9d7e9f9ac7, 0x434365e986fafa72], [0xd3094c4bf58f4385, 0xde8b4d7f6a8f684e, 0x49133bb3f15b0b8b, 0x1ff5263e20a2ed16]],
+    [[0xd94071ae009aedcc, 0xfd7428d476cc9d9d, 0x0dd97ff8cb78d6b5, 0x2e6cd97adc488af9], [0x11e7d4a4738b4bcb, 0x4d18f3f0f8480ec6, 0xa916f8bc5386994c, 0x270ee9eaacff7705]],
+    [[0x25480d46ac8b31e7, 0x6a6c83c5f8af2304, 0xc3bbeae20e13470a, 0x3e17753369de3e0c], [0xdd291d58714f9482, 0x70f5423317a54c5f, 0xd0e19a0baebfc082, 0x1b408d748c0609da]],
+    [[0xead358e4c783fe2a, 0x810f2d0b82bfe864, 0x6719d8a4e84a2430, 0x5670c88f6fa785e0], [0xa8f6dc85f76be79b, 0x70284274855d874a, 0xb9e87f409cf9e4d6, 0x439b9f00f24a1daf]],
+    [[0x40f55f97c402e88d, 0x7999d0424bae2562, 0xfb4729daf279e7ae, 0x18055a0cd2ee5fd7], [0x350f9d675c7ed232, 0x40b4fd0664340c9e, 0xc7230b1363d12f5f, 0x1c67e26876da0b9f]],
+    [[0xcef3094affff7bbb, 0xddb58561b7b22f94, 0x9167706a73bfba9b, 0x395e6c5ad4e032aa], [0x69e617146a9f808e, 0xa3fb684ebf927adc, 0xdfc091899c3ed46b, 0x699709f7569abfd9]],
+    [[0xf46db06a3b3277a7, 0xd8b7f79e736bbe15, 0x3fca2e9986ec1a08, 0x3b0d85171faebe0f], [0x95fb64613b3880fb, 0x8a8d1db662915846, 0x6cb60b478d6f0e3f, 0x2f0f1bd8994c555a]],
+    [[0xcf60dba40813443b, 0x4fcbb083c4e16b11, 0x96f57feff95bcbf3, 0x566cdd2fab32fd45], [0x187da754f7ab1856, 0x4aa20e9758862f5b, 0xd2517f832e8d374b, 0x5d124488deb8b31d]],
+    [[0xf8199897a0407304, 0xd333f5aa15459946, 0x62341afefd61d714, 0x040c1d8d4da26ebe], [0x053cc09fda54c571, 0xdbd69bb04510eb88, 0xa3924d120c9078db, 0x4fccd4de74a21cda]],
+    [[0xc41308d459dfa372, 0x45b2c197e73fbede, 0xec72e02d373f4aed, 0x0f517455ee6480ee], [0xfbff75457f99e154, 0xb665e4e851163181, 0x551f326162f23efc, 0x28fb76fe3486399e]],
+    [[0xcffca2c3889bd5f4, 0xff7d7dfae9b9da90, 0xd5690811f636bd9e, 0x05a9bfe3c01c6275], [0xa304a9c06f17b5e2, 0x082e1f71162ad68a, 0x693a4c9320811f97, 0x16800f73f5bb38d5]],
+    [[0x4b5d94d9e6ce7ec8, 0xf935109d550bd7e7, 0xd9a3c23c00970c4e, 0x2f0350e7fc974d18], [0xeddd79df75d9d148, 0x77ade106862c1e53, 0x93a879fe813acf1a, 0x2a8f88bcd3b1c8bd]],
+    [[0x91faa69791017388, 0xdfaedfdda945f9fb, 0x5e67a8254840e054, 0x4758897abce43226], [0x039e3035d7e54119, 0xcbdc7549de6af7f6, 0x96706a6c75a540f5, 0x40690efc2fed90b2]],
+    [[0xb290760a87d99836, 0xe5af200c42213678, 0x75376406666e8206, 0x361d2bbeae7dfa74], [0xd30e95b63cda86bb, 0x5b57708118dffef8, 0xe843b447d21bbeb6, 0x1b007fb3702a4956]],
+    [[0x37e6c9a43c480ff8, 0xcfbb346ef9c25f0b, 0x6ec9a74baa58ef91, 0x3dbb877920261087], [0xbcce364fa67265d8, 0x366dd7cb0ccd2b45, 0x7403406a388d4645, 0x0953c59850b03601]],
+    [[0x6120abc54bad2184, 0xaaa3444a6709317a, 0x866de7a0af96bf9e, 0x3642f637ab02d8a6], [0x6180f4c2a8388aa8, 0x5cdab1c3901a9950, 0xeb27f7d4e3ed91c4, 0x6efd36d235579a7c]],
+    [[0x29ae0659f5ef4dcc, 0x5c0bdf526901970c, 0x8d18ecc80c2efc29, 0x275582d9dab41482], [0xb51a16296efe7112, 0x651f0b4e0d2653b7, 0x6a8aefc8b0c0ea42, 0x61e6c91ca69ae482]],
+    [[0x6c397f1e8582cd0b, 0xc73df6ec53499e73, 0xcb11c3cbb4f029f3, 0x7224cefefe2ed303], [0x3df875d761148be5, 0x8bfbfb4b867279d1, 0xb7d10f2989307a94, 0x39a3c100d3228d98]],
+    [[0x56e07fee3fe6360e, 0x436e7564ec02de7d, 0x62364d27df0ae03f, 0x0e308b9b92c5c138], [0x4b99e2bae355d40c, 0x58373d5921f25922, 0xc34f8b6260383587, 0x112715072c1be010]],
+    [[0xc0f1b2acda553ab1, 0xc262e30ace7d5994, 0xa6027b3350d36497, 0x1798d6bc11c1acc1], [0x9354ddaf7a2a1cb5, 0x9290930c2b6f1908, 0xcd24a03b136a29a4, 0x5242c627c8372684]],
+    [[0xb74a61803dbf402a, 0x372a92eae46c9f63, 0x3f22135fbe76e789, 0x6b6468cd1682655c], [0xa3fac030361382eb, 0x9d32e763a7d0b394, 0xfed9542588443893, 0x4af90c0bedb64760]],
+    [[0x81f01a113191373a, 0xac0b8ef0d37bc45a, 0x6eb634ea3b1d0b22, 0x4d621fd939f9fe37], [0x800a9400b2ec7a5e, 0xc6539d7b4b4675eb, 0xc9ba31046bd66b38, 0x00375960906f53fd]],
+    [[0xc7389b0fa9880e76, 0xbe8a217c11df634c, 0x827cdbfbfaf448fa, 0x15c078f02237eff2], [0x4f0f27386ad7c423, 0xe144ce2cd5c51e9d, 0x9841bd945170af8c, 0x65664898c6f1c951]],
+    [[0xdffe498dfa803e24, 0x945d853798ac47d7, 0xb88727410c2cb466, 0x4ca640cce1d0c896], [0x482b9a3f15026dcf, 0x273e3bbc0bb30408, 0xafa86f696e948cb5, 0x35045050ed338e61]],
+    [[0x815fb0853d34dafe, 0x2e7d04dd08378a27, 0x428b3f5c7753551d, 0x2944895f3e9989fd], [0xad7a740ae6ed221c, 0xcad133e1151ba653, 0xa940425d258508e7, 0x12ce904a2b9b276e]],
+    [[0xfa050be4383fa9ea, 0x369c81d7889f112e, 0x6cb3bcbf782d3f34, 0x71ed126f79e26e7f], [0x9a2cf69e8d5fabd0, 0xb6341b2a5950969a, 0x472e481d0ed677b5, 0x6a4703360ce621e8]],
+    [[0x8ff075a23aa00d1a, 0xec242519c0861ace, 0x817a99f9d7bc08b3, 0x27fa97f102f244fe], [0x99aea62c549000c6, 0xea4023141e3de9d6, 0x81dacb4d5aa6f91f, 0x48e561605ff38f54]],
+    [[0xfcfb48d9893f9ced, 0xa74a121be773ef21, 0x80e19058da4d162d, 0x13b33262aa4ff698], [0x05755da623a6466f, 0x806937f0b0f6ef78, 0x30412dfb807ccecd, 0x386efb1da619e1f9]],
+    [[0x9b8be4d5cca4f59a, 0xde3e365fd0834f44, 0x3b59377003ca4401, 0x2c55d17f4ae13d14], [0x1db3ba6467202c5c, 0x4dd45615c223eac7, 0x5ff788f7c4ea6831, 0x1ce4b32cc0328fff]],
+    [[0xed58326d45fdde4a, 0xaae52d3781aa8585, 0x6e83fc707cdb87ae, 0x0c7bc73d9bd8e7e5], [0xe736fd18f7fda821, 0x1a5ae225aa6fe3ba, 0x7a780d25660ab167, 0x49cebf3acb2a79ab]],
+    [[0x7aae14e9fb0740f3, 0x42825cb619c7ad30, 0x79301f3dbe4b053b, 0x434d5b3e598678b1], [0x596fbc9039b8e1f1, 0xd22f1b6dee9a982a, 0x33767ee98d3d6df4, 0x3cd2df0153a2ede1]],
+    [[0x0bbd9b8da8f82719, 0xa1e5d9721acd4195, 0x59847b3d5764a91c, 0x522fdbe8819ab654], [0x089d721c7f79cb73, 0xd8d08007a3668735, 0x9976f40e95db9ee9, 0x2aaf9a9b0f9bfdb1]],
+    [[0x3ac4214581d11682, 0x399d803011722971, 0x5ead5ad5f2ef9c6c, 0x346d30cdc4e663d2], [0x766191e86a8c1187, 0xf94cac8d472c31a6, 0xad99b11d97dba38e, 0x4897c9d594080926]],
+    [[0x1163f08580f165fc, 0xa90ef902fe5ecb36, 0x816e2f922c6a7975, 0x025a07049f448f6e], [0xb41758b1cbedcb1f, 0x777a38c93d528c7b, 0x6ba18d904d2272c6, 0x71eafbba3d20d14e]],
+    [[0x2f76b1d2a13abadf, 0xf5e84419ea8c4840, 0x1f4711ec95643354, 0x18f81e57dec19685], [0x5d843e316e1bde7e, 0x159eb83911f5906b, 0x2efd1b0888f9db83, 0x01c05471908ab8ef]],
+    [[0x1714be0d2ed755e0, 0x1de5969438577075, 0x1b5c0ef2708dfae2, 0x0f601d7e71eed4e2], [0xb0acaa013db6011e, 0x3542bb56259cce2d, 0x4122bdc51ad76900, 0x4ba84d366d9cedb6]],
+    [[0x9f5d5382b8210a99, 0x7dbf3434cf4ab41f, 0x52f1d05ed8acd3d0, 0x16ba1ee4c53a7922], [0xd0c90b45fa5ac8be, 0x3caf93f07580c8a5, 0xe0bc9dc415f20189, 0x64a11a9b32fe98d3]],
+    [[0x166229ea1712afe7, 0x0402bc15d0a5281d, 0x7651135de406a081, 0x248aea0646374675], [0x34e458a15ad85054, 0x0d1389a6f5414b50, 0xa4bafa5befc111f2, 0x443772c943bd1a4b]],
+    [[0x7ab6e4786377ede3, 0x2fe696cb688d80cc, 0x680785dd2b0a313f, 0x11ac57a08f5d322c], [0x67666d1a8246efa5, 0xee3707aa7c6a2933, 0xf7e6dc5ad17e5c94, 0x3f842501d609421f]],
+    [[0xa3ae07dd60f52e56, 0xfc58602418a953cb, 0x172ada9ea5ed3979, 0x439574909d58ca14], [0x336fa83ac64fcbdf, 0x043408ee16da17b2, 0x7ef060f4e5da7f7a, 0x3adc741d2c6d7a92]],
+    [[0x8cefcbd1faeb12d0, 0x3fe55fec573337b2, 0x6e22f0ff81d1a020, 0x5104b8b2311ff1c4], [0x804a10f8230c88ff, 0xe720902547abe7f5, 0xffc1f35fc36dcc35, 0x62e023eb1e2ef333]],
+    [[0x0e58df2acee5e085, 0x47fb96a6c981601f, 0xbd89f44814933385, 0x00219802b68f7133], [0x9acfa09bc9935392, 0x841bc40669f9d31d, 0xc1ac1431b0159836, 0x44c522f2804c03ad]],
+    [[0x8a61729fc47847d8, 0xadbfa028396ef348, 0x5befec9238712455, 0x2973767bc17f3253], [0x5bae967120e348c3, 0x9470e2b46e50cad2, 0xe0cb2ed851d59f88, 0x0d5573c0af2962f6]],
+    [[0xd701ef0b6989297c, 0x4c21897785d1c5cd, 0xb1726c0fb4895d72, 0x3ea0eb530a30a50f], [0xdbb97d63af71d6db, 0xa55731965704cec3, 0x4ff20d551f2a81ed, 0x58d88191ce3670b0]],
+    [[0x1ca3d1932e331b3e, 0xa10a921df20db296, 0x970d3b7f5b8db3e8, 0x6a4c10f34732bb4f], [0xda64679201e5cd01, 0xa2b7472d7f6777d1, 0x33afbc6e15cba041, 0x72b24fa52af37121]],
+    [[0xb099acb7a0660caf, 0xcc1f9902ae9ed1d2, 0x6036018b08619868, 0x70b51a4dec60fcac], [0x6b60668a89ec09e7, 0xe5482a36ef25d30d, 0xf44ac0342ff7f2db, 0x33998c12c3f807e8]],
+    [[0xdba521188e174882, 0x60eea5d5e3abd585, 0x467b96810a297eb9, 0x297621eab824f180], [0x75067bf67b507673, 0x62f7c54f98b176f8, 0x02f2531afc752914, 0x45d4571f05f8004b]],
+    [[0xc05f4a15f6166d03, 0x8e913c7eb49819d4, 0xfcc5ceb196bcc686, 0x170e645728d3bd8d], [0x5ac6a3f113478cca, 0x1e0091d2e98f1e57, 0x094293f733623718, 0x500f87161068608c]],
+    [[0x5002551d9e22cce2, 0x394add40d74defd2, 0xa88306b97dde6de0, 0x31d50c8485d3d0eb], [0x9a06e5d0e87c674b, 0xc61fb797df9e64a3, 0xe0c5ab99571f8a55, 0x28bc0510bf54d9eb]],
+    [[0xd535dda620a5d4cd, 0xcbb3741111b9432c, 0x5a1a78a0b6ce1121, 0x3137727e6f781821], [0x2aa959a0098eb7a2, 0x9f76dfc205822f92, 0x355c2f9ce936ae10, 0x3514026476b9a0cc]],
+    [[0x248cee469c67357a, 0x285a8d2d4c6392b6, 0x8aca5c524c09f3c2, 0x179bf785fece633a], [0x5a8ec7c6b8c0baec, 0xee1ca8d0355b2c2a, 0xdc78968726450844, 0x487e7eb1b7de5b4f]],
+    [[0x4c52e06e94ce84aa, 0xb9cf012c1e04ae92, 0xbbce5970ce16d350, 0x04d695eee29d383b], [0xb8610323876d5c2d, 0x5e2cb88ff6814159, 0x04fcbeff5dbef072, 0x077a892f677591c6]],
+    [[0x67497001720456bc, 0x2e2f4dc03fd85e78, 0x57ef68cb9fb7b56b, 0x347d52c0f605991a], [0x28e88a1130e9b39a, 0x34947e0ab7d1d6d5, 0xb918cf6481a50dfe, 0x2a3d55db5905bbc6]],
+    [[0x00f51e9a6b03f288, 0x05f7967dea3a92fc, 0x7cc1f75fe4c8795a, 0x0ab9dd909d299773], [0xcb08bc6f31000268, 0x0a6465ce0466cefe, 0x78b02df6e665c31e, 0x2659b8a2cc772e74]],
+    [[0xeac26e4139aa421e, 0x4dcef055d55ec683, 0x07e209c8cafbf439, 0x56105d9b27122ebd], [0x6382d7f0cd5c9f1e, 0x96a752aeabf988d9, 0xd5e6474c74171391, 0x1b92e2944bf6371e]],
+    [[0x9da1e927f0139e8e, 0xf54ba85ca13fd614, 0xb83a62f224404bb7, 0x2c5514f3d68a060a], [0xd993b3d11784aaa4, 0xccf61ea01260f013, 0xecb030f6a43b45dc, 0x11cfd9106ad06b4e]],
+    [[0xe7a4ebd6445866fc, 0xbbd02d48636b93f2, 0x3eaf5ca52d2a549f, 0x04a86ca7fe5f3c72], [0x2a48cfc9e9bc744a, 0x91386885ee696703, 0x229f8fc6ceff436d, 0x30827539c99091ca]],
+    [[0xcb3d52b64fc2abe1, 0x3fe96edbba31afe5, 0x26d6c64e181b103d, 0x06ccfee3dc3cf0d7], [0x808d4473e99078c7, 0xd5549d1cb4a04ea3, 0xfb6f132de153403c, 0x574ab0515e4e4449]],
+    [[0xd3047acdb8ed73e4, 0x8c70f0ec9c1b76ec, 0x9236f07112101327, 0x0b991348474f30a1], [0xc696b915a50b99d9, 0x698d7ad70ccb0301, 0x3d59a96aa8fb0d8d, 0x6bbb5f7c1f7b4751]],
+    [[0x204abb14c9a68d03, 0x87ec31a8fd3ab84c, 0x00d2336a115285a9, 0x36854c60c183dac0], [0x4004192997b8fef3, 0xf75a1217f4b63610, 0xdf3ab0c0197bca80, 0x1d46abe58f8dfcaa]],
+    [[0x7af97ef48ec42544, 0xabe77fca36a168d6, 0x26bbc545167d8cd4, 0x3754ffc019a247d1], [0xb8a1e748b31b6bc3, 0x222f716e1be92b83, 0x6d6fc4599d4eb7ab, 0x227228f551dd6b35]],
+    [[0x657f8122adfa73a6, 0xb3a6a04e9743b6cd, 0x24c4a22d83508d6b, 0x0d6c46fdecd316d6], [0x0d8fcb9ff60ac0e7, 0x3477e3d45ca91144, 0x8f45eb5a1ba5d8f1, 0x67b6178dc9f7f3a3]],
+    [[0x67f37d7be8e42e82, 0xdceca3137d490525, 0x86e380750e2cb148, 0x09d8b058d491d208], [0xa472259227e46ab5, 0x94184dc5ae174832, 0x6ca8d7e0c92bd008, 0x207508950cbc9388]],
+    [[0xc6f7882cc77dfc7c, 0x53c480168a1ac008, 0xf79fbdd21c2ebc4a, 0x457758bd895f95ef], [0x4a6311727cc790c9, 0xe46246c01d06c2ef, 0xb1d131d69024d6f8, 0x66a89b2b49374c31]],
+    [[0x08249d65e2660c9e, 0x05bccb2c1d941dbc, 0x118f971270197a65, 0x03954c74c3fd8dae], [0xfcab3e355d651de8, 0x09ea70d209d300cd, 0x0dfeb69acd775fde, 0x4373bd06e51e4e43]],
+    [[0xf5585ee9664efa4e, 0xa62d9bed004add09, 0x1e41d77e1bf6d294, 0x1604cd9ebdb4efc5], [0x439dacbd10d89a86, 0x544dea3e925bca70, 0xdfaa6fc942b73d27, 0x4ee1fe34d0827b4a]],
+    [[0x53ec7e0b775e2fe5, 0x8be8daa4cf2919e9, 0x2c19e0d057638135, 0x6a8ddb9e892f9e72], [0xd956dad1c4986125, 0x4d644c1fb4ce1a1e, 0xed17e79947ceade6, 0x340ccfa32cd4fb9d]],
+    [[0x1ef7153e990d292e, 0xcbcbcb3355ec3075, 0x20b1ce0ba2e5b8ab, 0x1a456fab907a7594], [0xd64d427acba2f558, 0xd2c44667c439e0fb, 0xc1d0438a13ced336, 0x2b89593947001c11]],
+    [[0x3481e5118f2f439b, 0x284337e3109fa9c6, 0x0f758b8eb21f6185, 0x015b4c8981a8f126], [0x1e1b13a79b305ba7, 0xf6b4769251b44d7c, 0xac358f480ebfcdf1, 0x4c771f1b0c308261]],
+    [[0x63420eb888e18089, 0xfa87fa33a76a985b, 0xf4c11021d10c6b5e, 0x2d9a31c0c794fdf7], [0xe99f4b727f9b3ea0, 0x90ecb1078bcf388d, 0x04dbaceabeaf8852, 0x37585598b6df8526]],
+    [[0xd8711ab9744ba01c, 0x612936aa5780b165, 0xdb7e1d8ac76362da, 0x0190dbdfd09cdad9], [0x6e9838d988e839e0, 0x58fb78d6ae7a83da, 0x0d578a0de7614a5c, 0x2cc999a9ec1e3546]],
+    [[0x3f8412d44ba444e7, 0xa2bae0a4de2bd421, 0x952f567c8f0f3cb8, 0x16f3440a5305e418], [0x07d888ce1f55f0da, 0xad45705532e518de, 0x85b3343741d778a9, 0x43eb0f4a8647d0f0]],
+    [[0x54c9c30e27fe9654, 0x1c0b5ed7042bbe89, 0x3c54f2d13a83e920, 0x0df17415e93ea94b], [0x0c09be753c2340db, 0xb3478c95e2abd858, 0x5210cf7ba0bba988, 0x394870a45771cbfe]],
+    [[0x083fc113c6c702b8, 0x2b7951bfeb78ae28, 0xda62b2ed4df35b98, 0x2033e4a3a24e7633], [0xc75c12f2fbfdc221, 0xd911017042d9a976, 0xa81b0b4962e1b2bc, 0x06780553ba6d41f4]],
+    [[0x616fa3a53224a28a, 0xb0a819d33dc33628, 0xe8fb48240352297a, 0x17809c4836094b60], [0x372ae684ba7009b2, 0x1c26b09dfdcbe3f0, 0x6c508ce685e80e33, 0x135fe576b3ee4481]],
+    [[0xe51a870502789398, 0x17510fb74276a4d8, 0x08d4fa838ab2e638, 0x1dc5f4430a6182f2], [0x9d08ad1b60bb0911, 0x37d3f5a22f7e22e4, 0xfeb1a4a4ebe9f1fb, 0x2cd973a45ec9aa65]],
+    [[0x1321c2f2b37442c4, 0x03a2074b4219cd8c, 0x0eb7313c7f175aec, 0x42be561f3beab6c0], [0x6869c6c2155f31d1, 0x4c60acbaea58e9f3, 0xd12e4176f14bf412, 0x2f7d91e484993c68]],
+    [[0x06af69ce884baa9b, 0xf837ff464b6eb1f8, 0xa00f770d31321696, 0x3e2df1f2acf58f21], [0x1239e3c211e5fae6, 0xb241936694471187, 0x1e2e21117d72516a, 0x405f2d5949bd9fca]],
+    [[0x966e338dbf73580c, 0xe047e3b715fb73b4, 0x2a44b39f71a2465a, 0x255ac350949fb4b4], [0x02ffe47ec4a130f9, 0x1377cf1728b7eeda, 0xd2d7560880f01e48, 0x22350006e27ccabf]],
+    [[0xedc52139e8ac8979, 0x9fdfd3a36baba367, 0x00dc6acb5fde2d64, 0x53348fbdc7dc1276], [0x04ab0229ca254349, 0x0b356899d2aa48ce, 0x8d1553174bc27fcb, 0x28c05237d5c4538a]],
+    [[0xba8da79f49cfeff8, 0x24980a90bbfd0ec0, 0xbed08fa664c3abff, 0x1b943e927e6ebc2b], [0xd9c80932c271b52f, 0x495a10000ef11aae, 0x5550774c7766ac27, 0x4ead5415909c77ad]],
+    [[0xd6cb800b7ecadc3b, 0x8d6a81795c5c6b5e, 0xc0aecb8b52bd8d96, 0x5064b7aa2b9bb53b], [0x0e7d68f261338385, 0xdc0938ef0a528d18, 0x196cd7dddaf03a18, 0x4f4a45c6509fe4bd]],
+    [[0xbf2b9acbdb75d0d7, 0x0916e04f414e9455, 0x2dfe31ee6984ae43, 0x512bd76ce4cb0baa], [0xc7ecba5fc0dd4bf9, 0x0166fa3e420910bd, 0x769a4a227122cc70, 0x031cbecd7164d204]],
+    [[0x700fb76be6898050, 0xb003c0771dc61286, 0x6e11dd1e66317143, 0x2bf4681bf8682aab], [0x4c1d73025e43381a, 0x8e290b32bc770932, 0x606842a087af92b6, 0x689a65b13026cc3c]],
+    [[0xa8efd25b4d1643dd, 0x3664bc937f7c16e4, 0xb8b418016c9e2d68, 0x23c1297e1e378c41], [0x23991aa030043553, 0xfb6da6414d67f66e, 0x7e404f7a890c7bb7, 0x3d500b24cb8d43fd]],
+    [[0x3524740273029780, 0x433c12c281e6c0ef, 0x7d3e25143c05a70b, 0x247b3735d936f884], [0xfe75ab086f094151, 0xe42b40b04e59f59d, 0xdf1d91f593b6bf95, 0x08a06be84e3ed196]],
+    [[0x8fa4b884293b5d17, 0x5e44dafd46cc5b39, 0x8a7b4bb5e7ca7bf0, 0x275d476dbd265c12], [0x4e2f0daa58b9d292, 0x5e0e7108bd9bb1f5, 0x134582e451593dcc, 0x531d6278d3c59434]],
+    [[0xbac920c0352018b9, 0xaeda235f7763acb0, 0xd14bd49b721b70db, 0x44a1b3c42de75449], [0x9c927407389e487d, 0x7b90af82e66e52eb, 0x87ed13f9669f4c5e, 0x22c34ecb3385b454]],
+    [[0x24f06513d727ea53, 0x1ecc9874250ace52, 0x007949248e818d2a, 0x43c7b3879d28a307], [0x5a578d377af1c17d, 0x5f051e76046abdf3, 0x392d5b5d84637eed, 0x1134a21b9b20b41f]],
+    [[0xd9067c5bed87fe77, 0x8a92b06b59b94d3b, 0x836b7471b890db26, 0x23047bc2e1880fc5], [0x8db3fbe44cd76f3c, 0x136c13d659866b7e, 0x50cfb4f6adf0b802, 0x30e8a6db7ad81903]],
+    [[0x4690a9ad4eafbe4e, 0x40412f421dd60700, 0x9cfebd628b6879f0, 0x10796691220e91f7], [0x56912820aa785259, 0xb8fce5708302f596, 0x3fc04316d6dbd852, 0x33f4fbc3515a002a]],
+    [[0x09c5cb33723f5726, 0x6b45e3ce0f6cd44b, 0x149e1337c6fc58d6, 0x3643032528aa834e], [0x817f2bbf830ac83b, 0xdfc56e77e86e473d, 0x42f12bcf82ae82d3, 0x4d194f7e9e4bdeb7]],
+    [[0x77436cefa4eed108, 0xcbf021656cba56d6, 0x47ff60ccb14883c7, 0x1a87ab842a9c31d5], [0x680bcf860b9c30eb, 0xd8a92847d4c6e68e, 0x40e17d646b04a899, 0x6fd97dbbeec16c91]],
+    [[0x9eeea843c86e052a, 0xb851168043b154e8, 0x8d1533d5a474d253, 0x0f29d185568e6c91], [0x25c0785f2392356a, 0x287d997dce55977d, 0xcd6c6cc5e6553f23, 0x211fab754ed9d193]],
+    [[0x0f980296ed6b9456, 0x6bd786c146a6f0f5, 0x75edbcd0e8af2cb5, 0x6ef0eb475ad04b77], [0x990abd1c11354baa, 0x341c1b5d261e428a, 0x2eaf40c0c893b0ae, 0x4bbb64b82070685f]],
+    [[0x31008be66a1ac8db, 0xe9c0d5ebffb7ff62, 0x9656524d1f7fb87c, 0x50775123a461d897], [0x4e95609b420a0be6, 0x3db03fd211f88d45, 0x1f607276af9a66f4, 0x10e69710c809f74d]],
+    [[0xbcf787fcbb789007, 0xccf2db11b0f45090, 0x893a264462eb4b5f, 0x20ab34a0337739df], [0x853a262688da4fa8, 0xe511ede8432c9c59, 0x141fbd14e9fb8a8f, 0x58ace9f2dd814063]],
+    [[0xe716cf402a45dae8, 0xf80a6d553d0be582, 0xe03641e461105438, 0x3976b194b102bd5d], [0x3963718fc5979602, 0xeed7e3a9190f621a, 0xf06759e552814cb9, 0x46343df444d31f9a]],
+    [[0x8a28d79ec1604c6e, 0x1c623b3bf240f511, 0xe2310a7cfa7fc89f, 0x5af16a507eeabceb], [0x29541107e3854f99, 0xfc9c86f2951a315e, 0x00b395e0a7f0c273, 0x23a679b8cff4e4c3]],
+    [[0x4d437758b208f4e2, 0xa04ddc5cf8f2df77, 0x672eead2e1bb3143, 0x04b9b73bed109b79], [0xa6206cac99c364b9, 0x59d0baf6b532f4df, 0xefd14f1ebe80ca30, 0x3f98369ce73911b0]],
+    [[0xebecb2aaf633f601, 0x9a11b7f2ea2bf266, 0x97be731f934bb9c9, 0x24084203dbbb62c1], [0xa2c54e706053f5b6, 0x221a7e42931ca3ff, 0x6df463bed72ea839, 0x1218eb97f5bb3b7b]],
+    [[0xc8c39a8052fa9a68, 0xb9aa1a4b4a18eda8, 0x3e06aefffdf9e4d6, 0x48a95ab47a1dd4c6], [0xe3693b7842828f04, 0x8d38308bf24d2e41, 0xff0f03f022cfb0ec, 0x3d424d8bbbcfb2b0]],
+    [[0x8969c3ce2dce4be8, 0x487f56acec2fa166, 0x408001e3b0418ec3, 0x6c7a0d41636d78ae], [0xf9066023f1053a84, 0xfcfb512f27c0c186, 0x30b819e361892a5e, 0x0a94d73296bf0cbc]],
+    [[0x5a74c66e81c6e6b2, 0x823fdf4de32d2a1a, 0x4cd0a6e461040251, 0x14640580023eac2f], [0xf93210791d06385d, 0x1b126064188ca58d, 0xb62e0a04ea029833, 0x3bd89284c009037b]],
+    [[0x0722794737e63ccf, 0xcd35f223197144fe, 0x88a5e4cc551edc73, 0x5a5cbd64054758af], [0x4d831f392aa6fe6c, 0x22e7d91f6c12bc6f, 0x9b6a5834175fb492, 0x181c3b5e0cc833c7]],
+    [[0x42e91a6651ba51c6, 0x62341edc32b82a67, 0x3de97f65931e8976, 0x37d3f62d5be36c14], [0x74adc6b3f4171d10, 0x1319b0514345d61a, 0xef317758c6a194d8, 0x3f61cfc78d4aa462]],
+    [[0xbac896c3033413f7, 0x193818377afb7a76, 0x45900575bcc4a196, 0x6d5a79e4ce351735], [0x940ec6e45de7c64c, 0xd2f9ec9b4c3af3c7, 0x93ee2d3877f61272, 0x541eeee0b3fc8d2b]],
+    [[0xcd7da303b8a05256, 0x7fc8360a42b14109, 0xb001c9ce790b698d, 0x18b2a9a74451ea61], [0x6811c30e6d61d8a8, 0x44c25ccb394b782d, 0x7ebea7a736d3ba01, 0x10c215d843f13cf8]],
+    [[0x7d1f80a2e10cd037, 0x100c3ebf9719c507, 0x02b0221eb6c8bc55, 0x009f4f85530c29b0], [0xb114a2ff179028fc, 0xcbca9eb4a843692d, 0xa57ff9d8922d54ac, 0x24be76945eb9db93]],
+    [[0xe97a6ddb0688013c, 0xc3576c662e67cb90, 0xc2f21e399938d52e, 0x2a96f219b8fb80da], [0x51d49aff5a4ec30c, 0x793a1ce853d20208, 0x8fce082797e7e2cb, 0x320ce1e338bfb7a3]],
+    [[0x35230bbb23f400c8, 0xf879d498b7480f0e, 0x5996add58019c29e, 0x1af03b31db942c87], [0xe85d1010bd3765b4, 0x7295cbb33131ab69, 0xe4a902e27e884d9b, 0x0fccce2592b362d7]],
+    [[0x2cf34832742cfdbb, 0x12d1fb97c0380953, 0x90237bdf6727cfb2, 0x0d29ba5ea3e56b18], [0x5e8aab0065787d15, 0xe931adc17a4bf782, 0xc100c40440984236, 0x46726bf84ab9d494]],
+    [[0x81b1f6ce856746cd, 0xfb01d1bb787a8fd1, 0x5ed6cf1b430bc59d, 0x61bfc8060e16255d], [0xac59f21c418b17b9, 0x553663bacc1272a1, 0x541e394daf916b9d, 0x2360665e80904372]],
+    [[0xfe22cf8ccae62270, 0x3f22a32605ae18bc, 0x18b51f0dbcebd588, 0x0d04b236f43b0742], [0xff990d29e19234c9, 0xd23f9216a92b6f7d, 0xf109d2b1dd86a0b8, 0x55e3df6665d7fa4e]],
+    [[0x130d37fedbf0f512, 0x8988d9b249ad5417, 0x3f5e857f523784e2, 0x1da1584fb1578dc1], [0x00390aa1bad64563, 0xbaa4ea2c8c2f5b47, 0x6c8ef65aa0c80a74, 0x47da48d8868dd905]],
+    [[0x334716f19f60083d, 0xbfd362a9a9ebb526, 0x2b5dc969dc2700dd, 0x6e67d98aae02ef9c], [0xd62355ef511c1c15, 0xd59f7d9d330920e4, 0xa089cbd65667f218, 0x0bdd227585003cdb]],
+    [[0x3b94393375078319, 0xb3463b5d26e2f487, 0x4d5d58305049ad04, 0x6681db02e3d87ba5], [0xc4f8fd4277220cc5, 0xc476e519180a8dab, 0xf5da1a6b33e2475e, 0x46546896e9413b92]],
+    [[0xeef9900a0f2653ae, 0x99008c815f0484f4, 0x9442ca400b163dc2, 0x56c098e5a48ab31b], [0xe93ee53360a537af, 0x561bcbfbafcc86d3, 0x731b4e14baa06784, 0x4f38c9f9a9a62382]],
+    [[0x5f6d0bcce2543cbd, 0xa779dac91f6bced8, 0xcbdeb34afaacadda, 0x29e4a4942cee5ef5], [0x53dc3adf35cf2dd0, 0xf12913cab2d613f6, 0xf781f28ea506dcdd, 0x1ddb7c433c50c69a]],
+    [[0x7bdc5d05746df969, 0x007fc4cdc1fe1f8f, 0x9a9461d05629d7ae, 0x70b03630dcf227c1], [0x23383c2250fd656e, 0xb548b7a31eb16f92, 0x4a152e82b8eac59e, 0x60a91b89895e7740]],
+    [[0x352aa91fb8be2fd7, 0x3684f35764dd9cf1, 0x509cbc2740a57176, 0x5294c6250a15379c], [0xf53a9e2065a927f9, 0xc6ac13fd92020cf8, 0xe32933eb20a53c54, 0x0f02b8450168076a]],
+    [[0x50344e94ef0c21f1, 0x85228e2471877fa4, 0x12624038328202ee, 0x54d0c0a7c7cd73f4], [0xc69cb557c8c2f82f, 0x61a1469b83d0fd8f, 0xc0598d1213777c69, 0x385934398d9bd684]],
+    [[0x7eff0f4310fefa33, 0xd7393fa046495032, 0xb455b509dae48e4b, 0x6e917330fd8f6aed], [0x27b84cf2380779f2, 0x32e743156105a8ab, 0x44f460fefb0f1cc5, 0x0b28cda994662c0d]],
+    [[0xf27735c37ce3fe9f, 0xd1b646c47a1bab47, 0x801718237c0d537a, 0x234060c7e9a20131], [0x715b7c236a4dd4e1, 0x52133a895e68109b, 0x311521540979a601, 0x3e576a9d71b3a4f6]],
+    [[0x3f63f15bb442ef3e, 0x9001b9f6f8fd0f36, 0x993bf22cb1de95b3, 0x5edc75bbffead51b], [0x258a625b7511ce6b, 0xa91ed558772beab2, 0x8b0368fe6bce75c6, 0x15bcd31d05c21ce1]],
+    [[0xcca5dca673a29e29, 0xa4a7789dc991b142, 0xfd504a0de5998a43, 0x466f00365564179e], [0xba283b307f8c1a9c, 0x9c1f91b8a91f6c43, 0x2c6970d601e8c28f, 0x18a18fbcb07d2a90]],
+    [[0x931439f59ebaab28, 0x93b6bbdb734ce68f, 0x4f4d62ff0abcf5fc, 0x57a07a70a220b36c], [0x65b5dab96f7f2e2f, 0x89026eb79fc52745, 0xcc3ae4a31342b4e3, 0x37db569e81953e80]],
+    [[0xdb2c66f661cfab88, 0x9411ec8cbbe5342f, 0xc990f7cfcc065ed8, 0x326ab9de5077b410], [0xdf3a9f8a47f6dae9, 0x5a66937bc9b15a7d, 0xb69a4c38f9198446, 0x50481dfcd42371a7]],
+    [[0x0a50a9c06d193afa, 0x657e10d139b9bd2b, 0x297b1630eda59462, 0x1cd6331b1277f728], [0xfea44412fc545a5b, 0xc7c5498e8cad2603, 0x9bc9ba65afad929c, 0x4baf547bfee154ee]],
+    [[0xc5f401db24fd4160, 0x48a9d316019a7a90, 0x987817e7c5c9383d, 0x2c49544a95d47826], [0x3c99f9f71a10c228, 0x25dc10d02d3abf12, 0x7fd0ce4cdc1c0af9, 0x5d39ec700a44d77f]],
+    [[0xef1d116c7138f553, 0x88f4a8c9b26a141d, 0x1f3c16194b61babf, 0x400a8504660b1242], [0xbda1943e305e7ab8, 0x4913cb3d365e3aa8, 0x5838d97a23eac32a, 0x5240f5105458b5ac]],
+    [[0x7aa9259075f92fd2, 0xf1884e138f5f84b0, 0x87b009c1752a5193, 0x0db682860b21bbe8], [0x3940fbdcd31a90fb, 0x7e8ee5e8f0a8d48d, 0xba7e616520a70b75, 0x0c5e9527c2428f67]],
+    [[0xf930b271db5023e5, 0x985bd5ff64ca10ba, 0x2b42e39ec794759c, 0x1afd85c0522d3f75], [0xc1bcc1285f9fe1cc, 0x4715ecb85b3684ea, 0x1e75ce4a5888600e, 0x04ec3cc66b8ea6f6]],
+    [[0xdb77ee0f344d83cf, 0xc0113908c6194952, 0x1410df6e14f3718a, 0x692027ed09a229da], [0xcf9f7474dce1c8cc, 0x2ddaed9ccc1f3ca2, 0x25549d97ac179728, 0x3a93778d223bb312]],
+    [[0x90131c5de291c13c, 0xf92d4eeff81dedfe, 0xce3fffe9841b8c1d, 0x5d6346d64def3a92], [0x52de621a30f2c088, 0x4a59b5adc95831c3, 0x0ed1ab13dd108924, 0x1deae1436e670181]],
+    [[0x4560201ac329b664, 0x1423f135e1520b90, 0x00b1a2239e13a4ee, 0x50650250ce44c91d], [0x34625f06c4bd609b, 0xdeffba49b9d08942, 0x8a09fd4bc27003e1, 0x1f325fe133881892]],
+    [[0x66d2fd7f3700bbe0, 0x883ceadcb4c3e386, 0x35195056a299e3f7, 0x6adc9278bd57d0ea], [0xbff939d98058ed29, 0x13a4681a0ea2bc9d, 0xb738b2d0e2db864d, 0x04fef90db53bed62]],
+    [[0xda28a5ea70ac56ef, 0x9715ca7e540afd01, 0x301074f7f8e170fd, 0x25c75107996986e7], [0xb6a0c8d0d8779efa, 0x6a66d2f0aeceba88, 0x07b622c7a409e88c, 0x117b1adebf067373]],
+    [[0x49ffe122ba6d83c2, 0x15a380164c36742d, 0x11887929f3f68ecb, 0x2c7c4dbaf46468f4], [0x1ee6b02d04b889a7, 0x6895622e5b4cd409, 0x3cb75d098323c76f, 0x483297f9b14cf917]],
+    [[0x7ae688cdf773da51, 0x1b1077e303e516cb, 0x090ac5879bef56ee, 0x3382d916acf3da31], [0x57c0174e59e13f3e, 0x5aec8cd3b0249f4d, 0x0666691cec8a5493, 0x5a7045072a7a2885]],
+    [[0x7b739fed86ffdddb, 0x9c32186fa67a4aa8, 0x83a06550967e703e, 0x08c0bf0ba2d0184b], [0xf262cc32eb9e723e, 0x0d712cec9e1eb40a, 0x40eb6e9a2b52ec39, 0x64132e8e27c1a0be]],
+    [[0x6482158c3c894a21, 0x0294a6568edeccc7, 0x4a05143c765eca01, 0x21185cb9b76cd73a], [0xa9034579aceda0c3, 0x82ccbe81954024e6, 0x180598d066eedf55, 0x23a7e72c67cdb3e3]],
+    [[0x951f15fe8877181e, 0x3a9a9dd753d8f7b6, 0x1352a3d021d88c27, 0x44e5d65e05d854c5], [0x72a79c50cd694166, 0x5107ccaf5067ec37, 0x644f888ce9d7f4dc, 0x1494bd1e27c0b41d]],
+    [[0x3ca5ca758dc74337, 0xf583dc53482d409b, 0x5aa4f6e3d3b7b591, 0x6219bde1df13f891], [0x5e0822dadb7f4abf, 0xb219658c8e4bac03, 0xfbcfd4a346ea94f4, 0x062c2c2a04b39ef4]],
+    [[0xf262313cfec09193, 0x0acc3a9527521cc0, 0x19620d06f4e8fbb0, 0x12ba96f31e095cdd], [0x33a90d0e4a4a2e10, 0x8c1e09b0abf36972, 0x9f2aa395d831bfc0, 0x0ce4a310357fbbd9]],
+    [[0xd5cea915fa15544e, 0xc4411742f8ec9ee5, 0x116a5b87cff13b89, 0x68abc352448ff003], [0x391aece23330b889, 0x95ebe1ab99082704, 0x00d92fb9e87f2b3c, 0x3a2dc6ea604a37fa]],
+    [[0xb958ab6595b3a0c2, 0x063e3c193d24770b, 0x9f8aa0b7481756a1, 0x049904285f5c8337], [0x65783e34f379bf53, 0x072d13a377293d7b, 0x7d7d3be70ede1365, 0x4e683ba85cf9448c]],
+    [[0xcb59b595b2f4d532, 0x151727b70bc73342, 0x548a395788f522f7, 0x2f3b820bed487169], [0x08a510cc057259b6, 0xe8f8bb3e233038bc, 0x36556fc29ec8bde9, 0x3ae423db87ea2131]],
+    [[0x3a413080d128f39b, 0x6d6d25e5ff41860d, 0x9d45bb0c084339f1, 0x33bbe971ba12f91b], [0x528da5c6d7ad6c8f, 0xe5c08244105cdf49, 0x6e929af5fd720613, 0x3cfd62018375e236]],
+    [[0x82520fae8b36b5f1, 0xbf944bcf59f67df4, 0xe8ef315650a753aa, 0x3a3923feaffd285f], [0x30a868f3c0309703, 0xf6f17450c161f2ba, 0x8f8811dbfe5eea9a, 0x653285c497e9b212]],
+    [[0x0bdbcb403ca2375d, 0x2965186225ac8801, 0x45b30fb741c1220c, 0x3db0cf418eba57ef], [0x3d7744bdc47666ba, 0x4116b5f66ba9b081, 0x42f0cb9ccb228628, 0x5c53c724a45b79fb]],
+    [[0x7d9017f7ccc646ab, 0x091c94fbbc1bde36, 0x85f6eb13587f2c81, 0x0f309353db7ec889], [0x61dfef8dcde26f2c, 0xcdef1f942e078c34, 0x1169dc2ec8398e3c, 0x373104ce93297af9]],
+    [[0xd4b7807936e065f6, 0xa4cd11716b3e80db, 0x0949828c1ed3986c, 0x1ad3e11b54a1dccc], [0x8a4c04766e00d4c0, 0xa9005ed8cae93d78, 0x6d09bb597ddd61ef, 0x6bef4a7eff9b4803]],
+    [[0x8eff7ed7d2456c8c, 0x800a7206d5f746cb, 0xc086af7aef177440, 0x4794d9cde851ffe2], [0x510718d2b50f4bbf, 0x889551d0f46ea3fa, 0xb86868605a020df4, 0x0048f8a32a582656]],
+    [[0x335fe0e8e576c26b, 0x20938a4497585dd3, 0xb01a2872432fe06e, 0x400ed5e7df90a91f], [0xbd6fcd64cbc5894f, 0xeadf5dadca4154e7, 0x27ae248a1d1cef2a, 0x7357e737f4175f3e]],
+    [[0x27b5ac478bf0f8a8, 0xf37039c4cfba5eaa, 0x6c0fffe07c7df444, 0x14d3a269a02a74f9], [0x235ed3d2fefec5bc, 0xb82d385945048f45, 0xb31995d97e049a8a, 0x09054fe4cff974da]],
+    [[0x9ec456237db241ed, 0x8c17326763bdf95c, 0x909ed923e7fdf214, 0x40c70e9301e5a967], [0xae38c363e34a521b, 0x808c25d738a2808e, 0x279f6bfe28190350, 0x64a231b8ad37489c]],
+    [[0xaeecbe466da182aa, 0x47362f649baab889, 0x097c712223261e29, 0x330b06b3bbca8782], [0xe96324cd88b111a7, 0xb38ad7215e102df1, 0x23aec5688eeec59d, 0x316f99559a5b158a]],
+    [[0xdd0c21c980ed1454, 0xc62edc6d15ec4b79, 0x05695cf5c2401d68, 0x3b699ddaac50af1d], [0xa0e7a907980f0292, 0x4c6875684bc4e16d, 0x1b119839c3e3ec10, 0x4e987f99431d075a]],
+    [[0xfea513b7f67caa14, 0x5897e41013e749c7, 0xe48aea874366c8f7, 0x1ff92c7f4216db6c], [0x65eee8813e36ac3a, 0x438896ccd59222d3, 0x9773f32321cae434, 0x028acc5b3170c4df]],
+    [[0x535472836dc31068, 0xe10ecaab52387a4c, 0x5bee18c8922f90e4, 0x4bcccbe073ce2cc7], [0x78680847d1c14078, 0xb3de1c29b1bd4617, 0xc69181eb8c7da394, 0x29c720375544e3b9]],
+    [[0x3a3e88ea03bdad28, 0xda7edde987f13c55, 0x49c377cd79d8f684, 0x23400f43b6e121ee], [0x2e29bf24a66b60b6, 0x8a3d7cc395ab4bd2, 0xa8501fe4492f452d, 0x63a50c2514418f0b]],
+    [[0x67265a8306be18cd, 0xe07e0db14b2d5cb5, 0xf58b04dec860f508, 0x45a45ae81bea8b08], [0x2e473cd03d265103, 0xcb1a1a81379f2b8c, 0x030354833a6fab01, 0x703ebba7bea7a4fd]],
+    [[0x04de35eed6035de7, 0x0ab1edd6ef1af26a, 0x281f0c4ea135ec45, 0x2851b865aef1188b], [0x48cc1869cbd482f8, 0xecb6737341d40704, 0xb7c17ab51035444f, 0x37837691f22fbcf4]],
+    [[0x3fa421f2ee28d6f2, 0x1f4c976ec8896e8d, 0xd446ddf1d895c63e, 0x2a64846b09e54dc0], [0x78884bdbb793598e, 0x3dff3ecb7c1af2c6, 0xf3f96ae52dc1f234, 0x1457cc948685b73e]],
+    [[0x567e336c5173bc0c, 0xb44bd75dc145351d, 0x70beec63e87b17b3, 0x390783cf70395170], [0x3d7f7f70602f992e, 0x6bf3dfa713a7ba11, 0xe6adada00a59522c, 0x29430cc0b34a4d74]],
+    [[0x55c286b0d8491b8c, 0x69f7053c7a8bc378, 0x86035f4755fdeb65, 0x22bb2a7e4265c947], [0x6812447ea68cb76d, 0x47c2e9efdf3cd7f7, 0xe16f95ddb593c94b, 0x5f9f227fe5c89ac5]],
+    [[0x1f2fcf504718071a, 0x7b33da78fc88c69f, 0x7267ff6ab41b824e, 0x6294f461f8e42ee3], [0xb7cb9d39a2d59903, 0x4a7b74c0bc2a8f6f, 0x5388a2a5bb5b66e5, 0x1e32e1b5dba1bf86]],
+    [[0xcc23cd2b55ed3f07, 0x86f175870951f70f, 0x8631aead78d78232, 0x3868992133f5037d], [0x8dea848352d2f2fd, 0x2b0321653db70440, 0x5273e64cee9ba273, 0x4244eabcaba6da7e]],
+    [[0xae24240703b69b56, 0x331734e14858aaaa, 0x12392b2e1ae34b39, 0x5359076b611970c1], [0x10e124d689efd5b9, 0x66b77e072c72ce28, 0x44e7f3552fd720f2, 0x4688f101e3f4c26f]],
+    [[0x56dcfdf7fd67e7b9, 0x624daa54c883fba9, 0x9a4b45c1c6bb7387, 0x04e3e1fa9de46943], [0xe193ada2dd6e8456, 0x3ab6dc12ee9d187f, 0x810fb2cca44c7518, 0x0c3153ac01a88a6a]],
+    [[0xc9c05dba95c746e7, 0xebfb707c9fd16e5a, 0xcf2c99d6c9ef4924, 0x350142d78d6cfab2], [0xfb00612fd3f1e443, 0xe10ea21bc1f49515, 0xf79f043013fef5ba, 0x406871e77d6ac783]],
+    [[0xc1e1ae58c13fddd3, 0x4a4626bdc716b650, 0x8e0250d2c83daf49, 0x4aec014db8d396d8], [0xf64f167e186307fe, 0x179a9e0c58bf244c, 0xb647915083f9ebfc, 0x6dc9c8384566535a]],
+    [[0x8060bcbef790dabc, 0x48ab421ecde83c94, 0xd9a5ed405a082c22, 0x71a85fe15aeb9e6e], [0xaffd24d0276615ac, 0x6a6a18e14241a7b7, 0xab2186b9af5981fc, 0x4a78d1ae6713ff90]],
+    [[0x56c845a3faccaf20, 0x74824e4756dba4ec, 0x46293f34838fd8eb, 0x3b8b404992b1f00d], [0x93522fa508a5eda1, 0x2ea68b97fa88e5bd, 0x0eeb970238f94f50, 0x52ac9db7a6591f08]],
+    [[0x2068e6dd3cb1da21, 0xb2cf927f4731a84b, 0x02343d82d6970171, 0x4de0b1ecd79030ed], [0xc38c47fa0f49bfa3, 0xcf5ff5ab82901c43, 0x00fa188d23b02b8d, 0x0d4ad60118cc5a0f]],
+    [[0x15cc6ed80c73cbfd, 0xd9cb20f9de5c723d, 0x061be3028b28a32b, 0x6d472e56c39c8e0b], [0xeec7a78a45021610, 0xe3d93cc966dba72a, 0x9e984c511a734e77, 0x5575ea33b160fada]],
+    [[0xa0b1af5314aa79b0, 0xf0ddcc8d84b45b7b, 0x2d1d15fca34e91d3, 0x2af3acc8f23bd9d6], [0x82cbbf8d15e53648, 0x7a5d1a93390b666f, 0x51025f6379bfee83, 0x1a495f76aa54e365]],
+    [[0xdde90e67bbc6916b, 0xa24156949afd22e4, 0x1490213512c7c711, 0x63634dfbaf0ee8f5], [0x077d80c44b548253, 0xc86a12386b7efaa4, 0x47bc90b6d72c9c66, 0x40dcb6ec1aee0479]],
+    [[0x280ffabebef0ec56, 0x6dca556d96004a3d, 0xedfdee5560f4f8df, 0x0d89e75f00ba2a17], [0xdd4d6a0efa4437bb, 0x84d6b9e6df482ac1, 0x3cef7e56a0ec229f, 0x58b462dcda31e8ef]],
+    [[0x6af9cee09becaeb9, 0x2ad7c746530c4977, 0x9def1977e3fee214, 0x5a43a7f7e25dc82c], [0x627a7690841a867c, 0xd3a8f5f5002ff029, 0x6490875cbfeb109d, 0x00c4b631f53623dc]],
+    [[0x472ae644ed76d6bd, 0x53b90d971ee9dd56, 0xb614aca1a953c421, 0x0f40ef6b9e762c58], [0x8f12b081fe4f562f, 0x5144daa104e54709, 0x9bbd9b54cc8b4515, 0x0510c8a4670d5e30]],
+    [[0x7393fc4a2c3cf8ff, 0x9bb68a0394e8923f, 0x90de69ff3d1325f0, 0x36d42eec2d995c39], [0xf28524a653848556, 0x468cebd2db69c0fa, 0xd6d3ee80cba43abe, 0x6fcca9132902e878]],
+    [[0x029a76301a7883e5, 0x280f042a847a0ece, 0x657ece5fac72b578, 0x608d4dd33a54ab17], [0xacb3c559e0c94aac, 0x467b11c597a2afea, 0x3e7f8ad65fb7f52f, 0x29edf58651d9550d]],
+    [[0xaedfdcb0fda5c790, 0xe1056b0d7576cc58, 0x6c9d8c5f6cf429f3, 0x045f4bef3b957670], [0xeee3db524fc484ee, 0x72c79c2050732699, 0x281444334eba20b2, 0x5b9f5c6021542115]],
+    [[0xbf85094be9da04e9, 0x67d537ba3e147ea0, 0xe0546dffdd65a811, 0x10a6957a6711cef3], [0x5d9f2f8003712ae3, 0xcbf543bd5de3a4e4, 0xbb9e8c2ca69daefc, 0x5bd43361db609d56]],
+    [[0xf24baa3c4aa10de2, 0x0be65227fcd54451, 0xd15a0d7c3a0ab882, 0x5da017f40068bc3b], [0x20992fe1656166d0, 0x2cbf7fcb51227b62, 0x30d9b103940e26a2, 0x39fcb66317a620fd]],
+    [[0x594393efcc3ddb3a, 0xc7ab67a6116e5e60, 0x15086a5fe9242a16, 0x738e6b1ded1443ad], [0x0854ecea49953924, 0x6b17da41609c1306, 0x995e40e910dddd23, 0x6493f6c60042a1e5]],
+    [[0x112eb06f26ebb176, 0x4b7364e237364cd2, 0x4070dd013f2c22ad, 0x088f29b045d879ea], [0xb9a2418477b9569c, 0x4a33f58fd421d2c2, 0xf8aaf87deee53c74, 0x391f8af873543bf7]],
+    [[0xef496ca0ae4ac9c4, 0xa4fb4e62d06e2e8b, 0xc9865e51c735ac23, 0x2771b9d962068fb7], [0x868bc8787e82195a, 0xf713156371d5c4c5, 0xec1a404938dbf08e, 0x3bd5e096740d0395]],
+    [[0xcd58bbebff289698, 0x4c69dd31446c9df8, 0xd94da4743475d10b, 0x48f3dcf3569d536f], [0x4631aa5fdb7bde34, 0x95e442111c1f3efb, 0x5c0047b9a059df88, 0x6849465c7d0968d3]],
+    [[0x823ff8850fe1ec61, 0xd144bd0ed37caded, 0x35e239544a447c0f, 0x2a29d58f78bfa9a3], [0xc81895ab4dbb7d30, 0x24964cd0b958e034, 0x281a063fc63f615b, 0x094d5075a6350f5a]],
+    [[0x503c53aa67a0b68f, 0xbca220a4d1540ceb, 0xe5db59c73e2b36d9, 0x5a9a61a03459f02a], [0x15e39d57f0229069, 0xa1478fd18754edb1, 0xc8b4de32fd50e525, 0x3beddec7aab02a95]],
+    [[0x26fc0e597efe6b94, 0x09fcc760fa6b1c4f, 0x915646c54eb8857c, 0x60da7f2f791d073f], [0x5911e61ff4520347, 0x5c8ab50c4f2b1547, 0x74df31598bb01dae, 0x4e486c1044ffb1bf]],
+    [[0xb246fca64a58db07, 0xc230c2c680d7ea83, 0xa1518d6701dfb1e4, 0x4a4b22d58dbc0f79], [0xb8aedd06cc5be618, 0x394d6757878e588d, 0x5b67584dbc8bc157, 0x5ef9b68bdc62be7b]],
+    [[0xa88064a7f5231f4c, 0xd35bd13012828fd7, 0xa67469ea134baf82, 0x419aa8085ff1e516], [0xa16352c9294921ad, 0x3bab1b615d2ba681, 0xdca4b394d9243349, 0x3ef82ec539540b81]],
+    [[0x038ae322aa27551f, 0x079087b310f6647b, 0x60fa3191643feb78, 0x58069e18a13b5dd9], [0x2055530e1d270b57, 0xf02ada324b4db3cc, 0x0f4df40e8a95f581, 0x250b534e3728495d]],
+    [[0x64d3d2001af18006, 0xd351c4b35c04a39f, 0xb0fbc4f1d54c266a, 0x4c232588f0b0a5b8], [0x5d79d85dc59b7d0d, 0x0b8b1f5459000ea4, 0x273d019b2bd298ba, 0x495ed4f60d5bd31b]],
+    [[0xe592bd8b517274eb, 0xcddc9b625e9e47af, 0x93354968f446e6a6, 0x42abb8619f867845], [0x696c4236bd281fff, 0xd1767fa10a7ff5aa, 0xb6b6847e36ed97be, 0x49d341dc1b0eb420]],
+    [[0x9a06febaa0f16d89, 0x67b15ebd79d163a7, 0xd578c3b7df8cc15d, 0x101a476d87aa2b2a], [0x1a6793ee54130d01, 0x66d5590ee622e3f0, 0x03555426c62543eb, 0x12723794c77aacd8]],
+    [[0x95f10b33367f41ff, 0x22561420a2a4127f, 0x965577c23946a6a6, 0x239bef3c2b83962f], [0x0ebf987e31d2200c, 0x7288b2193c1819c5, 0x97f24072ff3ecb9d, 0x16d1bd5162f1f1d4]],
+    [[0xaf42e3d4195a08d3, 0x9c0b396845cac28e, 0x1a4e3cabe957e15c, 0x1d5ea4ec71ab889a], [0x4f3ce0878cc5f0cd, 0x60f5e9add875fc6e, 0x78540a783f9ce12a, 0x0e48c037f177623a]],
+    [[0xdf33c43a8caa4219, 0xe4fdbdb336e8a052, 0x0b565388cf6a42a2, 0x446f37c2375ac9a6], [0xc895338a16d88f70, 0x2032cb101a8fbc72, 0x0f6888246051fedf, 0x284b180555bdcc6a]],
+    [[0x4dacf29d647eccf4, 0x10922e5ebd05290f, 0x1a5e69b1e49a8a4f, 0x6d35d65655b002e4], [0x5f234a8a3bdc0182, 0x246d426a72b513c7, 0xf99ad873b72ccd53, 0x2660e88086102cd1]],
+    [[0x295d5befe75a5356, 0x4c5e4c8a976c15e5, 0xce4ab07b19e6c4be, 0x2270839a527a6714], [0x968702769449da71, 0xdb08a739e3874e91, 0xa77d528250930be0, 0x66791d073cbe3ccf]],
+    [[0xc4276aedb9e5f908, 0x0f1c9f617f357760, 0xe59a23a99ecf8901, 0x12fc91de1c5a3b63], [0xf0a68e2017b642d6, 0xaf5c9d828159a523, 0x8eae6951ae70c87f, 0x0033913d8931fbd9]],
+    [[0x2edc20648e6b4576, 0x2726c2cb6bfe2d55, 0x949f333c9f1f0775, 0x1a918db93d16a630], [0x05efd45de7a04508, 0x0fd357d6527bd90f, 0x685f6161a30b3d8a, 0x5f96f987e9441781]],
+    [[0x8433f34eadadbb4a, 0xb58e898ca47db2e7, 0xb37f2dc33c56aeed, 0x6743ad030d6fd765], [0xe50f50a26e368e65, 0x7d8977aec227cd03, 0x96d5220c2e2ae726, 0x104f3be99a9bda6e]],
+    [[0x3e3df532c080f76a, 0x0da3a9fe3098eef0, 0xf2b8abe923d33d42, 0x18c9ccfe36e6ed14], [0xd8202aa0c13e723d, 0x4ea8dc52a8541ac0, 0xa95e2d92e931dc98, 0x1f32968da378515b]],
+    [[0xa2c9d947ac9a2d7b, 0x7f71b99184637bb5, 0x564208df8e08d579, 0x3234caac96daeb5f], [0x0548269d7fe06102, 0x040dbfe333148065, 0xb733d8e0aaa92834, 0x2a2f13da911bc637]],
+    [[0x668c323e6fd42de4, 0x8feb650af08c733c, 0xf403b2ebcba93a30, 0x4b44c8a94c8c4686], [0xf0bdd5615b891765, 0x9658c732810b597b, 0x7ddda698ea94d3c0, 0x2f211685874adf3c]],
+    [[0x33da1daed3783475, 0x77dc217e2a0968b6, 0xc3400cadfca00fd5, 0x1fd86543f72c0943], [0x58bb013d88b4ae66, 0xdc0ecfce1b9ef706, 0x58cc07138b7a5ac7, 0x379deb9c5fcc1349]],
+    [[0x57019d1dc8a55e35, 0x1dd7e1426f24217c, 0x9be46938558b0fa0, 0x16d3b1c1f0aab5bf], [0x4e8d5d57305d86ca, 0xd283e3672b32c89b, 0xab5b49f912e67fe8, 0x0f909e74d2c29ef9]],
+    [[0x8088601110e8ace6, 0x603304957092f4df, 0x91aa9ef3548b2624, 0x26e6ea63fc43520c], [0x6a6232a4f4d22b0c, 0x52d8afa16b9ba371, 0x9b46aa337b07931c, 0x613ba5539c090dca]],
+    [[0x5b2138917dc2e9bd, 0x1263a2225cd4ac08, 0x358f26c9a55eb761, 0x697ff45a59fc3a57], [0x9d9f35dfbc4e1081, 0x9b07130364a76dd8, 0xcc44787e9d45833f, 0x6ae8a444129d151f]],
+    [[0x5937a906df25f510, 0x901dfcb924d389e2, 0x23599d4969e7595a, 0x1a41efa8decf59c5], [0x81291c32a2e852ad, 0xe9868731ecc9f056, 0xffe3d2d423ae0643, 0x08769c2c9e5668e3]],
+    [[0x8c779de4d4189bbe, 0xaa997db8c42c0551, 0x7835cd1b56c64b94, 0x683287dcfd44d0f3], [0x8cce742474edfaed, 0x1453285f1b78759d, 0x583a45aa2b1ece14, 0x44b1cab62b3f920c]],
+    [[0x180e14d682df1050, 0xa7cd99a1c43b4a84, 0x916692aadb33f78a, 0x1e011c1cbdf2c40c], [0x2c50c3c7194bffdb, 0xc69cd5e50410bcc8, 0xd160c67f292369e8, 0x45ac8c63f8404a19]],
+    [[0xfff02d1735c49145, 0x68bc927251459e34, 0xd2c2870bc8943c95, 0x55c5c97fab6bcb6c], [0xe8609aafc1a79edb, 0x9188c0995518f83c, 0x75dda6bfd7075256, 0x0cc661ad56b74675]],
+    [[0x7a160893f59341ef, 0xe7593490b5538cd7, 0xff847f79bb75298d, 0x4b252a3d076e0e9b], [0x2e39cce7ba76630f, 0x7e571e7619378f94, 0x60b61e4b76049c5f, 0x4977a3dad4ac8b0e]],
+    [[0xc5edf5a819f796e6, 0xc9a43b4818eb5915, 0x0eb410988b27c333, 0x0e5fca795391c683], [0x6ccd3e9cf4774b8e, 0x230ea62962d0c4b6, 0xe32413a4a56de402, 0x456bbe9b5d8f9cf2]],
+    [[0xb9a902023de72e2a, 0x5495d25faf477129, 0x180e7224588591f9, 0x3582d8879910dd55], [0x020d36c653aaa663, 0x8c948015b2985115, 0xdb35ad53f0533cb7, 0x5418a39f93b9029c]],
+    [[0x8ebec1046a108beb, 0x789ce9442140739c, 0xf920d6963a13c712, 0x6ca5a2162473cd35], [0xd88f207156f95d52, 0xf9e0ab345e703f73, 0x02bec8660c4d820d, 0x27553e1f077b335f]],
+    [[0x21113c625a2e617c, 0xf7cf1b1c18221384, 0x811942cfb448913f, 0x020b15b71ba58575], [0xf31c1e7e38e9196b, 0xb426fe8dc75eb816, 0xd4a9143b5f7b3cda, 0x61346c7a3a4109ed]],
+    [[0xe77044ac6ea93b5b, 0x54ecf6f1a25eef3e, 0x15b9f7bf3be21a24, 0x5832bd130bb78dbe], [0xf9b434c891aa9980, 0x5cfbc7cb0a5f05d2, 0x99f3523cdeb243b4, 0x61bd9affacfaa2c3]],
+    [[0x17746a723d7788a6, 0x302b2e5237cd9e8b, 0xe4e5574a56237bb4, 0x2448afa70f1f377c], [0x161050d5909b304f, 0xad1ee8d764aa1ec9, 0x86f8473648a0a3f6, 0x4596aa9ccd3b89ce]],
+    [[0x85938866d23cbe71, 0xa2b116277cc72c9d, 0xf71c19710a5950cf, 0x37bcd747b5f8bbe0], [0x60a767437cfbe08c, 0xa729b432080f29ee, 0x6b48ceef3928a8d6, 0x4370372441c12e0e]],
+    [[0x75186588b8e7413b, 0x260d7a5ad99b6251, 0x74b0f8c5cf3884dc, 0x73700fb883748bdf], [0x4be807265a6e63f6, 0x5fe30d63f6cefcab, 0x246c98e7091c46b3, 0x582d5a2fb701fc8c]],
+    [[0xb8a524f68252ff88, 0x1eed07bad3526fb5, 0x852c17969fedc54f, 0x11664b55ba5a95aa], [0xd60d1589637133f5, 0x4b1294f9796d144e, 0x4df03c7c068ab553, 0x302ffcf09e3b2d6a]],
+    [[0x37035543a7083372, 0xfb4b08e3a4ab961d, 0x0f85e059d4c34eb1, 0x07685bb7c9a1da08], [0xa4f05c2c28cf8872, 0xc8679dec601ab40c, 0x180551187d930378, 0x38815daf3f022013]],
+    [[0x5e52827abc22c9d7, 0x8be571f26ea4bd6f, 0x987b24c179a6a162, 0x26bd7ae69a9f3892], [0x57ec1a02b52f577b, 0x8c6ec9f30dddea4c, 0x84e87d9969445bf0, 0x3113001140695461]],
+    [[0xa463c4c474655fea, 0xd3ad0165a05eca72, 0x39e071bdcabd9917, 0x4408aca2c935f7e1], [0xd6d555bba4172821, 0xfc7c393857d68de5, 0x9c06ad00636bb891, 0x32b0e1b538e9bbf2]],
+    [[0xa998baf3a7f7f5b6, 0xb3ba5bcd0b9b63d8, 0xda3ece08e636a80f, 0x3544faf3aa077a95], [0xdc25194466497571, 0x7778564e48a5da29, 0x1f3c2e0ae23eb0f6, 0x59c1a0ad62a5623d]],
+    [[0xf2faa2f1cd2fd4d7, 0x531e4cadfdb82ebe, 0x3013ecd7ea6345a9, 0x1a83f0036f2ff497], [0x2ba452b545104b72, 0x2a58c19cdd98947d, 0x6807a4e9779b6032, 0x1d6cf725b69c4788]],
+    [[0x261de80199ebd869, 0xaa1f2d8cc800b8d5, 0x7d11b678101b326b, 0x22cf062f9c2a1742], [0xc36d6759a8f20dcd, 0x8a7b8b8c1e07428b, 0x8d5bc27a041156dc, 0x1a1ce2ba3c9d8c0c]],
+    [[0x52078f429615beaa, 0xdc84586d5b5d1a75, 0x8763cf08c145bda9, 0x157bcd41db1ea565], [0x346ed1b2e51fdcc9, 0x257608911558fbc9, 0xf04da28cd8222e19, 0x3934573dd25ad4ba]],
+    [[0xfc6ce28fe1ee67a8, 0x087dcfc0b23074be, 0x4bc6f4cf039d6dd2, 0x4b352915f899715c], [0xa5516f8854174857, 0xcf69dd20402760d5, 0x755e19b0f5215cb5, 0x24fd622636351985]],
+    [[0x3fb1aef5a6008ca8, 0xb0064df423a8eea7, 0x6e64edda14c34bf1, 0x3cbd3964331dd68d], [0xe027598e782509ac, 0x1dc4790eb98a1253, 0x9c49198fca394321, 0x0f1f77e673b53ea3]],
+    [[0xcb679bed4a310c27, 0xffb4fc992cc43d33, 0x66f12173496b8858, 0x37d090cad89cac04], [0xbb2c322cbffdc947, 0x4f53eebc704fcbcd, 0x65185ad071f9b1e3, 0x538fc4cfcd9008b8]],
+    [[0x3acff4900197109f, 0x1f89bbe402d57efe, 0x368c3bc788022181, 0x121f99059ca3b04c], [0x42f6812a5e7f8f28, 0xd3ba419987a45764, 0xceec6e9b5ac2828b, 0x0d19415ce7af6920]],
+    [[0x6c4bdda206d11859, 0x7c9fd297ec2a2122, 0x7c081682d365d95e, 0x4471cf8407125bd4], [0x040d036dcbfef8d1, 0xebcc64a8f58ca6d8, 0x8caa86793270b8fd, 0x035484dda8952f9c]],
+    [[0x70a27c6c346da7b7, 0x20eaae2638090629, 0xea82ab09ee725eed, 0x3509f3aa74c1ffb4], [0x78b4c491fcc2290f, 0x0ddbb6852c803b21, 0xc2124936475ef416, 0x36cb71f153287b47]],
+    [[0xf07cd61a77d2444b, 0x943d9075763dc6e3, 0x81568ad1c5cc4626, 0x207b824169816d3f], [0x413c9c85849406d5, 0x93902c9e98492866, 0x0b1078eb39ea3ba8, 0x1ea9756e8c2576b9]],
+    [[0x0e1090b6abc96720, 0xa7e430f4c0a175dc, 0xe344af86fcf5d699, 0x3d42d6a052a7d444], [0xa6167068ff37193a, 0x48b88710fa111bb0, 0x94a436bd03c8c4cf, 0x6bf56ca7b2a5cc37]],
+    [[0x3defcc76d1605f39, 0x382dc7bf402b299e, 0x141a33addc0395d4, 0x3ad69b3c13e5034d], [0xd02746553e85e72e, 0x00aa954f75fcf9df, 0x7e667c7cc9e9f8bd, 0x484723a41559a1ac]],
+    [[0xf51ea258081cceda, 0xa2ee1fce2d9116a9, 0xb101fd8cabc24e71, 0x72179c2848cdefe2], [0x0d2df7a8600f1ca5, 0x47db08ad1409f040, 0xb8fcb9431e8bc2b1, 0x2e209f3751f4f1ce]],
+    [[0xcd38fd672ed857e1, 0x3ddf205eaccaec32, 0x2eaf959e5ec31fd6, 0x478ea9b34384ed2d], [0xcd8620d62a03eb7f, 0x37d978fa95d3683c, 0x4bd3d66cf8bc1771, 0x6245782fe3aa7ddd]],
+    [[0x374646040e28efc2, 0x5c322bd67d4e1b24, 0x5f66d663f819d57f, 0x2547c9913607f99f], [0xed165f87f010d3b0, 0xffb93c549f45e883, 0xece021da07353470, 0x4b58047bc08f4b65]],
+    [[0xe19b009495f9b069, 0x58f616d6cb0a8820, 0xd537a2ed539f20ed, 0x5f83f71fa2744f81], [0x158abc1ab5c4b205, 0xac37bcd4a092badf, 0x5f58789846c89537, 0x5b9d67e93d51a8da]],
+    [[0xa25d4c9efe437c73, 0xa68c8df1ecfe1261, 0xaaec2e20553d83a7, 0x67d84a3c13e8e521], [0x22604c296649187e, 0xe787b10529b1d0aa, 0x1279ea2b212e38cd, 0x3e07a3c77991d97c]],
+    [[0x5357c87eb59bb374, 0x491bd9934a358fc1, 0x4b77ef976bf6cc8c, 0x64fd5e7efb1c8ccf], [0xd4fb6ed996dbfb77, 0x812b88a4c4d532e2, 0x8c2e77ba9c77468e, 0x1f622bd34a013941]],
+    [[0xcf5d30dd9dbbe6d5, 0x51a8f4bf0ce13240, 0x4ee9e3390a3807d6, 0x472df460b7de00f6], [0xbdfd5efcfa4a2cdd, 0x015cb1d6b26b7635, 0xfcf8bef6e68176d9, 0x6cdd28c87e38f4b1]],
+    [[0xdce4c5b45fca3d97, 0x831af0bc2cb62aab, 0xa534bd7c135aad35, 0x4818145099e79523], [0xa387551fce754ded, 0x55bfb10fb8af32d1, 0x8c15d723e00093e5, 0x00d71cc9d7fb5d15]],
+    [[0xb7be01dbd4114076, 0x17de8a6e4c42b2e0, 0x4b955b88af4be803, 0x6b1e3d6c1a2d8c0f], [0x0233077dc611a219, 0xac1deea3f55cc546, 0x2c47f9092278b72d, 0x1514193607ef6908]],
+    [[0x2a2adf02c04273cf, 0xde54c2d4f62b42a3, 0xb6e065dfa966daa1, 0x69acfb424aec3829], [0x576b382a41101dd2, 0x45b317565b6f02d9, 0x607f8194f67d97bc, 0x4df9d6e00582e5a0]],
+    [[0x8f0b757664a5ae09, 0x50457a43dd69aa4b, 0x550b691fbf52dfeb, 0x36c768e0361dbe58], [0xd598edbee2f21afc, 0x9f3d56d42267672e, 0xdfbf37c3e3358d1a, 0x6c53119b5e4fefb3]],
+    [[0x8ca36321d11c840e, 0x0ba0a31c2a27c616, 0x18b039bac03f8ffe, 0x1af09b308cad759a], [0x2162ed7643ec8154, 0x73ab47cf9868c232, 0xa4c6d68cbeaffb25, 0x6c3c4eb7970e336c]],
+    [[0xd119fe7cecf8be66, 0xfaab753dc6bc5dbb, 0xefb4082961362867, 0x41f9fe143339a2dc], [0x5fe6a5be4c1fadb7, 0x9366e063309faa29, 0x1780bf2ad9b7131b, 0x126c9f109f545b94]],
+    [[0x8ec3a671ea1dfcb3, 0x3a87009126badccd, 0x9e02d5d0b05f5a02, 0x62ac7ac13044d1c7], [0x50e9f8417c3d6f76, 0x381f8593f1072c47, 0xde6d7a74c277488b, 0x73a171351424aaec]],
+    [[0x6ac59c6fe8257a96, 0xc5c83a7a0b18acc0, 0xac551daeedbaf636, 0x0d18a793fe27114f], [0x61574fdad45f8017, 0xcee71daac314aa01, 0x9288f99c2f6f9f9f, 0x3daca8aa7e534222]],
+    [[0x048715f2131665e6, 0x124639928c06c6f2, 0x21cb06c7cbc726cf, 0x719da38765b87ce3], [0x177b555f0656612d, 0x6f4cbe82ebd5771b, 0x77e79a6f28be95bb, 0x6c0e83490dca79a7]],
+    [[0xcc4b92cc98878ebc, 0x2550f86e73a2e8e4, 0xc396c3e98f2adf9b, 0x07f17257fa72dbe8], [0x0f5ccafa9baf4115, 0xbfcc51ef51cbb530, 0xa01692fad9fbd23f, 0x51f6956ab9233ab5]],
+    [[0xd1c78e295c388acc, 0x850f9821c826c25e, 0x8eeb7b22de0526ef, 0x32496c63699a59a5], [0x9f17f825251aeea2, 0x9e311d639360ee4f, 0x9ff2ed2d1aa2d676, 0x6cbcbc888c377c06]],
+    [[0x61a17595e34458f6, 0xa752b6f15d33362d, 0xe241d26bdc76b52e, 0x3f9a14118ecb2b3a], [0xb693f3bdc3bd420b, 0x546eaa837130c81d, 0x7a0df61ede24a0eb, 0x405f0a893494abc0]],
+    [[0x2a09c94ef5b04024, 0x30e1620ac630d405, 0x43563b54cf9b370d, 0x6f81f6605f22bbea], [0x4767a452ee716edb, 0x895e6321ef0d2cc8, 0x0cf5814a4421a020, 0x2a0974bb853ad507]],
+    [[0xe355959936c065e7, 0xd6b1a0fe674a2b7e, 0x3aea57f69ea341fc, 0x3e68baef887af85f], [0xb26c017c93e27b57, 0x2e0159ad1eadbd43, 0x400981a6d26c4187, 0x08b544356aff69e5]],
+    [[0x0988664d1d7a0eb8, 0x78c6c0f57754d1d3, 0x74c5497d09b6ff31, 0x2e9911287b89ce9d], [0x1ee3f7c1f6d7b18d, 0x0c4ffcecaf99a990, 0xab63c0946ec5ea4f, 0x7102c8edcd5f100a]],
+    [[0xfae6e4544d575667, 0x1445a06e982f7343, 0x25e2f325adc408a4, 0x2c040f322f35e461], [0x3ba672686d90b5bd, 0x1bd8cca3c4fc803a, 0x4ce13f64553126fb, 0x3f703fcc12d260ef]],
+    [[0x2321c7981bc596f3, 0x0c75e5ca506341ac, 0x2a3bbd43dfdde2dd, 0x17f6041d50e5eee7], [0xafe00615d899f498, 0xe662c4ff8b236b17, 0x2eac0243a1056b27, 0x46fb03a1a7999584]],
+    [[0x884ea2f1e02e7b68, 0x300764a14723462a, 0x9283cd0782eea4e9, 0x567ef34a781bbdf5], [0x8deb4663ce54e052, 0x30d04b8667346652, 0xdcbf11207eb2b647, 0x16f9995505b6be0b]],
+    [[0xe4ca36f25d7fbb7b, 0xbd246c22fc4619b9, 0x6c87596c9466ce1c, 0x32b28e5d47709edf], [0x82fab78ddba99ad3, 0xb87e89f160bbefa8, 0xa3e417d2fa0c8a36, 0x0591d62065af5937]],
+    [[0xba3ebc117de0f30c, 0xbdff7f023438ae61, 0x42a0602f1034ab20, 0x30de013da7e94659], [0xa8b844f763a0e987, 0xc86f45a09206404d, 0xf9c48703ac4378c4, 0x6b510d4be0177164]],
+    [[0x71f3303bf377f91f, 0x8fc49376a522950a, 0x12f3dfb7dd6d5d45, 0x544f416031fed2b4], [0x50ede4881c7af072, 0x95eccd7e3c4d6f50, 0x65f020264e8d2ea0, 0x2c2aae14778f605f]],
+    [[0x3681f40f0da45022, 0xea5a9edd5dff48fa, 0xc7d590f257a09978, 0x331ee080f37b3658], [0x0f342f9b4c9ee559, 0x5dbd4803ea41b8e8, 0x8ac2945e20bc7fc3, 0x709d6eca8c8099de]],
+    [[0xda7be722c5eda4b4, 0x3dc42a9d5db211f0, 0x6c862e978da50d28, 0x33e0578df45350cb], [0x33bc639f4df659e3, 0x0b9ee76584cf58d6, 0xb0ee747729047bb9, 0x567b5cd82420a841]],
+    [[0x9d4a4c178d5a129a, 0xbc02a2ddadc2707e, 0x21acf4c9e8af7490, 0x00a1222e24bdad1e], [0x2657271a18779b97, 0xc85e322103236b8b, 0x9df1c6463ba06437, 0x0c783df5be771daf]],
+    [[0x6ebdb4b0efc27801, 0x9c380dcd57caf595, 0x9c8d18a6ec3e7c15, 0x31f7938be379031e], [0x7ee3e09bd5c086b5, 0xadcc2e4419d42208, 0x7b8e04cc322ad61b, 0x1122e30de2e46345]],
+    [[0xde6766af445dc64c, 0xe68d2ad87314001b, 0x9003ce360c53969d, 0x19fc444f71e88329], [0xab76da9069cad7f4, 0xe502b87381c53679, 0xf981ed943ff8b1da, 0x4506f2c09ad48e67]],
+    [[0xf896e33a5ac58e18, 0xdf59f43fdc722263, 0x71dd93d8ea31ee6b, 0x1e1179eeeb8b520f], [0xa20f0eabba563e0f, 0xd4e567151a932de3, 0x382ac6d69ca88fff, 0x035b99e920bdfbf9]],
+    [[0x84c1e56f720b3619, 0xeb3227faef945d5f, 0xf8aed7f23cbe1dd3, 0x4e2fb4452ba041c0], [0xdc07f8d755657273, 0xe20f72bace92a745, 0x0b971ec017092b70, 0x0dd4a67676ef7855]],
+    [[0xd47dc6f3bddc1653, 0x3bb901638882d858, 0xdbe60e9ae5106b53, 0x151091173193b4e0], [0x5e451ea0b5337e57, 0xcabee55e021caff3, 0x7a6d762c4cc5fd97, 0x70f64a7a25d71ff0]],
+    [[0x9a92a299242a65f8, 0x3e85c49eb00d6089, 0x32c6bc4d62eeaf5c, 0x5b8b328eec0bd858], [0xfb11fd28d501f291, 0xafd7345cd33364c9, 0xdcbbdd4cd6f958b5, 0x0621cc5db30e967c]],
+    [[0xd93b4fe5ad1a77d1, 0xe934211d7ad0d7ec, 0x732719fb77028bde, 0x6e86f7f46f6799d7], [0xa1ac2e9a504e71b5, 0x5d5d3b3776387914, 0x81f4956893067c1f, 0x09974b5b54604f4d]],
+    [[0xb1292ce67b361322, 0x3565aa740e88ad73, 0xa2ccda7dc8e56197, 0x406d91ccd8773036], [0xc4fb8eb24b61dcba, 0x851af89fdbc9fd83, 0xe7db38da79964621, 0x6565cad0196f057d]],
+    [[0x9c75d212a31627c8, 0x0eb099df4b09e3e1, 0x96655c49ebc11006, 0x0b5e944d94e734e5], [0x1719e128b3e8a2cf, 0x7101806752390f5d, 0x5db2e95568c9b1e4, 0x400962722b860f27]],
+    [[0x7d87548f98e0f466, 0xf4ab24c0865e9a03, 0xb691a1f1dc30cba8, 0x4505af79f3a9d8f1], [0xc1ac34792a255da6, 0xa9fdaee31c1b4281, 0x50c6a1602d29c88e, 0x6dcd3695801e49bb]],
+    [[0x6ddba37636c1dc86, 0x853ae1bcbf4828ce, 0xf22c499932ceaa5c, 0x50e2aafddadf7bfa], [0x8419c523866bbeee, 0x98e3b35abeec80e2, 0x16db725c5dc21c4b, 0x2d66a1333c99a9f1]],
+    [[0x8218133f9975344f, 0xe5bd88d84e122b2a, 0xba5e4ffbd14b80e2, 0x2ef4ef6e03cc7c89], [0x65adb1d2f2900a7d, 0xdf0ca10f45b81e19, 0x00283fa067175fd2, 0x708353ddb54e8169]],
+    [[0x2bd7329e01f59ed2, 0x7af953461c937423, 0x27cbde06ffc2b079, 0x71887eee2a3fc222], [0xa95eae92e82c41a9, 0x64c53366541fe755, 0xd4d4a7ecb4b753f9, 0x0773ff20afc8f369]],
+    [[0xcaa2fc27b9cc69a9, 0x673d6ad6d143d342, 0xbe4f8c82685dba92, 0x38089b2a3d857347], [0x425a3f3fefa764e4, 0x641915a019f31929, 0x58ffcc64b4496921, 0x54d6e816a974fb73]],
+    [[0x647b6eef07885f8a, 0xea1a140bc80a35f6, 0x73e9a190da5909e2, 0x52142c412bc4aa32], [0x1990e848b9694d1a, 0x0403045339093841, 0xb44713381a596516, 0x527a6431b8ec3960]],
+    [[0xad683ee2a11759b4, 0xdb531c3b4787e412, 0xc91c4d63a8bfbfef, 0x4ac5a315ce881db0], [0x99cb37ad1d794e00, 0xc1ac559cabacfdcf, 0x0d1c81205df406c1, 0x07e5ab3bc68e59c7]],
+    [[0xe1cfcf49d6e7a38e, 0xd93cbf68f7f2a7ce, 0x42045dae6e8775b6, 0x53a711a1fafaf104], [0x30362784bb4a6460, 0xaf734b02e57849cf, 0xc6528edf9a3cc972, 0x00c574b13b3fd0ae]],
+    [[0x4ea4b54db8ef43e2, 0x6cd1055b382614fc, 0x3b6f8be17e453317, 0x53e595f338b27029], [0x040f356ef986d54c, 0x34d8270186b24fc1, 0x3d3ff47b166e1153, 0x4aeeea2885b5b8cc]],
+    [[0x810f2d5b08285e48, 0xdd996a886dd27b33, 0xc131a12abd2482f2, 0x22d31dbe0439f186], [0x78e3aa319271a27e, 0xa6cb20c570cf7aa2, 0x4e808fd9d92366d9, 0x4e5bc81c6ff968c5]],
+    [[0x7545dd5ded4da5d5, 0x06d16404617d6a8e, 0x66eeb7159b7c6fd9, 0x57623a71d08e2922], [0x5bfda5117277647a, 0x33cc267cfacf455f, 0x101db10a2e322bf2, 0x01cc25ae70e6aed6]],
+    [[0x8229c5abd240d9c0, 0x0d7fbb5b1028e29c, 0x8fa1f2e15a9ca1f3, 0x338bbdcb3cc3f0b4], [0xd1ef396ad6b49825, 0xd56ae5001a79288e, 0x3c216b7cb25199b2, 0x714c856b896ac3ff]],
+    [[0xd97789f4216f7081, 0xd9a3fb3dec2b82a0, 0xade469f66202a211, 0x674643333628b045], [0x706f6184d05a4d55, 0x252cd8f3b896fac1, 0x609fc4dd10fdf9c4, 0x020d327e7bc211d9]],
+    [[0xf3bd24982d7986bf, 0xca9da81ced3be213, 0xba531fde545a0ce0, 0x1546e3f01bc7b73d], [0x336d408102f6d611, 0x6b2c3ba0b7689528, 0x105936e8ae0761c2, 0x3fd8d133fff03cbd]],
+    [[0x7be2f00bc2969bdc, 0x4bb8f570573bdc04, 0x9661beaea49dfc25, 0x12c601ce197319b8], [0x5c05a519183e9d8c, 0xdae4c828cfce41e4, 0x8e3e7dad1c0ed57f, 0x003cc2190b280e06]],
+    [[0xabee013e1c4e1288, 0x31994c30aab63ffc, 0x86edd0fc81ef19b1, 0x6133427ea34104a8], [0x76c69f01cf2a297f, 0x1673ea9d4b162a0d, 0x2ba49b9fb966552f, 0x12ee94d1f62db077]],
+    [[0x4340df4818f13b63, 0x04a6eab75d00d053, 0xa48f196e61bc6fb0, 0x0745709b2791489e], [0x4c132ce97b242e84, 0x714c02c391202a93, 0x666c2976bf3d4828, 0x5166beb8a76be5c9]],
+    [[0x9d923c2777612278, 0xc504ec4ad2a359a9, 0xc027010172fa1139, 0x4bbb1187a9d41b5e], [0x6d2194bb502ca3ea, 0x049e3fd16b566c3c, 0xdde15130e4ab69a8, 0x0ff7316625b24824]],
+    [[0xba6ca10bd7019211, 0x6b1feb7a78484ae6, 0x06ecf4634112dd2c, 0x4c1f7cdafb355145], [0xbcb2dc6fae1a6475, 0x950c576bc82c791f, 0x67d1ca50af10eef3, 0x1d05342f4acff044]],
+    [[0xd6de47ab227c0e2e, 0x3b126ed148ed2ee5, 0xe8724133e3fbdf2f, 0x5b2f2eee34ad8602], [0x38b8b977893c5dfd, 0xc1b72041bbfcff54, 0xaa12b90f963485a4, 0x54b86e5a58f40ae2]],
+    [[0x1e8aecbbb361a8f3, 0x4ba8b1c2c63f66f2, 0xeb56a875172211cb, 0x268bc72fe360987b], [0x5352c89dca8fb87e, 0xb50272e3631984bf, 0x97bb3667fe300123, 0x2a04fce50e3676dc]],
+    [[0xf3a82e07d1c38776, 0x29bc4839ac95dcbe, 0x40381a37490f5479, 0x1b2eeb389c1cf769], [0x122c0adf8e41a326, 0x218909d12e97d25b, 0x522607e44025391e, 0x296e818f24fc8387]],
+    [[0xc6cc82e96fbb5ed0, 0xb79451131179f220, 0xdc468b3253e4911c, 0x444bc089c9f94af8], [0xd04e684ae79c5da4, 0x74349437a3cf6806, 0x9c87c6cf943496dc, 0x2824150479365c50]],
+    [[0x5c073794a7f586cc, 0x90ab99f60df8794b, 0x2ecbbc2899fb54f1, 0x13656c15cdd894a6], [0x8aab4a499622663e, 0xaab997f3acdfa934, 0x8f64650d19968a8f, 0x468064b1b6767eb5]],
+    [[0xb4f883a3b61f7c79, 0x8d2b71f83dee8774, 0xca90736a1c28b338, 0x102995d96c180223], [0x57bcbf927a3a7fa7, 0x613b78f94aa1e4e9, 0x26eba8e312ac1b60, 0x4f82db6c26f371aa]],
+    [[0xadde1901bcbac656, 0x26f20f9dc266ade1, 0x24844256c00a5373, 0x5c2e58e6a09c7e25], [0x9d0ee0bb1ddc6a73, 0xabc854b08ca7df62, 0xb847b68e996c1ea7, 0x39963ea09b73efd0]],
+    [[0x4aaf9a523725285b, 0xdc9620c44633a3e2, 0xd5a7e6679e3ab1ae, 0x376f9f1f8a6dd040], [0x5a5ffce4238cb598, 0x23f6c33913f645b0, 0x7b76ef95bfeb9d96, 0x12b02c2070ea4558]],
+    [[0xee206f7814e81f5f, 0xf677e0eb7f738982, 0xc4caeac09ad88207, 0x14dcc9c1feef1bbb], [0x2cf1d619d0906270, 0x9b4f55458db161d9, 0x5305a3b9d38be591, 0x1fdd05adb9d37467]],
+    [[0x18f015893acfce7d, 0x51ca5e71613af8e6, 0x1005def30c41bafc, 0x4e28b3ce408e8978], [0x2c238ba3a3078eb3, 0x6fae1d92fc691b08, 0xf557296fe133cb6c, 0x54d58bd74bc253fc]],
+    [[0x4e20ee1faab1294a, 0x00ff76ca9099f222, 0x4fdb4b0c68996bd4, 0x012b95fcdc841dbd], [0x799442cc80d737ea, 0xc77b626f67f778ef, 0x42945b0145e60454, 0x2b3fa7296b7abf01]],
+    [[0x6ca04ea140c5a1b4, 0x16d5dd5842125981, 0xdbb001bee79dd2c8, 0x46eeafa28cd80cf5], [0x918f8d724df3f8ed, 0x85eb41a101413b07, 0x1f85238cb0162de3, 0x1f30ba7e21244c4c]],
+    [[0xeb89537e6bbbabca, 0x53c7f81b0867e05f, 0xf52386f3763be013, 0x473b8d1e57f54f51], [0xd47c90196233b712, 0x95c7dc3e09e1622c, 0xc1d99a3d28126298, 0x60794998d5230fe1]],
+    [[0x3a9204bddd48a473, 0xe9bc6ddfd2992038, 0xf5d37388795e20ae, 0x3f7f672ebff3cc16], [0xd5fedc061bdc1247, 0x797393d0ea3b62c7, 0x9aacf66e1353a25e, 0x416cfdb2b929a513]],
+    [[0x31a2054df23df10c, 0xd2faf362af711164, 0x819e3a6c62e243a2, 0x45bd059bce058dd5], [0x8f21b9e1313db1f9, 0x804702cc0a5b898b, 0xccefc81b8358ba4d, 0x5a9a7a6ebd474c25]],
+    [[0x940e6253a428c621, 0x5cd7d5841593d415, 0xf0aea1a8212a9816, 0x6b531bcb2a93db07], [0xf536cb8a7a05f240, 0xbda85df700bddf3b, 0xac70567038b29de7, 0x3c583fd695ed63ef]],
+    [[0x4badf7333194e80d, 0xaf7d8a2acd7a4ed1, 0x6416aef3ec1abe9f, 0x37fe63bbc3c74499], [0x6ac57e031a3ce914, 0xc3c01d5074e617a7, 0x384d949ee10f70f2, 0x43503dc9c469c401]],
+    [[0x5551dc13f5d57a9d, 0xe47a5a1693c8a21e, 0x89537e27dfe801aa, 0x6f67080b589ade0c], [0xa06c52a470dda573, 0xc31c2aafe52c8a34, 0x244afd173ca3d7e3, 0x68dc4d50a8bfa7de]],
+    [[0xaa27c42ea7d8f98f, 0x9466559a4c522256, 0xf23ea82f649aa07e, 0x267faf72d2d16502], [0x2de657918983288d, 0x6864ae3761239c6f, 0x1111e976d99fab14, 0x2b7adb5b0e6feaed]],
+    [[0x3393b26d50769d84, 0xb9933fddd5e1c3d2, 0x10429fb496a7d5eb, 0x0bb78b20eb73965f], [0xdb7bd729c41c2ce4, 0x4efcba9233ebeb31, 0x8f493022a907032c, 0x129d4a835671e36b]],
+    [[0x83f300226d68b7fa, 0xf8b7c02e5d8a45d8, 0x9f654c353a602b50, 0x6954d54150405c50], [0x34592994e2a1cd30, 0x038a0b7438ffb00b, 0x1212ff0115738d58, 0x28401c4cfd0f29e4]],
+    [[0x0179b56a61ae6d5d, 0x3cde6946a00557ff, 0x9758505e9ea3726b, 0x3738372da5feff9e], [0x2d67da4732668b8a, 0x131009af20100206, 0xffd86c5c5c1d0da8, 0x70adb6e9841e153d]],
+    [[0x1d74f98dd765ee3a, 0x6b7858ded9c2c9c4, 0x9fed3f3db2617cdd, 0x4339d7ea917982e3], [0xa37df948f6ac0b53, 0x0d6f0fcbbb5f4f23, 0x16d8d06987326c1c, 0x03feef4b338183a3]],
+    [[0x634d6619ed27e389, 0x3b4e8fdcbdc7b13c, 0x78fdebfa0e6a428b, 0x3b475b1af3b3fef5], [0x5cb51bc52f5afa7c, 0x8c318021e0a980e3, 0x342c5dc91054510d, 0x5bf48305c61fb620]],
+    [[0x3d5690e60e9bb3e4, 0x86f6e75c43633375, 0xbbcdf8e15461409d, 0x565650c983ba301e], [0x22f040da6aafea65, 0xd7f0a13291e0e8ce, 0x2caed0c3b37189b2, 0x3632cf45208ac184]],
+    [[0x05d46ed71f2b6ae7, 0xfa031f3fc602d68e, 0x4789e1d7e9ad15a6, 0x1d6e7342a0893567], [0xbf42044ea0d05123, 0xd135e4f1e043dd1c, 0x76bb8e059eb5d63d, 0x5011dfbb6f174ab5]],
+    [[0x9058213bfa8f712c, 0xae64655eb66ae97f, 0x74264c5bffd8cc81, 0x550caa4d49dff6ed], [0xa8d0da89c25107c0, 0x879a417ae4b70142, 0x878cf0a578cea799, 0x3758916fcafca785]],
+    [[0xaa730e090d092790, 0xd09341ae34e4bb69, 0x37b92d2adb3932dc, 0x6c6fbac65b7142c7], [0x3b7eaf1328751aee, 0x2a34a49ce9203e22, 0x7385ea781ac4f7da, 0x3e57878b0c8a1247]],
+    [[0x93f40ff0f8ee7ccf, 0xdced142890bac10f, 0x5c7c8ad4025b82f5, 0x15371e02f3f18b54], [0xaced3391601a0c2c, 0x18aeb8667054ed41, 0xb8d101bb390d5102, 0x4302ba93c8fa1953]],
+    [[0xc66d2a5727c8e0d7, 0x04b9de34435c41b1, 0x51f1cd9f497ceeb5, 0x0880c51a438f6f46], [0x3827da2c478e1014, 0x8c918f28297f690c, 0x2e7925df71d29e3d, 0x3de1b97d51f1172a]],
+    [[0xc343ccec569a42a4, 0xdf61ceaa17f77268, 0x0d45b8c1c31c013d, 0x056892bfbdc57dd6], [0x407005c69fcf9f4f, 0x514316dbc131713a, 0x08b8fd53c1419b5e, 0x56f73249ff84b039]],
+    [[0x09df41110faa91cd, 0x2911371bba1979ce, 0xb3b4d04fa85e17b0, 0x06e6e8a09d1fc2c6], [0xfbbbc17a2a9934a2, 0x3733bd2936e0d2c8, 0xd63fa14441cefeeb, 0x5b8aa49c879951fd]],
+    [[0xf19d58a0fad1e1aa, 0x4aa07f5da81893cf, 0x060169e2fbf4bb2a, 0x2f172f7e9a2c0544], [0x05323604f259da27, 0x8478f9d2ed7713c0, 0x9749a94aec1ac1c0, 0x60b351c6bebf88c8]],
+    [[0xba97d488a085293d, 0x3b17b65977b1f337, 0x91051508b0e878ff, 0x05bf474269caf613], [0x5c1a9fadebd2e4e0, 0x3d632165aa55a202, 0x9c338e5450b35da8, 0x5bcfa093d1d15544]],
+    [[0x91c1f85b11e21949, 0x7f13c4d96c3e2013, 0xca1b478e2cf5a497, 0x43c1009baa81f1d7], [0x804b057cba8505d2, 0x3a0ec3b77dc06661, 0xc95f82c2f81aea0f, 0x6ada712aaf310e98]],
+    [[0x0e74d7647c1f63ed, 0xb4613c62dd92aa43, 0x607d5bbfb5700f6d, 0x22d310bd48d799e4], [0x150d48603e4111af, 0xdee48dec4f9abba7, 0x82dc966ab3ef2991, 0x730ae32148b3212a]],
+    [[0x64e1a53e78715458, 0x99818f194bb9a412, 0xe7fe499da81ff5f2, 0x027b140dd3537e84], [0x0bda5066c61132a1, 0x8cbc256c28316203, 0xb2859d5f4ff14039, 0x68ca74a568976819]],
+    [[0x4f06cafb029bbafc, 0x1bf20523744156b1, 0xc6afea7af038eef8, 0x6aba60c3e7cc4328], [0x4e56cbe135e59b5e, 0x955527dd1cdabbcb, 0x90fbc4c9b94d476a, 0x3c20a370ef43de80]],
+    [[0xd9537b313f2ee6d2, 0x23d820cc8a11e52b, 0xe686544eec2842b6, 0x6ebfb06f0ba06945], [0x2a8ed23e6f26fac1, 0x36b15105d0cb6ebd, 0xafe918163d9fc8be, 0x16da43a73ee7ba94]],
+    [[0x9e5ef85542e8250c, 0x3a581bd7a60efafb, 0xac1a2ad5a7cfa20d, 0x426c7e42380e236a], [0xe17ed7e9e250d146, 0xe19307f34170bdb5, 0x2881e404f3018e41, 0x2c98df6ee6c46015]],
+    [[0x1c9a64548ffd0e72, 0x98470bfa1ae310d1, 0xe31934e07aecbc32, 0x5ac8cfe698e6d712], [0xf4cbeaba9d378f02, 0xd564be2991323158, 0x8726f817f52c73fe, 0x17324e9070d53043]],
+    [[0xdb4707014f0d16c6, 0x4dc6c431c19b488e, 0xde7f3a53074f46a7, 0x53aaa7d9f58f65d5], [0x76ce004597f355d1, 0x848ef210dbd3b24f, 0x0e60f80ba8a740e1, 0x62b379085fa0f4e3]],
+    [[0x77b80e04dedfc095, 0x239890a22f13a9f3, 0x93b43e69d32d6c46, 0x1379dde7b306eef4], [0x012111913609d69e, 0xd5b6feb5bccb7b13, 0x049902f857ca51fc, 0x6197ede1c71dfc13]],
+    [[0x003525a780847bee, 0xcf8c943fb2a90103, 0x02c2243fb659c207, 0x1f303fdb0129c6a7], [0xdb4d591b4afb9eef, 0x6448253d177b46b4, 0x0c17c5d778a8c75f, 0x32c4a1e7ea8d1501]],
+    [[0xc89c06fdb754d319, 0x6d8e31f4b788257d, 0xb0278e9e1a7e4d7a, 0x4907b5cf5c4d7a31], [0x5716d69a5ac70833, 0x5152fa06707dba74, 0x682eb1886aedf8b2, 0x41acdb8b36bec833]],
+    [[0x98d571a8d9e26f5f, 0x755494679786e6ef, 0xb9630561960ff2fa, 0x2f2db85b9866c2a9], [0x1bcea54874bcfd7f, 0x099154081aca57d6, 0xc2f372c00e36b4ae, 0x0da8b71b1d0643fb]],
+    [[0x9c759ae74e85ea47, 0xa9f4bc6d4f832682, 0xce0d69258be6334b, 0x3f65d00e12d7ed05], [0xae548ac1c9687be7, 0xa28eb00c49e0906f, 0x261e31714f54baf5, 0x1c4a25e8676c2f78]],
+    [[0x7d670d30f55642b7, 0xb80dea7686b82b8b, 0x083a729dc6ae11ea, 0x0ce9652a5df964ff], [0x2db9205830b62c48, 0xf64a5148cab8d73d, 0x525f24e2cb79a052, 0x246540dea31fc8e7]],
+    [[0x08ce978f515eb8e8, 0xf00b36be77c3498d, 0x3d761ca154808efc, 0x2977a501a60a6dd7], [0x112d13ff3c3345b7, 0x92471269fafb7f1b, 0xa65d012ed2d348e9, 0x3c3c30101b55cbfc]],
+    [[0x077e44d7f85c290d, 0x0b29ff32f8645f03, 0x6322bb55b33fea41, 0x2d042a7b2c43a5cb], [0x0cbe32b27b113cdc, 0x3d09fe0343edb083, 0x1a9f4f09cc76acd7, 0x41a6eb6a4af6fc8d]],
+    [[0xa5b1b8cd495cd9d8, 0xab7c9388a92bbeb4, 0xa6f04c9b391b231d, 0x55aa7d49ef6d309c], [0x943e5d569abd545b, 0x48b2f9e21917b34a, 0xda97631ae13faccd, 0x6b02aba113106fc9]],
+    [[0x9e9587a94a3a98a8, 0x2852ac6025141b23, 0xe748e729c7713319, 0x6895eedc692073d8], [0x654a80b093f35c83, 0x4bf2fecc0a4e2304, 0x808db5024a432a7d, 0x1aeefac05629a65f]],
+    [[0x6cde9e61ed6dc478, 0x259d66fd9f37594a, 0x5658f87f71e12389, 0x2cb135003c0d598f], [0x63fe3a52ec896fdb, 0xc3930f016d65d054, 0x18457f7562b0a41d, 0x2c995d3a863fb193]],
+    [[0x6e8413c58d0a61e2, 0x7df3a9f309936bfe, 0xf19fe65d9885ffb0, 0x42a67a05ceb672f9], [0x42a0a4b9c4356ccf, 0x4af9561f4b5a3d5b, 0x0ab5692a147d2866, 0x53592a9a5b7fb0a1]],
+    [[0x9087c3c85ad5bd44, 0xd63efe959ecf3900, 0x30ecd6cebd7e19a2, 0x7126ec54f8ab2e7a], [0x967323578852b538, 0x15c13621d25de55e, 0xf5f6063d118b1717, 0x1835c4f96a48e06d]],
+    [[0x75cf27f115be4145, 0x102e539d56cfc0f8, 0xa2ed348810ef2808, 0x604149755b280072], [0xb669104a3a3215e7, 0x7e41a511a1b9f959, 0xc31908846f6046d1, 0x64262ac6443864c0]],
+    [[0xf13d93cab5695751, 0x45dc7f8262ba783d, 0x3c21644d818fa0bb, 0x29b7cc859224499a], [0x486130f83ea459e8, 0x191a6dafa700ed12, 0xc458a10c1633556f, 0x31bab5bd997feb7f]],
+    [[0x36b84c86f2a056b8, 0x8ca64e2b9466bd1e, 0xd4f29f377c4a97fb, 0x4a08fa12d0b5f830], [0xa83a78205916fdb9, 0x27db4a02c93979b8, 0xdd255593f4634ac9, 0x6434e90a0dbadb46]],
+    [[0xb8609ded71bf0192, 0x907e3e67d50e8237, 0x3347089741a15008, 0x44f6105d78da1f6b], [0x4007bd7d321d2679, 0x5f4ba6db9c6d4dd1, 0x23623eed43879914, 0x423d9f42b76c102d]],
+    [[0x3eb30f67adf27a82, 0x13dab187e23b2c53, 0x59bf5157c7de84cb, 0x3ee2b210177e6361], [0x0f93f5d39c162c47, 0xdcc41b354da2d945, 0x503e85de8b257162, 0x68a54c7ab0989800]],
+    [[0xfb51b454af443433, 0x33efa03edf4a8eab, 0xf542b558cfbc2467, 0x025808509d042440], [0xdd352872f487eb90, 0x9bbe8ca0894c78b6, 0x48f8dc23bacfedb1, 0x5267a410442f2fd3]],
+    [[0x3e6b247e410e8e58, 0x7b9f3d6d38bfac8c, 0xed635241891b0077, 0x24fd404cf002d1be], [0x14882ca004af4e4b, 0x9aa82301c12603f2, 0x0873b4b54d01de7b, 0x39d6294a3eeee3d5]],
+    [[0x35a60b28cd47fca5, 0x554689981d38fd64, 0xbdfb0a93f48df462, 0x010534b6b58b24b3], [0x688c533d4708a67a, 0xda07816ccec1b4f2, 0x2633fa3f8e1c9362, 0x2000abba4295e3d8]],
+    [[0x84e97c2bd3f62c3c, 0x9c8d3e649d5c08a1, 0x14f2485ddc37800b, 0x3afb3def1a5320fb], [0x51d60c670c71fade, 0x52d1d96f15b8c8b1, 0xf8d813d1d7b2fad5, 0x2fd422b82d4855c0]],
+    [[0xe00bac40dd0393b5, 0x8bbc50c46d563da2, 0x325ee8898093aa6a, 0x4b6c48fef0a0e8de], [0x5522829398a47eb0, 0x078209e2c27bc43b, 0xf1a3ff4a0df0b00a, 0x5c4460f116583eca]],
+    [[0xd8ae1befb750354b, 0x21b0c9bfad0aecc7, 0x1670b063da7cb769, 0x3196f3e9e0d361c3], [0x73558bc799fe5cd7, 0x0ccf3e4b27211da4, 0x689bd30d6ea81a23, 0x130e6621d67a2d35]],
+    [[0xcc6c182ff05c3ff9, 0x3cc61e44cea71ffe, 0xf292db8aacf22257, 0x42506fdd5c079728], [0xb5e9c27177541c8e, 0xc6dcaaa8818e45ad, 0xfd8357965e725d77, 0x28bf580947506a7e]],
+    [[0xccf11423f9083173, 0x4116cb52bd739c45, 0x9990a450681b39b4, 0x5bd77cfa1e7cd635], [0x4e90e9a371fb0415, 0xaf0b4a0420260a0a, 0xa908f5a1ddcd31c4, 0x3ebe78b4685df3d1]],
+    [[0x28e22cb12200a04c, 0x6212e9fd4b44107f, 0x2b2dc4fed54800da, 0x439b75a87f5d06ff], [0x471227424d043e00, 0x09ee04862a8b3abc, 0x5f0d6928ad457d3a, 0x2fdadb586bfdf370]],
+    [[0x47507fa28ad2521f, 0xe67a56e019831c0a, 0x6f00ac56b4ebc723, 0x1d547b86eb012df5], [0x94c5837e2f46fbc7, 0x42b3c3c4aeae11b5, 0x679cb50f575d18c3, 0x70743f3c9612c245]],
+    [[0x45c39f1d3cf30042, 0x371c45aa7034908d, 0xff1ccb2367721a31, 0x4ed49c93e841a6a0], [0x40f725e8b0a57a36, 0x84568abf99e014c5, 0x757775ce71d4f65a, 0x16697d8ce02fde8e]],
+    [[0xb83708758c048b94, 0x96072c6d2858f6dd, 0x5732f5c8d572b2b7, 0x50d8722bf3b8aec0], [0x497892a6bfd48bde, 0x80a91e36eace88c4, 0x042f4c79fdef8c6b, 0x529438c36d871a0e]],
+    [[0x147cfd71267a037b, 0xa2c2584446479103, 0x37b96bb6049c51ac, 0x7019f6c8c1fb8bfa], [0xf8c2797d8b7409d1, 0xdf6b2fbe2eceaac8, 0x92aed56af47e0d45, 0x5fbde9d2c94210db]],
+    [[0xc0da69e5d585b2ae, 0xba8558fe19410e54, 0xc7b2ca08b2695f9b, 0x63c7880a9d55a811], [0x309ce0fc1a7e70c2, 0xd7ffb17564b372bc, 0xaee166ae1a7b236b, 0x2c33bc5b13af420d]],
+    [[0x0d23c023095f29a2, 0xa7e731fed674f0e2, 0x41b2530e2259d6ce, 0x0a4f1ea51fa86d3d], [0x94e5abbdbe19e328, 0x95436644370babaa, 0xc59a9d3f515a99ba, 0x3f6b11966bbf9b54]],
+    [[0x004a1f48e4aea40d, 0xdbb5fb3029fe186c, 0xf63925cf0e357709, 0x1cb3accfcc328f68], [0x09731009176d09d6, 0x04a355b36add5742, 0x589eb0a3850d2063, 0x6546da893132b7d3]],
+    [[0x5c407308ba69035f, 0xdc09716d5b2ae1cc, 0x41b376a44e0fbf3c, 0x241bac83cf8a607b], [0x9691072eef8adf21, 0x07228b163f9a42ee, 0x2053b5830c7336e8, 0x1b39c52e6a80a335]],
+    [[0x6c9a181b062d41e9, 0x5728c45cdf5d00a3, 0x484d497460df1ceb, 0x6934e315ea6b7ac5], [0x46024019288412ab, 0x1dd4e15dc6bcce8f, 0x355b23ee6cfb2e49, 0x12215f92a8d9d686]],
+    [[0xeb9938850d4ff77c, 0x9e7cb6224f333b58, 0x785beec9316e7a8d, 0x442923cb758e014f], [0x26742b83532e7e95, 0xc904328ee485bca2, 0x742192c7770a41ae, 0x48b565a12c6993b3]],
+    [[0x2818e9b620e41614, 0xda93370de1891d0d, 0x808de6b61e77faa6, 0x3037c949a2569085], [0xa014c2ce24d79584, 0xb4b659c53aa38535, 0x32f5f8c45a9e39a4, 0x4d9882dc54b5ae9a]],
+    [[0x591412b0954ad5d8, 0xece4b2a6851190ac, 0x07f3629f082b7f46, 0x48e680d83893f2ab], [0x939314d4d3ac4783, 0x421e7eb76e1252af, 0xbe435681e8bd7e7d, 0x65fc162a5b4e29ec]],
+    [[0xc1bec2cd4ccb2ea8, 0xf6b806c8d4f87e8f, 0x3fd0b0f7e2da01c7, 0x715e40ad1d05d2f7], [0x63c9af380b08c0ee, 0x146b1e79b1c9af14, 0xb2106d3efed2633b, 0x2a9509273db1d79a]],
+    [[0x1643609b36a1fdb0, 0x653609f4870b7453, 0xd81f8c28ffcd7bf5, 0x4eac6863994dbef3], [0x21d5a72d082c13cc, 0x612542200545f6de, 0x0e861e587458bf48, 0x07580a9ecca52d3c]],
+    [[0xa107197b7aaff57c, 0xa4c6760fd3885b8d, 0x1de7deecd042ddb5, 0x347b10746c640ca4], [0xe30caa95e4b6eb63, 0x58e64fed67e2e931, 0x884ce9c39d76bf0d, 0x60e8b12a2b2371f4]],
+    [[0x0920a37be47fc042, 0x49f7765767d706d0, 0x98e99f98ce14c691, 0x27803dbbe405c57b], [0xd7c698aab2472bd9, 0x8bbb8aba59642c02, 0x121f3958406cbb67, 0x2bd08d39947bacef]],
+    [[0x05b29d48410d6ae8, 0x8bd1a20988044545, 0xaf305efec7a2ba85, 0x2c48711137a0a1f4], [0x4aea8fd5325de336, 0x47686c71c1300f85, 0xa8800e19c661f9b3, 0x4a60594a9c533fbd]],
+    [[0xe84d972cf382de0e, 0xa6e59458cc3c157c, 0x3efb340abc18a5c0, 0x3f88fd0cf1c74ac2], [0x513c505272c71469, 0xb9c5451db73eb0f1, 0xed0bc2105e960bdb, 0x243cccb55b15640f]],
+    [[0x4eef7907ed1d3eaa, 0x1f338edb70626590, 0xd393d9998d59eb0f, 0x5e476fc127e4bb5e], [0xd180dd1da55cb295, 0x5310ff353533f506, 0x3428c91ebccff8a0, 0x3880a0a04e3bec8b]],
+    [[0x25daf6a7909dc9b8, 0xf9f81f5b98e07ab9, 0x2bab90681da32f0f, 0x419e73e215f4a94d], [0x577b92399d1cd2ab, 0xbc7b33db901e4beb, 0x3fc0ea83010530f9, 0x33d449bd165eee9e]],
+    [[0x8b93148916727936, 0xc9df1a65c3fcf7a7, 0x0e930187d0611057, 0x0a1b5ffce2f3279f], [0xfcdbe695587706cf, 0x6c3f961f79351f6e, 0xc9e78eb21f33ff58, 0x02fb78b600fddc44]],
+    [[0x2d6e70dc3f8c1918, 0x1e95fb429f8c8cc6, 0xa79e93a93834b464, 0x28444f5f1ad2c541], [0xa9bc54aa0f6b69bb, 0xe4aa5ad0e94ca235, 0x7c221edb95413488, 0x5e5e50a268470bc2]],
+    [[0xb7bcde6f5155b56b, 0x7eb14ea3c8efd5ef, 0xf30ecbdc137fb14f, 0x532f2a957a09c555], [0x502a316916127016, 0x6687d82e471c2f8b, 0x335acd609c5f87ae, 0x5f955a0268b4d9ea]],
+    [[0x46bfe4aa636a733d, 0x20e9335ba0a312f5, 0xdadd37b501fe33f4, 0x19fe0426226490e8], [0x70f694bc178506fb, 0xcce34b35c49aea12, 0xc1fb5334b545a9c5, 0x487d00e4d3632395]],
+    [[0x4ce19e62597aadbd, 0xbe9359687a093419, 0xdf7c06831f5fb957, 0x6d3b9bbfbe46a165], [0xe3b9e95d2c6fafb7, 0x9acc8922ce104296, 0x3ccf08c05d4e33e6, 0x1dd4cf163c147c98]],
+    [[0xe3833deb77050477, 0x10782484b784a27e, 0xa87371f8a6b176c4, 0x6f5932f6ecb879e3], [0x446535da3b6dbc64, 0xb9732c91a215522a, 0xc17cf5e70ee22833, 0x5c948415ce581ad9]],
+    [[0xeb65a4c932cd2950, 0x945ec507710e5b69, 0xfe592fc1e699d8a3, 0x18aee7292336ad67], [0x625ed6de731e815a, 0xe1584ce7fc157c44, 0x6c1006a97178207b, 0x1e7f56c6122ad67b]],
+    [[0x7e5501a88ba67851, 0xc30585e5609cae53, 0x51127ef35c505abe, 0x57b39d91f6150072], [0x15d06f1868f5734d, 0x5d97608cdf85665d, 0x0a4049236a0c7289, 0x3dae05c3f49374fc]],
+    [[0xf8f553facf4e206a, 0xe2f2fdec3efbeba5, 0xa8a48ce9f643f931, 0x50cc11cefc2111ad], [0x2a723d4c17f37663, 0x1e905c8c26eb2565, 0x11e5a35bf7b3ac0e, 0x3268ee5499df4576]],
+    [[0x66f4f5f9eda64115, 0x1f926ef406aba683, 0x9a0097646fcf36ad, 0x6d9db5b6386b599d], [0x0b0986497cdb4ed3, 0xcd1c41ae9586d759, 0x954d6ca4ab7501cc, 0x4dbff25d4496f5c0]],
+    [[0x70da9dc3b5bc1f9e, 0xc74dfc5bb9b4db2e, 0xe1a92ef64e77755d, 0x3d9b083441c98858], [0x1ae22e242c26a853, 0x3255855a12b02aef, 0xe1a21e565b25136f, 0x2ebe124527dd644f]],
+    [[0xe1b0b4b2c1338900, 0x41c7df0de784a46f, 0x54ea095305d74728, 0x32223f568cb1c6bb], [0x322a774bab1dd2a0, 0xfa5a69f3e836783d, 0xe3876d6ade334699, 0x1b444f7e9e17af4a]],
+    [[0x209cc8c5d8a04080, 0xa0b5756c7d77a292, 0xb51e0ebae0fb90ec, 0x5a6e37aaffbe1978], [0x374da08073076b9f, 0x7eecd0d7cab85caf, 0xcc5262acdef3711a, 0x1e454ca90ee929ce]],
+    [[0x54558f5ef2a59908, 0x92d6fae806c1751c, 0xe272247d0356baea, 0x5b7cb673936ee549], [0x5d0b35f3ac9ac9d2, 0xedf57ce5aa95fbbe, 0x69e3c3bd214a56ba, 0x236f559cdb00c47c]],
+    [[0xe1346d1bb4597930, 0x6f1d88245ac30513, 0x417dbb52356c3a1f, 0x46e7b228ca0a170b], [0x391e187c0f38566b, 0x05ce35a93fa7a012, 0xd4580e11577e9454, 0x3f2ccb512d57b06c]],
+    [[0x3fbc50d70809b195, 0x9348c0242712cdc0, 0x8f2f1715b8a934f8, 0x6264f98c3acacb59], [0x404ae45aeac2efb1, 0xb8befb8a2f7bde5f, 0x52a366744d5b73f3, 0x469fba478cad94fa]],
+];
@@ -0,0 +1,212 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! PLONK gadgets mirroring the native key operations
+//!
+//! The gadgets hash the shared secrets with the same Poseidon truncated hash
+//! used natively, so a circuit built with them accepts exactly the stealth
+//! addresses and one-time keys computed by [`PublicSpendKey`],
+//! [`ViewKey`](crate::ViewKey) and [`SecretSpendKey`].
+
+use crate::nullifier;
+use crate::{JubJubScalar, PublicSpendKey, SecretSpendKey, StealthAddress};
+
+use dusk_jubjub::{BlsScalar, GENERATOR_EXTENDED, GENERATOR_NUMS_EXTENDED};
+use dusk_plonk::prelude::{Composer, Constraint, Error, Witness, WitnessPoint};
+use dusk_poseidon::sponge;
+use dusk_poseidon::sponge::truncated;
+
+/// Order of the JubJub scalar field, as an element of the BLS scalar field
+const JUBJUB_ORDER: BlsScalar = BlsScalar::from_raw([
+    0xd0970e5ed6f72cb7,
+    0xa6682093ccc81082,
+    0x06673b0101343b00,
+    0x0e7db4ea6533afa9,
+]);
+
+/// [`StealthAddress`] appended to a circuit
+#[derive(Debug, Clone, Copy)]
+pub struct WitnessStealthAddress {
+    /// Random point `R`
+    pub R: WitnessPoint,
+    /// One-time public key `pk_r`
+    pub pk_r: WitnessPoint,
+}
+
+impl WitnessStealthAddress {
+    /// Appends the points of a [`StealthAddress`] as private witnesses
+    pub fn append<C: Composer>(composer: &mut C, sa: &StealthAddress) -> Self {
+        let R = composer.append_point(*sa.R());
+        let pk_r = composer.append_point(*sa.address());
+
+        Self { R, pk_r }
+    }
+
+    /// Appends the points of a [`StealthAddress`] as public inputs
+    pub fn append_public<C: Composer>(
+        composer: &mut C,
+        sa: &StealthAddress,
+    ) -> Self {
+        let R = composer.append_public_point(*sa.R());
+        let pk_r = composer.append_public_point(*sa.address());
+
+        Self { R, pk_r }
+    }
+}
+
+/// Appends the points `A` and `B` of a [`PublicSpendKey`] as private
+/// witnesses
+pub fn append_public_spend_key<C: Composer>(
+    composer: &mut C,
+    psk: &PublicSpendKey,
+) -> (WitnessPoint, WitnessPoint) {
+    let A = composer.append_point(*psk.A());
+    let B = composer.append_point(*psk.B());
+
+    (A, B)
+}
+
+/// Appends the scalars `a` and `b` of a [`SecretSpendKey`] as private
+/// witnesses
+pub fn append_secret_spend_key<C: Composer>(
+    composer: &mut C,
+    ssk: &SecretSpendKey,
+) -> (Witness, Witness) {
+    let a = composer.append_witness(*ssk.a());
+    let b = composer.append_witness(*ssk.b());
+
+    (a, b)
+}
+
+/// Hashes a point with the truncated Poseidon hash, mirroring
+/// `permutation::hash`
+fn hash<C: Composer>(composer: &mut C, p: WitnessPoint) -> Witness {
+    truncated::gadget(composer, &[*p.x(), *p.y()])
+}
+
+/// Generates the stealth address `R = r·G`, `pk_r = H(r·A)·G + B`, mirroring
+/// [`PublicSpendKey::gen_stealth_address`]
+pub fn gen_stealth_address<C: Composer>(
+    composer: &mut C,
+    A: WitnessPoint,
+    B: WitnessPoint,
+    r: Witness,
+) -> Result<WitnessStealthAddress, Error> {
+    let R = composer.component_mul_generator(r, GENERATOR_EXTENDED)?;
+
+    let rA = composer.component_mul_point(r, A);
+    let rA = hash(composer, rA);
+    let rA = composer.component_mul_generator(rA, GENERATOR_EXTENDED)?;
+
+    let pk_r = composer.component_add_point(rA, B);
+
+    Ok(WitnessStealthAddress { R, pk_r })
+}
+
+/// Constrains `pk_r = H(a·R)·G + B`, mirroring
+/// [`ViewKey::owns`](crate::ViewKey::owns)
+pub fn owns<C: Composer>(
+    composer: &mut C,
+    a: Witness,
+    B: WitnessPoint,
+    sa: &WitnessStealthAddress,
+) -> Result<(), Error> {
+    let aR = composer.component_mul_point(a, sa.R);
+    let aR = hash(composer, aR);
+    let aR = composer.component_mul_generator(aR, GENERATOR_EXTENDED)?;
+
+    let pk_r = composer.component_add_point(aR, B);
+    composer.assert_equal_point(pk_r, sa.pk_r);
+
+    Ok(())
+}
+
+/// Computes the one-time secret key `sk_r = H(a·R) + b`, mirroring
+/// [`SecretSpendKey::sk_r`]
+///
+/// The sum is reduced modulo the order of the JubJub scalar field: an honest
+/// prover assigns the witness the same value as the native `sk_r`, so it can
+/// be fed to [`Composer::component_mul_generator`], and the constraints bind
+/// it to `H(a·R) + b` modulo the order.
+pub fn sk_r<C: Composer>(
+    composer: &mut C,
+    a: Witness,
+    b: Witness,
+    R: WitnessPoint,
+) -> Witness {
+    let aR = composer.component_mul_point(a, R);
+    let aR = hash(composer, aR);
+
+    // The hash is truncated to 250 bits and `b` is a JubJub scalar, so the sum
+    // exceeds the order at most once
+    let h = jubjub_scalar(&composer[aR]);
+    let b_value = jubjub_scalar(&composer[b]);
+
+    let sk = h + b_value;
+    let sk_bls = BlsScalar::from(sk);
+    let carry = match sk_bls == composer[aR] + composer[b] {
+        true => BlsScalar::zero(),
+        false => BlsScalar::one(),
+    };
+
+    let sk = composer.append_witness(sk_bls);
+    let carry = composer.append_witness(carry);
+
+    composer.component_boolean(carry);
+    composer.component_range::<126>(sk);
+
+    // sk + carry·order - H(a·R) - b = 0
+    let constraint = Constraint::new()
+        .left(1)
+        .a(sk)
+        .right(JUBJUB_ORDER)
+        .b(carry)
+        .fourth(-BlsScalar::one())
+        .d(aR)
+        .output(-BlsScalar::one())
+        .o(b);
+    composer.append_gate(constraint);
+
+    sk
+}
+
+/// Computes the [`Nullifier`](crate::Nullifier) of the one-time key `sk_r`,
+/// mirroring [`Nullifier::new`](crate::Nullifier::new)
+pub fn nullifier<C: Composer>(
+    composer: &mut C,
+    sk_r: Witness,
+) -> Result<Witness, Error> {
+    let image =
+        composer.component_mul_generator(sk_r, GENERATOR_NUMS_EXTENDED)?;
+    let domain = composer.append_constant(nullifier::DOMAIN);
+
+    Ok(sponge::gadget(composer, &[domain, *image.x(), *image.y()]))
+}
+
+/// Computes the [`Nullifier`](crate::Nullifier) of the one-time key `sk_r`
+/// bound to the position of the output, mirroring
+/// [`Nullifier::with_position`](crate::Nullifier::with_position)
+pub fn nullifier_with_position<C: Composer>(
+    composer: &mut C,
+    sk_r: Witness,
+    pos: Witness,
+) -> Result<Witness, Error> {
+    let image =
+        composer.component_mul_generator(sk_r, GENERATOR_NUMS_EXTENDED)?;
+    let domain = composer.append_constant(nullifier::DOMAIN);
+
+    Ok(sponge::gadget(
+        composer,
+        &[domain, *image.x(), *image.y(), pos],
+    ))
+}
+
+/// Interprets a witness value as a JubJub scalar, mapping out of range
+/// values to zero so the circuit is rejected by its constraints instead of
+/// panicking
+fn jubjub_scalar(value: &BlsScalar) -> JubJubScalar {
+    JubJubScalar::from_bytes(&value.to_bytes()).unwrap_or(JubJubScalar::zero())
+}
@@ -0,0 +1,191 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Hashing to the JubJub curve
+//!
+//! The construction follows the structure of the IETF `hash_to_curve` draft,
+//! with Poseidon in place of `expand_message`: the message is hashed into
+//! field elements under an explicit [`DomainSeparationTag`], every field
+//! element is mapped to the curve with Elligator 2, and the result is
+//! multiplied by the cofactor so it lands in the prime order subgroup.
+//!
+//! Every step runs in constant time with respect to the message.
+
+use crate::{JubJubAffine, JubJubExtended};
+
+use dusk_jubjub::BlsScalar;
+use dusk_poseidon::sponge;
+use ff::Field;
+use subtle::{ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
+
+/// Number of bytes of a message packed into a single field element
+const BYTES_PER_SCALAR: usize = 31;
+
+/// Domain separation tag of a hash to curve
+///
+/// Every protocol hashing to the curve must use its own tag, so that the
+/// points derived for one purpose are unrelated to the points derived for
+/// another one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DomainSeparationTag(BlsScalar);
+
+impl DomainSeparationTag {
+    /// Creates a tag from an arbitrary byte string, such as
+    /// `b"dusk-network/generators/v1"`
+    pub fn new(tag: &[u8]) -> Self {
+        Self(hash_bytes(tag))
+    }
+
+    /// Creates a tag from a raw field element
+    ///
+    /// Intended for constant tags; the caller is responsible for making sure
+    /// the element does not collide with the tags of other protocols.
+    pub const fn from_scalar(tag: BlsScalar) -> Self {
+        Self(tag)
+    }
+
+    /// Gets the tag as a field element
+    pub const fn as_scalar(&self) -> &BlsScalar {
+        &self.0
+    }
+}
+
+/// Non-square of the base field used by the Elligator 2 map
+const Z: BlsScalar = BlsScalar::from_raw([5, 0, 0, 0]);
+
+/// `K` of the Montgomery form `K·t² = s³ + J·s² + s` of JubJub, `-40964`
+const K: BlsScalar = BlsScalar::from_raw([
+    0xfffffffeffff5ffd,
+    0x53bda402fffe5bfe,
+    0x3339d80809a1d805,
+    0x73eda753299d7d48,
+]);
+
+/// `J / K`, where `J = 40962`
+const J_DIV_K: BlsScalar = BlsScalar::from_raw([
+    0x00832feb6b1a1f58,
+    0x1496bfb69babce93,
+    0x7afec903f35ebfea,
+    0x15498c73a5fd15a4,
+]);
+
+/// `1 / K²`
+const INV_K2: BlsScalar = BlsScalar::from_raw([
+    0xb1dec13d57ee22ee,
+    0xc36c50191c27f784,
+    0xc984b83f5d049e6b,
+    0x2b806e727d7b6e67,
+]);
+
+/// Hashes a message into a point of the prime order subgroup of JubJub.
+///
+/// Two field elements are derived from the message and mapped to the curve,
+/// so the output is indistinguishable from a uniformly random point.
+pub fn hash_to_curve(
+    dst: &DomainSeparationTag,
+    message: &[BlsScalar],
+) -> JubJubExtended {
+    let message = sponge::hash(message);
+
+    let u0 = hash_to_field(dst, 0, &message);
+    let u1 = hash_to_field(dst, 1, &message);
+
+    let p = map_to_curve(&u0) + map_to_curve(&u1);
+
+    p.mul_by_cofactor()
+}
+
+/// Hashes a byte string into a point of the prime order subgroup of JubJub,
+/// with the same guarantees of [`hash_to_curve`].
+pub fn hash_bytes_to_curve(
+    dst: &DomainSeparationTag,
+    message: &[u8],
+) -> JubJubExtended {
+    hash_to_curve(dst, &[hash_bytes(message)])
+}
+
+/// Encodes a message into a point of the prime order subgroup of JubJub.
+///
+/// Cheaper than [`hash_to_curve`] since a single field element is mapped to
+/// the curve, but the output distribution is not uniform: it must only be
+/// used when the protocol tolerates it.
+pub fn encode_to_curve(
+    dst: &DomainSeparationTag,
+    message: &[BlsScalar],
+) -> JubJubExtended {
+    let message = sponge::hash(message);
+
+    let u = hash_to_field(dst, 2, &message);
+
+    map_to_curve(&u).mul_by_cofactor()
+}
+
+fn hash_to_field(
+    dst: &DomainSeparationTag,
+    index: u64,
+    message: &BlsScalar,
+) -> BlsScalar {
+    sponge::hash(&[dst.0, BlsScalar::from(index), *message])
+}
+
+/// Hashes an arbitrary byte string into a field element, absorbing it in
+/// chunks that always fit the field modulus
+fn hash_bytes(bytes: &[u8]) -> BlsScalar {
+    let length = BlsScalar::from(bytes.len() as u64);
+
+    bytes.chunks(BYTES_PER_SCALAR).fold(length, |state, chunk| {
+        let mut buf = [0u8; 32];
+        buf[..chunk.len()].copy_from_slice(chunk);
+
+        let chunk = BlsScalar::from_bytes(&buf).unwrap_or(BlsScalar::zero());
+        sponge::hash(&[state, chunk])
+    })
+}
+
+/// Elligator 2 map of a field element to a JubJub point, computed in
+/// constant time
+fn map_to_curve(u: &BlsScalar) -> JubJubExtended {
+    let one = BlsScalar::one();
+    let zero = BlsScalar::zero();
+
+    // Map to the Montgomery curve `K·t² = s³ + J·s² + s`
+    let tv = (one + Z * u.square()).invert_ct().unwrap_or(zero);
+
+    let x1 = -J_DIV_K * tv;
+    let x1 = BlsScalar::conditional_select(&x1, &-J_DIV_K, x1.ct_eq(&zero));
+    let gx1 = x1.square() * x1 + J_DIV_K * x1.square() + x1 * INV_K2;
+
+    let x2 = -x1 - J_DIV_K;
+    let gx2 = x2.square() * x2 + J_DIV_K * x2.square() + x2 * INV_K2;
+
+    let y1 = gx1.sqrt();
+    let is_square = y1.is_some();
+    let y2 = gx2.sqrt().unwrap_or(zero);
+
+    let x = BlsScalar::conditional_select(&x2, &x1, is_square);
+    let mut y =
+        BlsScalar::conditional_select(&y2, &y1.unwrap_or(zero), is_square);
+
+    // `sgn0(y)` must be `1` if `gx1` is square, `0` otherwise
+    let sign = (y.to_bytes()[0] & 1).ct_eq(&1);
+    y.conditional_negate(sign ^ is_square);
+
+    let s = x * K;
+    let t = y * K;
+
+    // Rational map to the twisted Edwards form of JubJub, sending the
+    // exceptional cases to the identity
+    let s1 = s + one;
+    let exceptional = t.ct_eq(&zero) | s1.ct_eq(&zero);
+
+    let u = s * t.invert_ct().unwrap_or(zero);
+    let v = (s - one) * s1.invert_ct().unwrap_or(zero);
+
+    let u = BlsScalar::conditional_select(&u, &zero, exceptional);
+    let v = BlsScalar::conditional_select(&v, &one, exceptional);
+
+    JubJubAffine::from_raw_unchecked(u, v).into()
+}
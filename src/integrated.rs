@@ -0,0 +1,196 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use crate::keys::spend::stealth::Ownable;
+use crate::{
+    JubJubExtended, JubJubScalar, KeyDerivation, KeyError, PublicSpendKey,
+    StealthAddress,
+};
+
+use dusk_bytes::{DeserializableSlice, HexDebug, Serializable};
+
+#[cfg(feature = "rkyv-impl")]
+use crate::keys::spend::stealth::ArchivedStealthAddress;
+#[cfg(feature = "rkyv-impl")]
+use bytecheck::{CheckBytes, ErrorBox, StructCheckError};
+#[cfg(feature = "rkyv-impl")]
+use rkyv::{Archive, Deserialize, Serialize};
+
+/// Size of a payment ID, in bytes
+pub const PAYMENT_ID_SIZE: usize = 8;
+
+/// [`PublicSpendKey`] bundled with a short payment ID
+///
+/// Every stealth address generated for an integrated address carries its
+/// payment ID, encrypted under the shared secret `r·A`, so only the owner of
+/// the [`ViewKey`] is able to tell which deposit an output pays for. This
+/// allows a single spend key to receive payments from many users.
+///
+/// [`ViewKey`]: crate::ViewKey
+#[derive(HexDebug, Clone, Copy, PartialEq, Eq)]
+pub struct IntegratedAddress {
+    psk: PublicSpendKey,
+    payment_id: [u8; PAYMENT_ID_SIZE],
+}
+
+impl IntegratedAddress {
+    /// Creates a new integrated address from a key and a payment ID
+    pub const fn new(
+        psk: PublicSpendKey,
+        payment_id: [u8; PAYMENT_ID_SIZE],
+    ) -> Self {
+        Self { psk, payment_id }
+    }
+
+    /// Gets the key receiving the payments
+    pub const fn psk(&self) -> &PublicSpendKey {
+        &self.psk
+    }
+
+    /// Gets the payment ID
+    pub const fn payment_id(&self) -> &[u8; PAYMENT_ID_SIZE] {
+        &self.payment_id
+    }
+
+    /// Generates new `PKr = H(A · r) · G + B` from a given `r`, encrypting
+    /// the payment ID under `A · r`
+    pub fn gen_stealth_address(
+        &self,
+        r: &JubJubScalar,
+    ) -> IntegratedStealthAddress {
+        self.gen_stealth_address_with(&KeyDerivation::LEGACY, r)
+    }
+
+    /// Generates new `PKr = H(A · r) · G + B` from a given `r`, encrypting
+    /// the payment ID under `A · r`, and hashing the shared secret with the
+    /// given [`KeyDerivation`]
+    pub fn gen_stealth_address_with(
+        &self,
+        derivation: &KeyDerivation,
+        r: &JubJubScalar,
+    ) -> IntegratedStealthAddress {
+        let sa = self.psk.gen_stealth_address_with(derivation, r);
+
+        let rA = self.psk.A() * r;
+        let encrypted_id = encrypt(derivation, &rA, &self.payment_id);
+
+        IntegratedStealthAddress { sa, encrypted_id }
+    }
+}
+
+impl Serializable<{ PublicSpendKey::SIZE + PAYMENT_ID_SIZE }>
+    for IntegratedAddress
+{
+    type Error = KeyError;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0u8; Self::SIZE];
+        bytes[..PublicSpendKey::SIZE].copy_from_slice(&self.psk.to_bytes());
+        bytes[PublicSpendKey::SIZE..].copy_from_slice(&self.payment_id);
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        let psk = PublicSpendKey::from_slice(&bytes[..PublicSpendKey::SIZE])?;
+
+        let mut payment_id = [0u8; PAYMENT_ID_SIZE];
+        payment_id.copy_from_slice(&bytes[PublicSpendKey::SIZE..]);
+
+        Ok(Self { psk, payment_id })
+    }
+}
+
+/// [`StealthAddress`] generated for an [`IntegratedAddress`], along with its
+/// encrypted payment ID
+#[derive(Default, HexDebug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "rkyv-impl", derive(Archive, Serialize, Deserialize))]
+pub struct IntegratedStealthAddress {
+    sa: StealthAddress,
+    encrypted_id: [u8; PAYMENT_ID_SIZE],
+}
+
+#[cfg(feature = "rkyv-impl")]
+impl<C: ?Sized> CheckBytes<C> for ArchivedIntegratedStealthAddress {
+    type Error = StructCheckError;
+
+    unsafe fn check_bytes<'a>(
+        value: *const Self,
+        context: &mut C,
+    ) -> Result<&'a Self, Self::Error> {
+        ArchivedStealthAddress::check_bytes(
+            core::ptr::addr_of!((*value).sa),
+            context,
+        )
+        .map_err(|e| StructCheckError {
+            field_name: "sa",
+            inner: ErrorBox::new(e),
+        })?;
+
+        // Every bit pattern is a valid encrypted ID
+        Ok(&*value)
+    }
+}
+
+impl IntegratedStealthAddress {
+    /// Gets the encrypted payment ID
+    pub const fn encrypted_id(&self) -> &[u8; PAYMENT_ID_SIZE] {
+        &self.encrypted_id
+    }
+
+    /// Decrypts the payment ID with the shared secret `a · R`
+    pub(crate) fn decrypt_id(
+        &self,
+        derivation: &KeyDerivation,
+        aR: &JubJubExtended,
+    ) -> [u8; PAYMENT_ID_SIZE] {
+        // The cipher is a one-time pad, so decryption mirrors encryption
+        encrypt(derivation, aR, &self.encrypted_id)
+    }
+}
+
+impl Ownable for IntegratedStealthAddress {
+    fn stealth_address(&self) -> &StealthAddress {
+        &self.sa
+    }
+}
+
+impl Serializable<{ StealthAddress::SIZE + PAYMENT_ID_SIZE }>
+    for IntegratedStealthAddress
+{
+    type Error = KeyError;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0u8; Self::SIZE];
+        bytes[..StealthAddress::SIZE].copy_from_slice(&self.sa.to_bytes());
+        bytes[StealthAddress::SIZE..].copy_from_slice(&self.encrypted_id);
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        let sa = StealthAddress::from_slice(&bytes[..StealthAddress::SIZE])?;
+
+        let mut encrypted_id = [0u8; PAYMENT_ID_SIZE];
+        encrypted_id.copy_from_slice(&bytes[StealthAddress::SIZE..]);
+
+        Ok(Self { sa, encrypted_id })
+    }
+}
+
+/// Masks the payment ID with the encryption key derived from the shared
+/// secret
+///
+/// Every shared secret is bound to a fresh `r`, so the key is never reused.
+fn encrypt(
+    derivation: &KeyDerivation,
+    shared: &JubJubExtended,
+    id: &[u8; PAYMENT_ID_SIZE],
+) -> [u8; PAYMENT_ID_SIZE] {
+    let key = derivation.encryption_key(shared).to_bytes();
+
+    let mut encrypted = *id;
+    encrypted.iter_mut().zip(key).for_each(|(b, k)| *b ^= k);
+    encrypted
+}
@@ -0,0 +1,170 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use crate::keys::spend::stealth::Ownable;
+use crate::{
+    KeyDerivation, PublicSpendKey, SecretKey, SecretSpendKey, ViewKey,
+};
+
+use alloc::collections::BTreeMap;
+
+use dusk_jubjub::BlsScalar;
+use dusk_poseidon::sponge::truncated;
+
+/// Domain separator of the account keys, `"account"` in ASCII
+const DOMAIN: BlsScalar = BlsScalar::from_raw([0x0074_6e75_6f63_6361, 0, 0, 0]);
+
+/// Size in bytes of the master seed of a [`Keyring`]
+pub const SEED_SIZE: usize = 32;
+
+/// Public material of an account managed by a [`Keyring`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Account {
+    index: u32,
+    vk: ViewKey,
+    psk: PublicSpendKey,
+}
+
+impl Account {
+    /// Gets the index of the account
+    pub const fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Gets the view key of the account
+    pub const fn view_key(&self) -> &ViewKey {
+        &self.vk
+    }
+
+    /// Gets the public spend key of the account
+    pub const fn public_spend_key(&self) -> &PublicSpendKey {
+        &self.psk
+    }
+}
+
+/// Many accounts derived from a single master seed
+///
+/// The secret spend key of every account is derived from the seed and its
+/// index on request, and never stored. The view key and the public spend key
+/// of an account are derived the first time the account is accessed, and
+/// cached from then on, so the accounts accessed are the ones searched for
+/// the owner of an output.
+#[derive(Clone)]
+pub struct Keyring {
+    seed: [u8; SEED_SIZE],
+    accounts: BTreeMap<u32, Account>,
+}
+
+impl Keyring {
+    /// Creates a keyring with no accounts from a master seed
+    pub const fn new(seed: [u8; SEED_SIZE]) -> Self {
+        Self {
+            seed,
+            accounts: BTreeMap::new(),
+        }
+    }
+
+    /// Derives the secret spend key of the account at the given index
+    ///
+    /// The key `(a, b)` is the pair of hashes `H(seed, index, 0)` and
+    /// `H(seed, index, 1)`.
+    pub fn secret_spend_key(&self, index: u32) -> SecretSpendKey {
+        let [s0, s1, s2, s3] = [0, 8, 16, 24].map(|i| {
+            let mut limb = [0u8; 8];
+            limb.copy_from_slice(&self.seed[i..i + 8]);
+            u64::from_le_bytes(limb)
+        });
+        let seed = [
+            BlsScalar::from_raw([s0, s1, 0, 0]),
+            BlsScalar::from_raw([s2, s3, 0, 0]),
+        ];
+        let index = BlsScalar::from(index as u64);
+
+        let a = truncated::hash(&[
+            DOMAIN,
+            seed[0],
+            seed[1],
+            index,
+            BlsScalar::zero(),
+        ]);
+        let b = truncated::hash(&[
+            DOMAIN,
+            seed[0],
+            seed[1],
+            index,
+            BlsScalar::one(),
+        ]);
+
+        SecretSpendKey::new(a, b)
+    }
+
+    /// Gets the account at the given index, deriving and caching its public
+    /// material if it is accessed for the first time
+    pub fn account(&mut self, index: u32) -> &Account {
+        if !self.accounts.contains_key(&index) {
+            let ssk = self.secret_spend_key(index);
+            let account = Account {
+                index,
+                vk: ssk.view_key(),
+                psk: ssk.public_spend_key(),
+            };
+            self.accounts.insert(index, account);
+        }
+
+        &self.accounts[&index]
+    }
+
+    /// Gets the view key of the account at the given index
+    pub fn view_key(&mut self, index: u32) -> &ViewKey {
+        self.account(index).view_key()
+    }
+
+    /// Gets the public spend key of the account at the given index
+    pub fn public_spend_key(&mut self, index: u32) -> &PublicSpendKey {
+        self.account(index).public_spend_key()
+    }
+
+    /// Iterates over the accessed accounts, by increasing index
+    pub fn accounts(&self) -> impl Iterator<Item = &Account> {
+        self.accounts.values()
+    }
+
+    /// Finds the accessed account owning the given output
+    pub fn owner(&self, owner: &impl Ownable) -> Option<&Account> {
+        self.owner_with(&KeyDerivation::LEGACY, owner)
+    }
+
+    /// Finds the accessed account owning the given output, hashing the
+    /// shared secrets with the given [`KeyDerivation`]
+    pub fn owner_with(
+        &self,
+        derivation: &KeyDerivation,
+        owner: &impl Ownable,
+    ) -> Option<&Account> {
+        self.accounts()
+            .find(|account| account.vk.owns_with(derivation, owner))
+    }
+
+    /// Computes the one-time secret key `sk_r = H(a · R) + b` of an output
+    /// owned by one of the accessed accounts
+    pub fn sk_r(&self, owner: &impl Ownable) -> Option<SecretKey> {
+        self.sk_r_with(&KeyDerivation::LEGACY, owner)
+    }
+
+    /// Computes the one-time secret key `sk_r = H(a · R) + b` of an output
+    /// owned by one of the accessed accounts, hashing the shared secret with
+    /// the given [`KeyDerivation`]
+    pub fn sk_r_with(
+        &self,
+        derivation: &KeyDerivation,
+        owner: &impl Ownable,
+    ) -> Option<SecretKey> {
+        let account = self.owner_with(derivation, owner)?;
+        let ssk = self.secret_spend_key(account.index);
+
+        Some(ssk.sk_r_with(derivation, owner.stealth_address()))
+    }
+}
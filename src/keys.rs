@@ -7,3 +7,32 @@
 pub mod public;
 pub mod secret;
 pub mod spend;
+
+use crate::{JubJubAffine, JubJubExtended, JubJubScalar, KeyError};
+
+/// Decodes a scalar, rejecting non canonical encodings
+pub(crate) fn decode_scalar(bytes: &[u8]) -> Result<JubJubScalar, KeyError> {
+    let mut buf = [0u8; 32];
+    buf.copy_from_slice(bytes);
+
+    Option::from(JubJubScalar::from_bytes(&buf)).ok_or(KeyError::InvalidScalar)
+}
+
+/// Decodes a compressed point
+pub(crate) fn decode_point(bytes: &[u8]) -> Result<JubJubExtended, KeyError> {
+    let mut buf = [0u8; 32];
+    buf.copy_from_slice(bytes);
+
+    let point: Option<JubJubAffine> = JubJubAffine::from_bytes(buf).into();
+    point.map(Into::into).ok_or(KeyError::OffCurvePoint)
+}
+
+/// Rejects a point of small order, the identity included
+pub(crate) fn check_small_order(
+    point: &JubJubExtended,
+) -> Result<(), KeyError> {
+    match bool::from(point.is_small_order()) {
+        true => Err(KeyError::SmallOrderPoint),
+        false => Ok(()),
+    }
+}
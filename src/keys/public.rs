@@ -5,25 +5,46 @@
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
 use super::secret::SecretKey;
-use crate::{JubJubAffine, JubJubExtended};
-use dusk_bytes::{Error, HexDebug, Serializable};
-use dusk_jubjub::GENERATOR_EXTENDED;
+use crate::keys::{check_small_order, decode_point};
+use crate::KeyError;
+use crate::{
+    fixed_base, vrf, JubJubAffine, JubJubExtended, VrfOutput, VrfProof,
+};
+use dusk_bytes::{HexDebug, Serializable};
+use dusk_jubjub::BlsScalar;
 
+#[cfg(feature = "rkyv-impl")]
+use crate::archive;
+#[cfg(feature = "rkyv-impl")]
+use bytecheck::{CheckBytes, TupleStructCheckError};
 #[cfg(feature = "rkyv-impl")]
 use rkyv::{Archive, Deserialize, Serialize};
 
 /// Structure repesenting a [`PublicKey`]
 #[derive(Default, Copy, Clone, HexDebug)]
-#[cfg_attr(
-    feature = "rkyv-impl",
-    derive(Archive, Serialize, Deserialize),
-    archive_attr(derive(bytecheck::CheckBytes))
-)]
+#[cfg_attr(feature = "rkyv-impl", derive(Archive, Serialize, Deserialize))]
 pub struct PublicKey(pub(crate) JubJubExtended);
 
+#[cfg(feature = "rkyv-impl")]
+impl<C: ?Sized> CheckBytes<C> for ArchivedPublicKey {
+    type Error = TupleStructCheckError;
+
+    unsafe fn check_bytes<'a>(
+        value: *const Self,
+        context: &mut C,
+    ) -> Result<&'a Self, Self::Error> {
+        archive::check_point(core::ptr::addr_of!((*value).0), context)
+            .map_err(|inner| TupleStructCheckError {
+                field_index: 0,
+                inner,
+            })?;
+        Ok(&*value)
+    }
+}
+
 impl From<&SecretKey> for PublicKey {
     fn from(sk: &SecretKey) -> Self {
-        let public_key = GENERATOR_EXTENDED * sk.0;
+        let public_key = fixed_base::mul_generator(&sk.0);
 
         PublicKey(public_key)
     }
@@ -58,19 +79,14 @@ impl AsRef<JubJubExtended> for PublicKey {
 }
 
 impl Serializable<32> for PublicKey {
-    type Error = Error;
+    type Error = KeyError;
 
     fn to_bytes(&self) -> [u8; 32] {
         JubJubAffine::from(self.0).to_bytes()
     }
 
-    fn from_bytes(bytes: &[u8; 32]) -> Result<Self, Error> {
-        let public_key: JubJubAffine =
-            match JubJubAffine::from_bytes(*bytes).into() {
-                Some(pk) => pk,
-                None => return Err(Error::InvalidData),
-            };
-        Ok(Self(public_key.into()))
+    fn from_bytes(bytes: &[u8; 32]) -> Result<Self, KeyError> {
+        decode_point(bytes).map(Self)
     }
 }
 
@@ -88,4 +104,24 @@ impl PublicKey {
     pub const fn from_raw_unchecked(key: JubJubExtended) -> Self {
         Self(key)
     }
+
+    /// Rejects a key of small order, the identity included
+    ///
+    /// Decoding accepts any point of the curve, so a key received from an
+    /// untrusted party can be checked before use.
+    pub fn check_small_order(&self) -> Result<(), KeyError> {
+        check_small_order(&self.0)
+    }
+
+    /// Verifies a proof generated with [`SecretKey::vrf_prove`] for the
+    /// given input, returning the VRF output if the proof is valid.
+    ///
+    /// Returns `None` for a key of small order, the identity included.
+    pub fn vrf_verify(
+        &self,
+        input: &[BlsScalar],
+        proof: &VrfProof,
+    ) -> Option<VrfOutput> {
+        vrf::verify(&self.0, input, proof)
+    }
 }
@@ -4,23 +4,43 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
-use crate::JubJubScalar;
-use dusk_bytes::{Error, HexDebug, Serializable};
+use crate::keys::decode_scalar;
+use crate::KeyError;
+use crate::{vrf, JubJubScalar, PublicKey, VrfOutput, VrfProof};
+use dusk_bytes::{HexDebug, Serializable};
+use dusk_jubjub::BlsScalar;
 use rand_core::{CryptoRng, RngCore};
 
+#[cfg(feature = "rkyv-impl")]
+use crate::archive;
+#[cfg(feature = "rkyv-impl")]
+use bytecheck::{CheckBytes, TupleStructCheckError};
 #[cfg(feature = "rkyv-impl")]
 use rkyv::{Archive, Deserialize, Serialize};
 
 /// Structure repesenting a secret key
 #[allow(non_snake_case)]
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, HexDebug)]
-#[cfg_attr(
-    feature = "rkyv-impl",
-    derive(Archive, Serialize, Deserialize),
-    archive_attr(derive(bytecheck::CheckBytes))
-)]
+#[cfg_attr(feature = "rkyv-impl", derive(Archive, Serialize, Deserialize))]
 pub struct SecretKey(pub(crate) JubJubScalar);
 
+#[cfg(feature = "rkyv-impl")]
+impl<C: ?Sized> CheckBytes<C> for ArchivedSecretKey {
+    type Error = TupleStructCheckError;
+
+    unsafe fn check_bytes<'a>(
+        value: *const Self,
+        context: &mut C,
+    ) -> Result<&'a Self, Self::Error> {
+        archive::check_scalar(core::ptr::addr_of!((*value).0), context)
+            .map_err(|inner| TupleStructCheckError {
+                field_index: 0,
+                inner,
+            })?;
+        Ok(&*value)
+    }
+}
+
 impl From<JubJubScalar> for SecretKey {
     fn from(s: JubJubScalar) -> SecretKey {
         SecretKey(s)
@@ -50,20 +70,25 @@ impl SecretKey {
 
         SecretKey(fr)
     }
+
+    /// Evaluates the verifiable random function on the given input,
+    /// returning the pseudorandom output together with the proof of its
+    /// correctness, to be checked with [`PublicKey::vrf_verify`].
+    pub fn vrf_prove(&self, input: &[BlsScalar]) -> (VrfOutput, VrfProof) {
+        let pk = PublicKey::from(self);
+
+        vrf::prove(&self.0, pk.as_ref(), input)
+    }
 }
 
 impl Serializable<32> for SecretKey {
-    type Error = Error;
+    type Error = KeyError;
 
     fn to_bytes(&self) -> [u8; 32] {
         self.0.to_bytes()
     }
 
-    fn from_bytes(bytes: &[u8; 32]) -> Result<Self, Error> {
-        let secret_key = match JubJubScalar::from_bytes(bytes).into() {
-            Some(sk) => sk,
-            None => return Err(Error::InvalidData),
-        };
-        Ok(Self(secret_key))
+    fn from_bytes(bytes: &[u8; 32]) -> Result<Self, KeyError> {
+        decode_scalar(bytes).map(Self)
     }
 }
@@ -4,6 +4,7 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
+pub mod authority;
 pub mod public;
 pub mod secret;
 pub mod stealth;
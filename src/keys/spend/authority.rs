@@ -0,0 +1,178 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use crate::{
+    fixed_base, IntegratedStealthAddress, JubJubExtended, JubJubScalar,
+    KeyDerivation, Ownable, PublicSpendKey, SecretKey, SecretSpendKey,
+    StealthAddress, VrfOutput, VrfProof, PAYMENT_ID_SIZE,
+};
+
+use core::convert::Infallible;
+
+use dusk_jubjub::BlsScalar;
+
+/// Holder of the secrets `(a, b)` of a [`PublicSpendKey`]
+///
+/// The trait models every use of a [`SecretSpendKey`] through the shared
+/// secret `a · R`, the one-time secret key `H(a · R) + b` of an owned output
+/// and the responses of a [`ReserveProof`], so that the secrets can be kept
+/// by a remote signer or an enclave. The signer derives `H(a · R)` itself,
+/// and releases a one-time secret key only for an address it owns, so `b`
+/// is never disclosed. Every operation may fail with the error of the
+/// implementation, such as a failure of the transport to the signer.
+///
+/// [`SecretSpendKey`] is the in-memory implementation, which never fails.
+///
+/// [`ReserveProof`]: crate::ReserveProof
+pub trait SpendAuthority {
+    /// Error of the operations
+    type Error;
+
+    /// Gets the public spend key `(a · G, b · G)` of the secrets
+    fn public_spend_key(&self) -> PublicSpendKey;
+
+    /// Computes the shared secret `a · R` of an output
+    ///
+    /// This is a Diffie-Hellman oracle on `a`: whoever can call it learns
+    /// `a · R` for any point of their choosing, and so the shared secret of
+    /// every output. It grants the same view of the chain as the
+    /// [`ViewKey`], which computes `a · R` as well, but no spend authority,
+    /// since `b` is not involved. Signers meant to keep the outputs of their
+    /// key private from the caller must not implement it.
+    ///
+    /// [`ViewKey`]: crate::ViewKey
+    fn shared_secret(
+        &self,
+        R: &JubJubExtended,
+    ) -> Result<JubJubExtended, Self::Error>;
+
+    /// Computes the one-time secret key `sk_r = H(a · R) + b` of a
+    /// [`StealthAddress`], hashing the shared secret with the given
+    /// [`KeyDerivation`]
+    ///
+    /// Returns `None` if the address is not owned, that is if
+    /// `sk_r · G` is not its one-time public key.
+    fn sk_r_with(
+        &self,
+        derivation: &KeyDerivation,
+        sa: &StealthAddress,
+    ) -> Result<Option<SecretKey>, Self::Error>;
+
+    /// Computes the responses `(c, s_a, s_b)` of a [`ReserveProof`] for the
+    /// statement committed by `transcript`, where `R` is the combination of
+    /// the `R` of the addresses
+    ///
+    /// The nonces are derived from the secrets, the statement and the given
+    /// `entropy`, so the caller learns nothing of the secrets from the
+    /// responses. The statement is not checked, which lets the caller prove
+    /// the knowledge of `(a, b)` for any transcript.
+    ///
+    /// [`ReserveProof`]: crate::ReserveProof
+    fn reserve_responses(
+        &self,
+        transcript: &BlsScalar,
+        R: &JubJubExtended,
+        entropy: &JubJubScalar,
+    ) -> Result<(JubJubScalar, JubJubScalar, JubJubScalar), Self::Error>;
+
+    /// Computes the one-time secret key `sk_r = H(a · R) + b` of a
+    /// [`StealthAddress`], if it is owned
+    fn sk_r(
+        &self,
+        sa: &StealthAddress,
+    ) -> Result<Option<SecretKey>, Self::Error> {
+        self.sk_r_with(&KeyDerivation::LEGACY, sa)
+    }
+
+    /// Signs the input with the one-time secret key of a [`StealthAddress`],
+    /// if it is owned, evaluating the verifiable random function of
+    /// [`SecretKey::vrf_prove`]
+    fn vrf_prove(
+        &self,
+        sa: &StealthAddress,
+        input: &[BlsScalar],
+    ) -> Result<Option<(VrfOutput, VrfProof)>, Self::Error> {
+        self.vrf_prove_with(&KeyDerivation::LEGACY, sa, input)
+    }
+
+    /// Signs the input with the one-time secret key of a [`StealthAddress`],
+    /// if it is owned, hashing the shared secret with the given
+    /// [`KeyDerivation`]
+    ///
+    /// The default implementation computes the one-time secret key with
+    /// [`sk_r_with`], and should be overridden by the implementations that
+    /// do not release it.
+    ///
+    /// [`sk_r_with`]: SpendAuthority::sk_r_with
+    fn vrf_prove_with(
+        &self,
+        derivation: &KeyDerivation,
+        sa: &StealthAddress,
+        input: &[BlsScalar],
+    ) -> Result<Option<(VrfOutput, VrfProof)>, Self::Error> {
+        let sk_r = self.sk_r_with(derivation, sa)?;
+        Ok(sk_r.map(|sk_r| sk_r.vrf_prove(input)))
+    }
+
+    /// Decrypts the payment ID of an output, if it is owned
+    fn decrypt_payment_id(
+        &self,
+        output: &IntegratedStealthAddress,
+    ) -> Result<Option<[u8; PAYMENT_ID_SIZE]>, Self::Error> {
+        self.decrypt_payment_id_with(&KeyDerivation::LEGACY, output)
+    }
+
+    /// Decrypts the payment ID of an output, if it is owned, hashing the
+    /// shared secret with the given [`KeyDerivation`]
+    fn decrypt_payment_id_with(
+        &self,
+        derivation: &KeyDerivation,
+        output: &IntegratedStealthAddress,
+    ) -> Result<Option<[u8; PAYMENT_ID_SIZE]>, Self::Error> {
+        let sa = output.stealth_address();
+
+        let aR = self.shared_secret(sa.R())?;
+        let pk_r = fixed_base::mul_generator(&derivation.stealth_key(&aR));
+        let pk_r = pk_r + self.public_spend_key().B();
+
+        Ok((sa.address() == &pk_r).then(|| output.decrypt_id(derivation, &aR)))
+    }
+}
+
+impl SpendAuthority for SecretSpendKey {
+    type Error = Infallible;
+
+    fn public_spend_key(&self) -> PublicSpendKey {
+        SecretSpendKey::public_spend_key(self)
+    }
+
+    fn shared_secret(
+        &self,
+        R: &JubJubExtended,
+    ) -> Result<JubJubExtended, Self::Error> {
+        Ok(R * self.a())
+    }
+
+    fn sk_r_with(
+        &self,
+        derivation: &KeyDerivation,
+        sa: &StealthAddress,
+    ) -> Result<Option<SecretKey>, Self::Error> {
+        let sk_r = SecretSpendKey::sk_r_with(self, derivation, sa);
+        let pk_r = fixed_base::mul_generator(sk_r.as_ref());
+
+        Ok((sa.address() == &pk_r).then_some(sk_r))
+    }
+
+    fn reserve_responses(
+        &self,
+        transcript: &BlsScalar,
+        R: &JubJubExtended,
+        entropy: &JubJubScalar,
+    ) -> Result<(JubJubScalar, JubJubScalar, JubJubScalar), Self::Error> {
+        Ok(crate::reserve::respond(self, transcript, R, entropy))
+    }
+}
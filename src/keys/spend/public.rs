@@ -5,31 +5,54 @@
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
 use crate::{
-    permutation, JubJubAffine, JubJubExtended, JubJubScalar, PublicKey,
-    StealthAddress,
+    fixed_base, JubJubAffine, JubJubExtended, JubJubScalar, KeyDerivation,
+    PublicKey, StealthAddress,
 };
 
 use super::secret::SecretSpendKey;
 
+#[cfg(feature = "rkyv-impl")]
+use crate::archive;
+#[cfg(feature = "rkyv-impl")]
+use bytecheck::{CheckBytes, StructCheckError};
 #[cfg(feature = "rkyv-impl")]
 use rkyv::{Archive, Deserialize, Serialize};
 
-use dusk_bytes::{DeserializableSlice, Error, HexDebug, Serializable};
-use dusk_jubjub::GENERATOR_EXTENDED;
+use crate::keys::{check_small_order, decode_point};
+use crate::KeyError;
+use dusk_bytes::{HexDebug, Serializable};
 use subtle::{Choice, ConstantTimeEq};
 
 /// Public pair of `a·G` and `b·G` defining a [`PublicSpendKey`]
 #[derive(HexDebug, Clone, Copy)]
-#[cfg_attr(
-    feature = "rkyv-impl",
-    derive(Archive, Serialize, Deserialize),
-    archive_attr(derive(bytecheck::CheckBytes))
-)]
+#[cfg_attr(feature = "rkyv-impl", derive(Archive, Serialize, Deserialize))]
 pub struct PublicSpendKey {
     A: JubJubExtended,
     B: JubJubExtended,
 }
 
+#[cfg(feature = "rkyv-impl")]
+impl<C: ?Sized> CheckBytes<C> for ArchivedPublicSpendKey {
+    type Error = StructCheckError;
+
+    unsafe fn check_bytes<'a>(
+        value: *const Self,
+        context: &mut C,
+    ) -> Result<&'a Self, Self::Error> {
+        archive::check_point(core::ptr::addr_of!((*value).A), context)
+            .map_err(|inner| StructCheckError {
+                field_name: "A",
+                inner,
+            })?;
+        archive::check_point(core::ptr::addr_of!((*value).B), context)
+            .map_err(|inner| StructCheckError {
+                field_name: "B",
+                inner,
+            })?;
+        Ok(&*value)
+    }
+}
+
 impl PublicSpendKey {
     /// This method is used to construct a new `PublicSpendKey` from the given
     /// public pair of `a·G` and `b·G`
@@ -47,14 +70,29 @@ impl PublicSpendKey {
         &self.B
     }
 
+    /// Rejects a key with `A` or `B` of small order
+    pub fn check_small_order(&self) -> Result<(), KeyError> {
+        check_small_order(&self.A)?;
+        check_small_order(&self.B)
+    }
+
     /// Generates new `PKr = H(A · r) · G + B` from a given `r`
     pub fn gen_stealth_address(&self, r: &JubJubScalar) -> StealthAddress {
-        let G = GENERATOR_EXTENDED;
-        let R = G * r;
+        self.gen_stealth_address_with(&KeyDerivation::LEGACY, r)
+    }
+
+    /// Generates new `PKr = H(A · r) · G + B` from a given `r`, hashing the
+    /// shared secret with the given [`KeyDerivation`]
+    pub fn gen_stealth_address_with(
+        &self,
+        derivation: &KeyDerivation,
+        r: &JubJubScalar,
+    ) -> StealthAddress {
+        let R = fixed_base::mul_generator(r);
 
         let rA = self.A * r;
-        let rA = permutation::hash(&rA);
-        let rA = G * rA;
+        let rA = derivation.stealth_key(&rA);
+        let rA = fixed_base::mul_generator(&rA);
 
         let pk_r = rA + self.B;
         let pk_r = PublicKey(pk_r);
@@ -90,7 +128,7 @@ impl From<&SecretSpendKey> for PublicSpendKey {
 }
 
 impl Serializable<64> for PublicSpendKey {
-    type Error = Error;
+    type Error = KeyError;
 
     fn to_bytes(&self) -> [u8; Self::SIZE] {
         let mut bytes = [0u8; Self::SIZE];
@@ -100,8 +138,8 @@ impl Serializable<64> for PublicSpendKey {
     }
 
     fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
-        let A = JubJubExtended::from(JubJubAffine::from_slice(&bytes[..32])?);
-        let B = JubJubExtended::from(JubJubAffine::from_slice(&bytes[32..])?);
+        let A = decode_point(&bytes[..32])?;
+        let B = decode_point(&bytes[32..])?;
 
         Ok(Self { A, B })
     }
@@ -4,31 +4,54 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
-use crate::{permutation, JubJubScalar, SecretKey, ViewKey};
+use crate::{fixed_base, JubJubScalar, KeyDerivation, SecretKey, ViewKey};
 
 use super::public::PublicSpendKey;
 use super::stealth::StealthAddress;
 
+#[cfg(feature = "rkyv-impl")]
+use crate::archive;
+#[cfg(feature = "rkyv-impl")]
+use bytecheck::{CheckBytes, StructCheckError};
 #[cfg(feature = "rkyv-impl")]
 use rkyv::{Archive, Deserialize, Serialize};
 
-use dusk_bytes::{DeserializableSlice, Error, HexDebug, Serializable};
-use dusk_jubjub::GENERATOR_EXTENDED;
+use crate::keys::decode_scalar;
+use crate::KeyError;
+use dusk_bytes::{HexDebug, Serializable};
 use rand_core::{CryptoRng, RngCore};
 use subtle::{Choice, ConstantTimeEq};
 
 /// Secret pair of `a` and `b` defining a [`SecretSpendKey`]
 #[derive(Clone, Copy, Eq, HexDebug)]
-#[cfg_attr(
-    feature = "rkyv-impl",
-    derive(Archive, Serialize, Deserialize),
-    archive_attr(derive(bytecheck::CheckBytes))
-)]
+#[cfg_attr(feature = "rkyv-impl", derive(Archive, Serialize, Deserialize))]
 pub struct SecretSpendKey {
     a: JubJubScalar,
     b: JubJubScalar,
 }
 
+#[cfg(feature = "rkyv-impl")]
+impl<C: ?Sized> CheckBytes<C> for ArchivedSecretSpendKey {
+    type Error = StructCheckError;
+
+    unsafe fn check_bytes<'a>(
+        value: *const Self,
+        context: &mut C,
+    ) -> Result<&'a Self, Self::Error> {
+        archive::check_scalar(core::ptr::addr_of!((*value).a), context)
+            .map_err(|inner| StructCheckError {
+                field_name: "a",
+                inner,
+            })?;
+        archive::check_scalar(core::ptr::addr_of!((*value).b), context)
+            .map_err(|inner| StructCheckError {
+                field_name: "b",
+                inner,
+            })?;
+        Ok(&*value)
+    }
+}
+
 impl SecretSpendKey {
     /// This method is used to construct a new `SecretSpendKey` from the given
     /// secret pair of `a` and `b`.
@@ -58,23 +81,34 @@ impl SecretSpendKey {
     /// Generates a [`SecretKey`] using the [`StealthAddress`] given.
     /// With the formula: `sk_r = H(a · R) + b`
     pub fn sk_r(&self, sa: &StealthAddress) -> SecretKey {
+        self.sk_r_with(&KeyDerivation::LEGACY, sa)
+    }
+
+    /// Generates a [`SecretKey`] using the [`StealthAddress`] given, hashing
+    /// the shared secret with the given [`KeyDerivation`].
+    /// With the formula: `sk_r = H(a · R) + b`
+    pub fn sk_r_with(
+        &self,
+        derivation: &KeyDerivation,
+        sa: &StealthAddress,
+    ) -> SecretKey {
         let aR = sa.R() * self.a;
-        let aR = permutation::hash(&aR);
+        let aR = derivation.stealth_key(&aR);
 
         SecretKey(aR + self.b)
     }
 
     /// Derive the secret to deterministically construct a [`PublicSpendKey`]
     pub fn public_spend_key(&self) -> PublicSpendKey {
-        let A = GENERATOR_EXTENDED * self.a;
-        let B = GENERATOR_EXTENDED * self.b;
+        let A = fixed_base::mul_generator(&self.a);
+        let B = fixed_base::mul_generator(&self.b);
 
         PublicSpendKey::new(A, B)
     }
 
     /// Derive the secret to deterministically construct a [`ViewKey`]
     pub fn view_key(&self) -> ViewKey {
-        let B = GENERATOR_EXTENDED * self.b;
+        let B = fixed_base::mul_generator(&self.b);
 
         ViewKey::new(self.a, B)
     }
@@ -93,7 +127,7 @@ impl PartialEq for SecretSpendKey {
 }
 
 impl Serializable<64> for SecretSpendKey {
-    type Error = Error;
+    type Error = KeyError;
 
     fn to_bytes(&self) -> [u8; 64] {
         let mut bytes = [0u8; 64];
@@ -103,8 +137,8 @@ impl Serializable<64> for SecretSpendKey {
     }
 
     fn from_bytes(buf: &[u8; 64]) -> Result<Self, Self::Error> {
-        let a = JubJubScalar::from_slice(&buf[..32])?;
-        let b = JubJubScalar::from_slice(&buf[32..])?;
+        let a = decode_scalar(&buf[..32])?;
+        let b = decode_scalar(&buf[32..])?;
 
         Ok(Self { a, b })
     }
@@ -6,10 +6,16 @@
 
 use crate::{JubJubAffine, JubJubExtended, PublicKey};
 
-use dusk_bytes::{DeserializableSlice, Error, HexDebug, Serializable};
+use crate::keys::{check_small_order, decode_point};
+use crate::KeyError;
+use dusk_bytes::{HexDebug, Serializable};
 
 use subtle::{Choice, ConstantTimeEq};
 
+#[cfg(feature = "rkyv-impl")]
+use crate::{archive, keys::public::ArchivedPublicKey};
+#[cfg(feature = "rkyv-impl")]
+use bytecheck::{CheckBytes, ErrorBox, StructCheckError};
 #[cfg(feature = "rkyv-impl")]
 use rkyv::{Archive, Deserialize, Serialize};
 
@@ -18,16 +24,38 @@ use rkyv::{Archive, Deserialize, Serialize};
 /// A `StealthAddress` is composed by a one-time public key (`pk_r`, the actual
 // address) and a random point `R`.
 #[derive(Default, HexDebug, Clone, Copy)]
-#[cfg_attr(
-    feature = "rkyv-impl",
-    derive(Archive, Serialize, Deserialize),
-    archive_attr(derive(bytecheck::CheckBytes))
-)]
+#[cfg_attr(feature = "rkyv-impl", derive(Archive, Serialize, Deserialize))]
 pub struct StealthAddress {
     pub(crate) R: JubJubExtended,
     pub(crate) pk_r: PublicKey,
 }
 
+#[cfg(feature = "rkyv-impl")]
+impl<C: ?Sized> CheckBytes<C> for ArchivedStealthAddress {
+    type Error = StructCheckError;
+
+    unsafe fn check_bytes<'a>(
+        value: *const Self,
+        context: &mut C,
+    ) -> Result<&'a Self, Self::Error> {
+        archive::check_point(core::ptr::addr_of!((*value).R), context)
+            .map_err(|inner| StructCheckError {
+                field_name: "R",
+                inner,
+            })?;
+        ArchivedPublicKey::check_bytes(
+            core::ptr::addr_of!((*value).pk_r),
+            context,
+        )
+        .map_err(|e| StructCheckError {
+            field_name: "pk_r",
+            inner: ErrorBox::new(e),
+        })?;
+
+        Ok(&*value)
+    }
+}
+
 /// The trait `Ownable` is required by any type that wants to prove its
 /// ownership.
 pub trait Ownable {
@@ -66,6 +94,12 @@ impl StealthAddress {
     pub fn address(&self) -> &JubJubExtended {
         self.pk_r.as_ref()
     }
+
+    /// Rejects an address with `R` or `PKr` of small order
+    pub fn check_small_order(&self) -> Result<(), KeyError> {
+        check_small_order(&self.R)?;
+        self.pk_r.check_small_order()
+    }
 }
 
 impl ConstantTimeEq for StealthAddress {
@@ -87,7 +121,7 @@ impl Ownable for StealthAddress {
 }
 
 impl Serializable<64> for StealthAddress {
-    type Error = Error;
+    type Error = KeyError;
     /// Encode the `StealthAddress` to an array of 64 bytes
     fn to_bytes(&self) -> [u8; Self::SIZE] {
         let mut bytes = [0u8; Self::SIZE];
@@ -99,11 +133,9 @@ impl Serializable<64> for StealthAddress {
     }
 
     /// Decode the `StealthAddress` from an array of 64 bytes
-    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Error> {
-        let R = JubJubExtended::from(JubJubAffine::from_slice(&bytes[..32])?);
-        let pk_r =
-            JubJubExtended::from(JubJubAffine::from_slice(&bytes[32..])?);
-        let pk_r = PublicKey(pk_r);
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, KeyError> {
+        let R = decode_point(&bytes[..32])?;
+        let pk_r = PublicKey(decode_point(&bytes[32..])?);
 
         Ok(StealthAddress { R, pk_r })
     }
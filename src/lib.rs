@@ -18,21 +18,88 @@
 #![deny(missing_docs)]
 #![allow(non_snake_case)]
 
+/// Archive Validation
+#[cfg(feature = "rkyv-impl")]
+pub use archive::{ArchivedCompact, Compact, InvalidArchive};
+/// Key Derivation
+pub use derivation::{DerivationDomain, KeyDerivation, ProtocolVersion};
+/// Key Error
+pub use error::KeyError;
+/// Integrated Address
+pub use integrated::{
+    IntegratedAddress, IntegratedStealthAddress, PAYMENT_ID_SIZE,
+};
+/// Keyring
+#[cfg(feature = "alloc")]
+pub use keyring::{Account, Keyring, SEED_SIZE};
 /// Public Key
 pub use keys::public::PublicKey;
 /// Secret Key
 pub use keys::secret::SecretKey;
+/// Spend Authority
+pub use keys::spend::authority::SpendAuthority;
 /// Public Spend Key
 pub use keys::spend::public::PublicSpendKey;
 /// Secret Spend Key
 pub use keys::spend::secret::SecretSpendKey;
 /// Stealth Address
 pub use keys::spend::stealth::{Ownable, StealthAddress};
+/// Nullifier
+pub use nullifier::Nullifier;
+/// Payment Request
+#[cfg(feature = "alloc")]
+pub use payment::{PaymentRequest, PaymentRequestError};
+/// Prepared View Key
+pub use prepared::PreparedViewKey;
+/// Reserve Proof
+#[cfg(feature = "alloc")]
+pub use reserve::ReserveProof;
+/// Scanner
+pub use scanner::{
+    BlockHash, OwnedOutput, ScanError, Scanner, MAX_REORG_DEPTH,
+};
+/// Tagged Encoding
+pub use tagged::{KeyType, Network, TaggedHeader, TAGGED_OVERHEAD};
 /// ViewKey
 pub use view::ViewKey;
+/// Verifiable Random Function
+pub use vrf::{VrfOutput, VrfProof};
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "rkyv-impl")]
+mod archive;
+mod derivation;
+mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod fixed_base;
+#[cfg(feature = "plonk")]
+pub mod gadget;
+pub mod hash_to_curve;
+mod integrated;
+#[cfg(feature = "alloc")]
+mod keyring;
 mod keys;
+mod nullifier;
+#[cfg(feature = "alloc")]
+mod payment;
 mod permutation;
+mod pkcs8;
+mod prepared;
+mod reserve;
+mod scanner;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+mod tagged;
 mod view;
+mod vrf;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 use dusk_jubjub::{JubJubAffine, JubJubExtended, JubJubScalar};
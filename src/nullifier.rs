@@ -0,0 +1,82 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use crate::{JubJubExtended, SecretKey};
+
+use dusk_bytes::{Error, HexDebug, Serializable};
+use dusk_jubjub::{BlsScalar, GENERATOR_NUMS_EXTENDED};
+use dusk_poseidon::sponge;
+
+#[cfg(feature = "rkyv-impl")]
+use rkyv::{Archive, Deserialize, Serialize};
+
+/// Domain separator of the nullifier hash, `"nullify"` in ASCII
+pub(crate) const DOMAIN: BlsScalar =
+    BlsScalar::from_raw([0x7966696c6c756e, 0, 0, 0]);
+
+/// Deterministic tag marking a stealth output as spent
+///
+/// The nullifier is the hash of the key image `sk_r·G'`, where `G'` is the
+/// nothing-up-my-sleeve generator `GENERATOR_NUMS`, optionally together with
+/// the position of the output. The key image is unique for every one-time
+/// key, while linking it to `pk_r = sk_r·G` requires solving the decisional
+/// Diffie-Hellman problem, so publishing the nullifier does not reveal which
+/// output was spent.
+///
+/// The same definition is available in circuit through `gadget::nullifier`
+/// and `gadget::nullifier_with_position`, behind the `plonk` feature.
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, HexDebug)]
+#[cfg_attr(
+    feature = "rkyv-impl",
+    derive(Archive, Serialize, Deserialize),
+    archive_attr(derive(bytecheck::CheckBytes))
+)]
+pub struct Nullifier(BlsScalar);
+
+impl Nullifier {
+    /// Computes the nullifier of the one-time key `sk_r`
+    pub fn new(sk_r: &SecretKey) -> Self {
+        let [u, v] = Self::key_image(sk_r).to_hash_inputs();
+
+        Self(sponge::hash(&[DOMAIN, u, v]))
+    }
+
+    /// Computes the nullifier of the one-time key `sk_r` bound to the
+    /// position of the output
+    pub fn with_position(sk_r: &SecretKey, pos: u64) -> Self {
+        let [u, v] = Self::key_image(sk_r).to_hash_inputs();
+
+        Self(sponge::hash(&[DOMAIN, u, v, BlsScalar::from(pos)]))
+    }
+
+    /// Computes the key image `sk_r·G'` of a one-time key
+    pub fn key_image(sk_r: &SecretKey) -> JubJubExtended {
+        GENERATOR_NUMS_EXTENDED * sk_r.as_ref()
+    }
+
+    /// Gets the nullifier as a field element
+    pub const fn as_scalar(&self) -> &BlsScalar {
+        &self.0
+    }
+}
+
+impl From<Nullifier> for BlsScalar {
+    fn from(nullifier: Nullifier) -> Self {
+        nullifier.0
+    }
+}
+
+impl Serializable<32> for Nullifier {
+    type Error = Error;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        <BlsScalar as Serializable<32>>::from_bytes(bytes).map(Self)
+    }
+}
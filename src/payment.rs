@@ -0,0 +1,359 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use crate::{IntegratedAddress, KeyError, PublicSpendKey, PAYMENT_ID_SIZE};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+use dusk_bytes::{ParseHexStr, Serializable};
+
+/// Scheme of the payment request URIs
+const SCHEME: &str = "dusk:";
+
+/// Error returned when building or parsing a [`PaymentRequest`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PaymentRequestError {
+    /// The URI does not start with the `dusk:` scheme
+    InvalidScheme,
+    /// The address is not a valid [`PublicSpendKey`]
+    InvalidAddress(KeyError),
+    /// The amount is zero or not a decimal integer
+    InvalidAmount,
+    /// The payment ID is not 16 hex characters
+    InvalidPaymentId,
+    /// The expiry is not a decimal timestamp
+    InvalidExpiry,
+    /// The memo is longer than [`PaymentRequest::MAX_MEMO_SIZE`] bytes
+    MemoTooLong,
+    /// A parameter is malformed, repeated, or is an unknown required
+    /// parameter
+    InvalidParameter,
+}
+
+impl fmt::Display for PaymentRequestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidScheme => write!(f, "invalid scheme"),
+            Self::InvalidAddress(err) => write!(f, "invalid address: {err}"),
+            Self::InvalidAmount => write!(f, "invalid amount"),
+            Self::InvalidPaymentId => write!(f, "invalid payment id"),
+            Self::InvalidExpiry => write!(f, "invalid expiry"),
+            Self::MemoTooLong => write!(f, "memo too long"),
+            Self::InvalidParameter => write!(f, "invalid parameter"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PaymentRequestError {}
+
+/// Request of a payment to a [`PublicSpendKey`], encoded as a `dusk:` URI
+///
+/// The URI holds the hex encoding of the key, followed by the optional
+/// query parameters:
+///
+/// - `amount`: requested amount, as a decimal integer of the smallest unit
+/// - `memo`: percent-encoded UTF-8 description of the payment
+/// - `id`: payment ID, as 16 hex characters
+/// - `expires`: UNIX timestamp, in seconds, after which the request is void
+///
+/// ```text
+/// dusk:<128 hex characters>?amount=1000&memo=Coffee%20beans&id=0011223344556677
+/// ```
+///
+/// As in BIP-21, unknown parameters are ignored, unless they are prefixed
+/// with `req-` to mark them as required.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaymentRequest {
+    psk: PublicSpendKey,
+    amount: Option<u64>,
+    memo: Option<String>,
+    payment_id: Option<[u8; PAYMENT_ID_SIZE]>,
+    expiry: Option<u64>,
+}
+
+impl PaymentRequest {
+    /// Largest memo, in bytes
+    pub const MAX_MEMO_SIZE: usize = 256;
+
+    /// Creates a request of a payment to the given key
+    pub fn new(psk: PublicSpendKey) -> Self {
+        Self {
+            psk,
+            amount: None,
+            memo: None,
+            payment_id: None,
+            expiry: None,
+        }
+    }
+
+    /// Sets the requested amount, which must not be zero
+    pub fn with_amount(
+        mut self,
+        amount: u64,
+    ) -> Result<Self, PaymentRequestError> {
+        if amount == 0 {
+            return Err(PaymentRequestError::InvalidAmount);
+        }
+
+        self.amount = Some(amount);
+        Ok(self)
+    }
+
+    /// Sets the memo, of at most [`Self::MAX_MEMO_SIZE`] bytes
+    pub fn with_memo(
+        mut self,
+        memo: impl Into<String>,
+    ) -> Result<Self, PaymentRequestError> {
+        let memo = memo.into();
+        if memo.len() > Self::MAX_MEMO_SIZE {
+            return Err(PaymentRequestError::MemoTooLong);
+        }
+
+        self.memo = Some(memo);
+        Ok(self)
+    }
+
+    /// Sets the payment ID
+    pub fn with_payment_id(
+        mut self,
+        payment_id: [u8; PAYMENT_ID_SIZE],
+    ) -> Self {
+        self.payment_id = Some(payment_id);
+        self
+    }
+
+    /// Sets the UNIX timestamp, in seconds, after which the request is void
+    pub fn with_expiry(mut self, expiry: u64) -> Self {
+        self.expiry = Some(expiry);
+        self
+    }
+
+    /// Gets the key receiving the payment
+    pub const fn psk(&self) -> &PublicSpendKey {
+        &self.psk
+    }
+
+    /// Gets the requested amount
+    pub const fn amount(&self) -> Option<u64> {
+        self.amount
+    }
+
+    /// Gets the memo
+    pub fn memo(&self) -> Option<&str> {
+        self.memo.as_deref()
+    }
+
+    /// Gets the payment ID
+    pub const fn payment_id(&self) -> Option<&[u8; PAYMENT_ID_SIZE]> {
+        self.payment_id.as_ref()
+    }
+
+    /// Gets the expiry timestamp
+    pub const fn expiry(&self) -> Option<u64> {
+        self.expiry
+    }
+
+    /// Gets the [`IntegratedAddress`] of the key and payment ID, if the
+    /// request has a payment ID
+    pub fn integrated_address(&self) -> Option<IntegratedAddress> {
+        self.payment_id
+            .map(|payment_id| IntegratedAddress::new(self.psk, payment_id))
+    }
+
+    /// Checks if the request is expired at the given UNIX timestamp
+    pub fn is_expired(&self, now: u64) -> bool {
+        matches!(self.expiry, Some(expiry) if now > expiry)
+    }
+
+    /// Encodes the request as a `dusk:` URI
+    pub fn to_uri(&self) -> String {
+        use alloc::string::ToString;
+        self.to_string()
+    }
+
+    /// Parses a request from a `dusk:` URI
+    pub fn from_uri(uri: &str) -> Result<Self, PaymentRequestError> {
+        let rest = match uri.get(..SCHEME.len()) {
+            Some(scheme) if scheme.eq_ignore_ascii_case(SCHEME) => {
+                &uri[SCHEME.len()..]
+            }
+            _ => return Err(PaymentRequestError::InvalidScheme),
+        };
+
+        let (address, query) = match rest.split_once('?') {
+            Some((address, query)) => (address, Some(query)),
+            None => (rest, None),
+        };
+
+        if address.len() != 2 * PublicSpendKey::SIZE {
+            return Err(PaymentRequestError::InvalidAddress(
+                KeyError::WrongLength {
+                    found: address.len(),
+                    expected: 2 * PublicSpendKey::SIZE,
+                },
+            ));
+        }
+        let psk = PublicSpendKey::from_hex_str(address)
+            .map_err(PaymentRequestError::InvalidAddress)?;
+
+        let mut request = Self::new(psk);
+        let mut memo = None;
+
+        for param in query.into_iter().flat_map(|q| q.split('&')) {
+            let (key, value) = param
+                .split_once('=')
+                .ok_or(PaymentRequestError::InvalidParameter)?;
+
+            match key {
+                "amount" => set(&mut request.amount, parse_amount(value)?)?,
+                "memo" => set(&mut memo, percent_decode(value)?)?,
+                "id" => set(&mut request.payment_id, parse_payment_id(value)?)?,
+                "expires" => set(
+                    &mut request.expiry,
+                    parse_decimal(value)
+                        .ok_or(PaymentRequestError::InvalidExpiry)?,
+                )?,
+                _ if key.starts_with("req-") => {
+                    return Err(PaymentRequestError::InvalidParameter)
+                }
+                _ => (),
+            }
+        }
+
+        match memo {
+            Some(memo) => request.with_memo(memo),
+            None => Ok(request),
+        }
+    }
+}
+
+impl fmt::Display for PaymentRequest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{SCHEME}{:x}", self.psk)?;
+
+        let mut separator = '?';
+        let mut param = |f: &mut fmt::Formatter, key: &str| {
+            let result = write!(f, "{separator}{key}=");
+            separator = '&';
+            result
+        };
+
+        if let Some(amount) = self.amount {
+            param(f, "amount")?;
+            write!(f, "{amount}")?;
+        }
+        if let Some(memo) = &self.memo {
+            param(f, "memo")?;
+            percent_encode(f, memo)?;
+        }
+        if let Some(payment_id) = &self.payment_id {
+            param(f, "id")?;
+            payment_id.iter().try_for_each(|b| write!(f, "{b:02x}"))?;
+        }
+        if let Some(expiry) = self.expiry {
+            param(f, "expires")?;
+            write!(f, "{expiry}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for PaymentRequest {
+    type Err = PaymentRequestError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_uri(s)
+    }
+}
+
+/// Sets a parameter, rejecting the ones appearing more than once
+fn set<T>(param: &mut Option<T>, value: T) -> Result<(), PaymentRequestError> {
+    match param.replace(value) {
+        Some(_) => Err(PaymentRequestError::InvalidParameter),
+        None => Ok(()),
+    }
+}
+
+/// Parses a decimal integer, without sign nor leading zeros
+fn parse_decimal(value: &str) -> Option<u64> {
+    let digits = value.bytes().all(|b| b.is_ascii_digit());
+    let leading_zero = value.len() > 1 && value.starts_with('0');
+
+    match digits && !leading_zero {
+        true => value.parse().ok(),
+        false => None,
+    }
+}
+
+/// Parses a byte from two hex digits, without sign
+fn parse_hex_byte(hex: &[u8]) -> Option<u8> {
+    match hex.len() == 2 && hex.iter().all(u8::is_ascii_hexdigit) {
+        true => u8::from_str_radix(core::str::from_utf8(hex).ok()?, 16).ok(),
+        false => None,
+    }
+}
+
+fn parse_amount(value: &str) -> Result<u64, PaymentRequestError> {
+    match parse_decimal(value) {
+        Some(amount) if amount > 0 => Ok(amount),
+        _ => Err(PaymentRequestError::InvalidAmount),
+    }
+}
+
+fn parse_payment_id(
+    value: &str,
+) -> Result<[u8; PAYMENT_ID_SIZE], PaymentRequestError> {
+    if value.len() != 2 * PAYMENT_ID_SIZE {
+        return Err(PaymentRequestError::InvalidPaymentId);
+    }
+
+    let mut id = [0u8; PAYMENT_ID_SIZE];
+    for (b, hex) in id.iter_mut().zip(value.as_bytes().chunks(2)) {
+        *b =
+            parse_hex_byte(hex).ok_or(PaymentRequestError::InvalidPaymentId)?;
+    }
+
+    Ok(id)
+}
+
+/// Writes the string percent-encoding everything but the unreserved
+/// characters of RFC 3986
+fn percent_encode(f: &mut fmt::Formatter, s: &str) -> fmt::Result {
+    s.bytes().try_for_each(|b| match b {
+        b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+            write!(f, "{}", b as char)
+        }
+        _ => write!(f, "%{b:02X}"),
+    })
+}
+
+fn percent_decode(s: &str) -> Result<String, PaymentRequestError> {
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut iter = s.bytes();
+
+    while let Some(b) = iter.next() {
+        match b {
+            b'%' => {
+                let hex = [
+                    iter.next().ok_or(PaymentRequestError::InvalidParameter)?,
+                    iter.next().ok_or(PaymentRequestError::InvalidParameter)?,
+                ];
+                bytes.push(
+                    parse_hex_byte(&hex)
+                        .ok_or(PaymentRequestError::InvalidParameter)?,
+                );
+            }
+            _ => bytes.push(b),
+        }
+    }
+
+    String::from_utf8(bytes).map_err(|_| PaymentRequestError::InvalidParameter)
+}
@@ -4,10 +4,15 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
-use crate::{JubJubExtended, JubJubScalar};
+use crate::{JubJubAffine, JubJubExtended, JubJubScalar};
 use dusk_poseidon::sponge::truncated;
 
 /// Hashes a JubJub's ExtendedPoint into a JubJub's Scalar
 pub fn hash(p: &JubJubExtended) -> JubJubScalar {
     truncated::hash(&p.to_hash_inputs())
 }
+
+/// Hashes a JubJub's AffinePoint into a JubJub's Scalar
+pub fn hash_affine(p: &JubJubAffine) -> JubJubScalar {
+    truncated::hash(&[p.get_u(), p.get_v()])
+}
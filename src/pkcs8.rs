@@ -0,0 +1,399 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! PKCS#8 and SubjectPublicKeyInfo encodings of the keys
+//!
+//! The secret keys are encoded as a PKCS#8 `PrivateKeyInfo` and the public
+//! keys as a `SubjectPublicKeyInfo`, following the layout of RFC 8410 for
+//! Ed25519: the algorithm identifier has no parameters, the secret key is
+//! wrapped in an inner `OCTET STRING` and the public key fills the
+//! `BIT STRING`. The keys themselves are their [`Serializable`] encoding.
+//!
+//! The algorithms are identified under the UUID arc `2.25` (ITU-T X.667),
+//! which requires no registration:
+//!
+//! - `2.25.87914366523037193872620638038412089636.1` for [`SecretKey`] and
+//!   [`PublicKey`]
+//! - `2.25.87914366523037193872620638038412089636.2` for [`SecretSpendKey`]
+//!   and [`PublicSpendKey`]
+//!
+//! The DER encodings can be armored as PEM, labelled `PRIVATE KEY` and
+//! `PUBLIC KEY` respectively.
+
+use crate::{KeyError, PublicKey, PublicSpendKey, SecretKey, SecretSpendKey};
+
+use dusk_bytes::Serializable;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// Content of the object identifier of [`SecretKey`] and [`PublicKey`]
+const KEY_OID: [u8; 21] = [
+    0x69, 0x81, 0x84, 0xa3, 0xd8, 0xbb, 0x83, 0xde, 0x8a, 0xae, 0xf7, 0xab,
+    0x93, 0xc8, 0x9e, 0xab, 0xaf, 0x97, 0xb2, 0x24, 0x01,
+];
+
+/// Content of the object identifier of [`SecretSpendKey`] and
+/// [`PublicSpendKey`]
+const SPEND_KEY_OID: [u8; 21] = [
+    0x69, 0x81, 0x84, 0xa3, 0xd8, 0xbb, 0x83, 0xde, 0x8a, 0xae, 0xf7, 0xab,
+    0x93, 0xc8, 0x9e, 0xab, 0xaf, 0x97, 0xb2, 0x24, 0x02,
+];
+
+/// Size of the DER `AlgorithmIdentifier`
+const ALGORITHM_SIZE: usize = 4 + KEY_OID.len();
+
+/// Size of the DER encoding preceding the key in a `PrivateKeyInfo`
+const PKCS8_PREFIX_SIZE: usize = 9 + ALGORITHM_SIZE;
+
+/// Size of the DER encoding preceding the key in a `SubjectPublicKeyInfo`
+const SPKI_PREFIX_SIZE: usize = 5 + ALGORITHM_SIZE;
+
+/// Largest DER encoding of a key
+const MAX_DER_SIZE: usize = PKCS8_PREFIX_SIZE + 64;
+
+const PRIVATE_KEY_LABEL: &str = "PRIVATE KEY";
+const PUBLIC_KEY_LABEL: &str = "PUBLIC KEY";
+
+/// Writes the DER `AlgorithmIdentifier` with the given object identifier
+const fn algorithm(
+    mut prefix: [u8; MAX_DER_SIZE],
+    mut i: usize,
+    oid: &[u8; 21],
+) -> [u8; MAX_DER_SIZE] {
+    // SEQUENCE { OBJECT IDENTIFIER }
+    prefix[i] = 0x30;
+    prefix[i + 1] = (ALGORITHM_SIZE - 2) as u8;
+    prefix[i + 2] = 0x06;
+    prefix[i + 3] = oid.len() as u8;
+    i += 4;
+
+    let mut j = 0;
+    while j < oid.len() {
+        prefix[i + j] = oid[j];
+        j += 1;
+    }
+
+    prefix
+}
+
+/// DER encoding preceding a key of `n` bytes in a `PrivateKeyInfo`
+const fn pkcs8_prefix(oid: &[u8; 21], n: usize) -> [u8; MAX_DER_SIZE] {
+    let mut prefix = [0u8; MAX_DER_SIZE];
+
+    // SEQUENCE { INTEGER 0, AlgorithmIdentifier, OCTET STRING }
+    prefix[0] = 0x30;
+    prefix[1] = (PKCS8_PREFIX_SIZE - 2 + n) as u8;
+    prefix[2] = 0x02;
+    prefix[3] = 0x01;
+    prefix[4] = 0x00;
+
+    let mut prefix = algorithm(prefix, 5, oid);
+
+    // OCTET STRING { OCTET STRING }
+    let i = 5 + ALGORITHM_SIZE;
+    prefix[i] = 0x04;
+    prefix[i + 1] = (n + 2) as u8;
+    prefix[i + 2] = 0x04;
+    prefix[i + 3] = n as u8;
+
+    prefix
+}
+
+/// DER encoding preceding a key of `n` bytes in a `SubjectPublicKeyInfo`
+const fn spki_prefix(oid: &[u8; 21], n: usize) -> [u8; MAX_DER_SIZE] {
+    let mut prefix = [0u8; MAX_DER_SIZE];
+
+    // SEQUENCE { AlgorithmIdentifier, BIT STRING }
+    prefix[0] = 0x30;
+    prefix[1] = (SPKI_PREFIX_SIZE - 2 + n) as u8;
+
+    let mut prefix = algorithm(prefix, 2, oid);
+
+    // BIT STRING with no unused bits
+    let i = 2 + ALGORITHM_SIZE;
+    prefix[i] = 0x03;
+    prefix[i + 1] = (n + 1) as u8;
+    prefix[i + 2] = 0x00;
+
+    prefix
+}
+
+/// Layout of the DER encoding of a key type
+struct Template {
+    prefix: [u8; MAX_DER_SIZE],
+    prefix_size: usize,
+    key_size: usize,
+}
+
+impl Template {
+    const fn pkcs8(oid: &[u8; 21], key_size: usize) -> Self {
+        Self {
+            prefix: pkcs8_prefix(oid, key_size),
+            prefix_size: PKCS8_PREFIX_SIZE,
+            key_size,
+        }
+    }
+
+    const fn spki(oid: &[u8; 21], key_size: usize) -> Self {
+        Self {
+            prefix: spki_prefix(oid, key_size),
+            prefix_size: SPKI_PREFIX_SIZE,
+            key_size,
+        }
+    }
+
+    fn prefix(&self) -> &[u8] {
+        &self.prefix[..self.prefix_size]
+    }
+
+    fn matches(&self, der: &[u8]) -> bool {
+        der.len() == self.prefix_size + self.key_size
+            && der.starts_with(self.prefix())
+    }
+
+    fn encode<const N: usize, const M: usize>(&self, key: &[u8; N]) -> [u8; M] {
+        let mut der = [0u8; M];
+        der[..self.prefix_size].copy_from_slice(self.prefix());
+        der[self.prefix_size..].copy_from_slice(key);
+        der
+    }
+
+    /// Extracts the key from its DER encoding, telling apart the encodings
+    /// of the other key types
+    fn decode<const N: usize>(
+        &self,
+        others: &[&Template],
+        der: &[u8],
+    ) -> Result<[u8; N], KeyError> {
+        if !self.matches(der) {
+            if others.iter().any(|t| t.matches(der)) {
+                return Err(KeyError::WrongKeyType);
+            }
+
+            let expected = self.prefix_size + self.key_size;
+            if der.len() != expected {
+                return Err(KeyError::WrongLength {
+                    found: der.len(),
+                    expected,
+                });
+            }
+
+            return Err(KeyError::InvalidData);
+        }
+
+        let mut key = [0u8; N];
+        key.copy_from_slice(&der[self.prefix_size..]);
+        Ok(key)
+    }
+}
+
+const SECRET_KEY: Template = Template::pkcs8(&KEY_OID, SecretKey::SIZE);
+const SECRET_SPEND_KEY: Template =
+    Template::pkcs8(&SPEND_KEY_OID, SecretSpendKey::SIZE);
+const PUBLIC_KEY: Template = Template::spki(&KEY_OID, PublicKey::SIZE);
+const PUBLIC_SPEND_KEY: Template =
+    Template::spki(&SPEND_KEY_OID, PublicSpendKey::SIZE);
+
+/// Maps a value in `0..64` to its base64 character, in constant time
+#[cfg(feature = "alloc")]
+fn encode_6bits(src: u8) -> u8 {
+    let src = src as i16;
+    let mut diff = 0x41i16;
+
+    diff += ((25 - src) >> 8) & 6;
+    diff -= ((51 - src) >> 8) & 75;
+    diff -= ((61 - src) >> 8) & 15;
+    diff += ((62 - src) >> 8) & 3;
+
+    (src + diff) as u8
+}
+
+/// Maps a base64 character to its value, or to `-1` if the character is
+/// invalid, in constant time
+fn decode_6bits(c: u8) -> i16 {
+    let c = c as i16;
+    let mut ret = -1i16;
+
+    ret += (((0x40 - c) & (c - 0x5b)) >> 8) & (c - 64);
+    ret += (((0x60 - c) & (c - 0x7b)) >> 8) & (c - 70);
+    ret += (((0x2f - c) & (c - 0x3a)) >> 8) & (c + 5);
+    ret += (((0x2a - c) & (c - 0x2c)) >> 8) & 63;
+    ret += (((0x2e - c) & (c - 0x30)) >> 8) & 64;
+
+    ret
+}
+
+/// Armors a DER encoding as PEM with the given label
+#[cfg(feature = "alloc")]
+fn pem_encode(label: &str, der: &[u8]) -> String {
+    let mut pem = String::new();
+
+    pem.push_str("-----BEGIN ");
+    pem.push_str(label);
+    pem.push_str("-----\n");
+
+    let mut line = 0;
+    for chunk in der.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let sextets = [
+            b[0] >> 2,
+            ((b[0] & 0x03) << 4) | (b[1] >> 4),
+            ((b[1] & 0x0f) << 2) | (b[2] >> 6),
+            b[2] & 0x3f,
+        ];
+
+        for (i, s) in sextets.iter().enumerate() {
+            match i <= chunk.len() {
+                true => pem.push(encode_6bits(*s) as char),
+                false => pem.push('='),
+            }
+        }
+
+        line += 4;
+        if line == 64 {
+            pem.push('\n');
+            line = 0;
+        }
+    }
+    if line != 0 {
+        pem.push('\n');
+    }
+
+    pem.push_str("-----END ");
+    pem.push_str(label);
+    pem.push_str("-----\n");
+
+    pem
+}
+
+/// Removes the PEM armor with the given label, decoding the DER encoding
+/// into the buffer
+fn pem_decode<'a>(
+    pem: &str,
+    label: &str,
+    buf: &'a mut [u8; MAX_DER_SIZE],
+) -> Result<&'a [u8], KeyError> {
+    let body = pem
+        .trim()
+        .strip_prefix("-----BEGIN ")
+        .and_then(|s| s.strip_prefix(label))
+        .and_then(|s| s.strip_prefix("-----"))
+        .and_then(|s| s.strip_suffix("-----"))
+        .and_then(|s| s.strip_suffix(label))
+        .and_then(|s| s.strip_suffix("-----END "))
+        .ok_or(KeyError::InvalidData)?;
+
+    let mut acc = 0u32;
+    let mut bits = 0;
+    let mut len = 0;
+    let mut chars = 0;
+    let mut padding = 0;
+    let mut invalid = 0i16;
+
+    for c in body.bytes() {
+        match c {
+            b'\n' | b'\r' => continue,
+            b'=' => padding += 1,
+            _ if padding > 0 => return Err(KeyError::InvalidData),
+            _ => {
+                let v = decode_6bits(c);
+                invalid |= v;
+
+                acc = (acc << 6) | (v as u32 & 0x3f);
+                bits += 6;
+
+                if bits >= 8 {
+                    bits -= 8;
+                    if len == buf.len() {
+                        return Err(KeyError::InvalidData);
+                    }
+                    buf[len] = (acc >> bits) as u8;
+                    len += 1;
+                }
+            }
+        }
+        chars += 1;
+    }
+
+    let canonical = acc & ((1 << bits) - 1) == 0;
+    if invalid < 0 || padding > 2 || chars % 4 != 0 || !canonical {
+        return Err(KeyError::InvalidData);
+    }
+
+    Ok(&buf[..len])
+}
+
+macro_rules! impl_pkcs8 {
+    ($ty:ident, $template:expr, $others:expr, $der:literal) => {
+        impl $ty {
+            /// Encodes the key as a DER PKCS#8 `PrivateKeyInfo`
+            pub fn to_pkcs8_der(&self) -> [u8; $der] {
+                $template.encode(&self.to_bytes())
+            }
+
+            /// Decodes the key from a DER PKCS#8 `PrivateKeyInfo`
+            pub fn from_pkcs8_der(der: &[u8]) -> Result<Self, KeyError> {
+                Self::from_bytes(&$template.decode(&$others, der)?)
+            }
+
+            /// Encodes the key as a PKCS#8 PEM, labelled `PRIVATE KEY`
+            #[cfg(feature = "alloc")]
+            pub fn to_pkcs8_pem(&self) -> String {
+                pem_encode(PRIVATE_KEY_LABEL, &self.to_pkcs8_der())
+            }
+
+            /// Decodes the key from a PKCS#8 PEM, labelled `PRIVATE KEY`
+            pub fn from_pkcs8_pem(pem: &str) -> Result<Self, KeyError> {
+                let mut buf = [0u8; MAX_DER_SIZE];
+                let der = pem_decode(pem, PRIVATE_KEY_LABEL, &mut buf)?;
+
+                Self::from_pkcs8_der(der)
+            }
+        }
+    };
+}
+
+macro_rules! impl_spki {
+    ($ty:ident, $template:expr, $others:expr, $der:literal) => {
+        impl $ty {
+            /// Encodes the key as a DER `SubjectPublicKeyInfo`
+            pub fn to_public_key_der(&self) -> [u8; $der] {
+                $template.encode(&self.to_bytes())
+            }
+
+            /// Decodes the key from a DER `SubjectPublicKeyInfo`
+            pub fn from_public_key_der(der: &[u8]) -> Result<Self, KeyError> {
+                Self::from_bytes(&$template.decode(&$others, der)?)
+            }
+
+            /// Encodes the key as a `SubjectPublicKeyInfo` PEM, labelled
+            /// `PUBLIC KEY`
+            #[cfg(feature = "alloc")]
+            pub fn to_public_key_pem(&self) -> String {
+                pem_encode(PUBLIC_KEY_LABEL, &self.to_public_key_der())
+            }
+
+            /// Decodes the key from a `SubjectPublicKeyInfo` PEM, labelled
+            /// `PUBLIC KEY`
+            pub fn from_public_key_pem(pem: &str) -> Result<Self, KeyError> {
+                let mut buf = [0u8; MAX_DER_SIZE];
+                let der = pem_decode(pem, PUBLIC_KEY_LABEL, &mut buf)?;
+
+                Self::from_public_key_der(der)
+            }
+        }
+    };
+}
+
+impl_pkcs8!(SecretKey, SECRET_KEY, [&SECRET_SPEND_KEY], 66);
+impl_pkcs8!(SecretSpendKey, SECRET_SPEND_KEY, [&SECRET_KEY], 98);
+impl_spki!(PublicKey, PUBLIC_KEY, [&PUBLIC_SPEND_KEY], 62);
+impl_spki!(PublicSpendKey, PUBLIC_SPEND_KEY, [&PUBLIC_KEY], 94);
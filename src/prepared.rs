@@ -0,0 +1,158 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use crate::keys::spend::stealth::Ownable;
+use crate::{
+    fixed_base, JubJubAffine, JubJubExtended, JubJubScalar, KeyDerivation,
+    ViewKey,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use dusk_jubjub::{AffineNielsPoint, ExtendedNielsPoint};
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+
+/// Number of bits of a digit of `a`
+const WINDOW_BITS: usize = 4;
+
+/// Number of multiples of `R` selected by a digit of `a`
+const DIGITS: usize = 1 << WINDOW_BITS;
+
+/// Number of digits of `a`, covering the 252 bits of a scalar
+const WINDOWS: usize = 63;
+
+/// [`ViewKey`] prepared for checking the ownership of many outputs
+///
+/// The secret `a` is split once in 4-bit digits, so every shared secret
+/// `a · R` costs a single addition per digit, and `B` is kept in the form
+/// of the cheapest addition. The answers are the same of [`ViewKey::owns`].
+///
+/// [`owns_batch`] additionally normalizes the shared secrets of many outputs
+/// with a single inversion before hashing them.
+///
+/// [`owns_batch`]: PreparedViewKey::owns_batch
+#[derive(Debug, Clone, Copy)]
+pub struct PreparedViewKey {
+    vk: ViewKey,
+    digits: [u8; WINDOWS],
+    B: AffineNielsPoint,
+}
+
+impl PreparedViewKey {
+    /// Prepares a view key
+    pub fn new(vk: &ViewKey) -> Self {
+        let bytes = vk.a().to_bytes();
+
+        let mut digits = [0u8; WINDOWS];
+        digits.iter_mut().enumerate().for_each(|(i, digit)| {
+            *digit = (bytes[i / 2] >> (WINDOW_BITS * (i % 2))) & 0x0f;
+        });
+
+        Self {
+            vk: *vk,
+            digits,
+            B: JubJubAffine::from(vk.B()).to_niels(),
+        }
+    }
+
+    /// Gets the prepared view key
+    pub const fn view_key(&self) -> &ViewKey {
+        &self.vk
+    }
+
+    /// Checks `PKr = H(R · a) · G + B`
+    pub fn owns(&self, owner: &impl Ownable) -> bool {
+        self.owns_with(&KeyDerivation::LEGACY, owner)
+    }
+
+    /// Checks `PKr = H(R · a) · G + B`, hashing the shared secret with the
+    /// given [`KeyDerivation`]
+    pub fn owns_with(
+        &self,
+        derivation: &KeyDerivation,
+        owner: &impl Ownable,
+    ) -> bool {
+        let sa = owner.stealth_address();
+
+        let aR = self.shared_secret(sa.R());
+        let aR = JubJubAffine::from(aR);
+
+        self.check(owner, &derivation.stealth_key_affine(&aR))
+    }
+
+    /// Checks `PKr = H(R · a) · G + B` for every output
+    #[cfg(feature = "alloc")]
+    pub fn owns_batch<T: Ownable>(&self, owners: &[T]) -> Vec<bool> {
+        self.owns_batch_with(&KeyDerivation::LEGACY, owners)
+    }
+
+    /// Checks `PKr = H(R · a) · G + B` for every output, hashing the shared
+    /// secrets with the given [`KeyDerivation`]
+    #[cfg(feature = "alloc")]
+    pub fn owns_batch_with<T: Ownable>(
+        &self,
+        derivation: &KeyDerivation,
+        owners: &[T],
+    ) -> Vec<bool> {
+        let mut shared: Vec<JubJubExtended> = owners
+            .iter()
+            .map(|owner| self.shared_secret(owner.stealth_address().R()))
+            .collect();
+
+        dusk_jubjub::batch_normalize(&mut shared)
+            .zip(owners)
+            .map(|(aR, owner)| {
+                let stealth_key = derivation.stealth_key_affine(&aR);
+                self.check(owner, &stealth_key)
+            })
+            .collect()
+    }
+
+    /// Computes the shared secret `a · R`
+    pub(crate) fn shared_secret(&self, R: &JubJubExtended) -> JubJubExtended {
+        let mut multiples = [ExtendedNielsPoint::identity(); DIGITS];
+        let mut p = *R;
+        multiples.iter_mut().skip(1).for_each(|multiple| {
+            *multiple = p.to_niels();
+            p += R;
+        });
+
+        let mut acc = JubJubExtended::identity();
+
+        self.digits.iter().rev().for_each(|digit| {
+            (0..WINDOW_BITS).for_each(|_| acc = acc.double());
+
+            let mut entry = ExtendedNielsPoint::identity();
+            multiples.iter().enumerate().for_each(|(d, multiple)| {
+                entry.conditional_assign(multiple, digit.ct_eq(&(d as u8)));
+            });
+
+            acc += entry;
+        });
+
+        acc
+    }
+
+    /// Checks `PKr = H(R · a) · G + B` given the scalar `H(R · a)`
+    fn check(&self, owner: &impl Ownable, stealth_key: &JubJubScalar) -> bool {
+        let pk_r = fixed_base::mul_generator(stealth_key) + self.B;
+
+        owner.stealth_address().address() == &pk_r
+    }
+}
+
+impl From<&ViewKey> for PreparedViewKey {
+    fn from(vk: &ViewKey) -> Self {
+        Self::new(vk)
+    }
+}
+
+impl From<ViewKey> for PreparedViewKey {
+    fn from(vk: ViewKey) -> Self {
+        Self::new(&vk)
+    }
+}
@@ -0,0 +1,295 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#[cfg(feature = "alloc")]
+use crate::{
+    permutation, JubJubAffine, PublicSpendKey, SpendAuthority, StealthAddress,
+};
+use crate::{JubJubExtended, JubJubScalar, SecretSpendKey};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use dusk_bytes::{DeserializableSlice, Error, Serializable};
+use dusk_jubjub::{BlsScalar, GENERATOR_EXTENDED};
+use dusk_poseidon::sponge;
+use dusk_poseidon::sponge::truncated;
+#[cfg(feature = "alloc")]
+use rand_core::{CryptoRng, RngCore};
+
+/// Domain separator of the reserve proof transcript, `"reserve"` in ASCII
+#[cfg(feature = "alloc")]
+const DOMAIN: BlsScalar = BlsScalar::from_raw([0x0065_7672_6573_6572, 0, 0, 0]);
+
+/// Domain separator of the reserve proof nonces, `"reserve-nonce"` in ASCII
+const NONCE: BlsScalar =
+    BlsScalar::from_raw([0x2d65_7672_6573_6572, 0x0065_636e_6f6e, 0, 0]);
+
+/// Aggregate proof that a set of [`StealthAddress`]es belongs to a single
+/// [`PublicSpendKey`].
+///
+/// For every address the proof discloses the shared secret `a·R`, so the
+/// verifier can recompute `PKr = H(a·R)·G + B`. A single batched discrete
+/// log equality proof binds all the shared secrets to `A = a·G`, and a
+/// Schnorr proof of knowledge of `b` shows the prover holds the spend
+/// authority of every address.
+///
+/// The serialized proof is `32·n + 96` bytes, against the `96·n` bytes of
+/// one independent proof per address.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReserveProof {
+    shared: Vec<JubJubExtended>,
+    c: JubJubScalar,
+    s_a: JubJubScalar,
+    s_b: JubJubScalar,
+}
+
+#[cfg(feature = "alloc")]
+impl ReserveProof {
+    /// Size in bytes of the fixed part of a serialized proof
+    pub const FIXED_SIZE: usize = 3 * JubJubScalar::SIZE;
+
+    /// Prove that every address in `addresses` is owned by the given
+    /// [`SpendAuthority`].
+    ///
+    /// Returns `None` if any of the addresses is not owned by the authority.
+    pub fn new<S: SpendAuthority, R: RngCore + CryptoRng>(
+        authority: &S,
+        addresses: &[StealthAddress],
+        rng: &mut R,
+    ) -> Result<Option<Self>, S::Error> {
+        let psk = authority.public_spend_key();
+
+        let mut shared = Vec::with_capacity(addresses.len());
+        for sa in addresses {
+            let aR = authority.shared_secret(sa.R())?;
+            if !derives(&psk, sa, &aR) {
+                return Ok(None);
+            }
+            shared.push(aR);
+        }
+
+        let transcript = transcript(&psk, addresses, &shared);
+        let R = combine(&transcript, addresses.iter().map(|sa| sa.R()));
+
+        let entropy = JubJubScalar::random(rng);
+        let (c, s_a, s_b) =
+            authority.reserve_responses(&transcript, &R, &entropy)?;
+
+        Ok(Some(Self {
+            shared,
+            c,
+            s_a,
+            s_b,
+        }))
+    }
+
+    /// Number of addresses covered by the proof
+    pub fn len(&self) -> usize {
+        self.shared.len()
+    }
+
+    /// Returns `true` if the proof covers no addresses
+    pub fn is_empty(&self) -> bool {
+        self.shared.is_empty()
+    }
+
+    /// Verify that every address in `addresses` belongs to `psk`
+    pub fn verify(
+        &self,
+        psk: &PublicSpendKey,
+        addresses: &[StealthAddress],
+    ) -> bool {
+        if addresses.len() != self.shared.len() {
+            return false;
+        }
+
+        let G = GENERATOR_EXTENDED;
+
+        let derived = addresses
+            .iter()
+            .zip(self.shared.iter())
+            .all(|(sa, aR)| derives(psk, sa, aR));
+
+        if !derived {
+            return false;
+        }
+
+        let transcript = transcript(psk, addresses, &self.shared);
+        let R = combine(&transcript, addresses.iter().map(|sa| sa.R()));
+        let aR = combine(&transcript, self.shared.iter());
+
+        let t_a = G * self.s_a + psk.A() * self.c;
+        let t_r = R * self.s_a + aR * self.c;
+        let t_b = G * self.s_b + psk.B() * self.c;
+
+        challenge(&transcript, &t_a, &t_r, &t_b) == self.c
+    }
+
+    /// Serialize the proof as the shared secrets followed by the challenge
+    /// and the two responses
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            self.shared.len() * JubJubAffine::SIZE + Self::FIXED_SIZE,
+        );
+
+        self.shared.iter().for_each(|aR| {
+            bytes.extend_from_slice(&JubJubAffine::from(aR).to_bytes())
+        });
+
+        bytes.extend_from_slice(&self.c.to_bytes());
+        bytes.extend_from_slice(&self.s_a.to_bytes());
+        bytes.extend_from_slice(&self.s_b.to_bytes());
+
+        bytes
+    }
+
+    /// Deserialize a proof from a slice of bytes
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        let n =
+            bytes.len().saturating_sub(Self::FIXED_SIZE) / JubJubAffine::SIZE;
+
+        // The length of a proof with as many shared secrets as fit in the
+        // bytes
+        let expected = n * JubJubAffine::SIZE + Self::FIXED_SIZE;
+        if expected != bytes.len() {
+            return Err(Error::BadLength {
+                found: bytes.len(),
+                expected,
+            });
+        }
+
+        let (points, scalars) = bytes.split_at(bytes.len() - Self::FIXED_SIZE);
+
+        let shared = points
+            .chunks_exact(JubJubAffine::SIZE)
+            .map(|p| JubJubAffine::from_slice(p).map(JubJubExtended::from))
+            .collect::<Result<_, _>>()?;
+
+        let c = JubJubScalar::from_slice(&scalars[..32])?;
+        let s_a = JubJubScalar::from_slice(&scalars[32..64])?;
+        let s_b = JubJubScalar::from_slice(&scalars[64..])?;
+
+        Ok(Self {
+            shared,
+            c,
+            s_a,
+            s_b,
+        })
+    }
+}
+
+/// Check that the shared secret `a·R` derives the one-time key
+/// `PKr = H(a·R)·G + B` of the address
+#[cfg(feature = "alloc")]
+fn derives(
+    psk: &PublicSpendKey,
+    sa: &StealthAddress,
+    aR: &JubJubExtended,
+) -> bool {
+    let pk_r = GENERATOR_EXTENDED * permutation::hash(aR) + psk.B();
+    sa.address() == &pk_r
+}
+
+/// Commit to the statement: the public spend key, the addresses and the
+/// disclosed shared secrets
+#[cfg(feature = "alloc")]
+fn transcript(
+    psk: &PublicSpendKey,
+    addresses: &[StealthAddress],
+    shared: &[JubJubExtended],
+) -> BlsScalar {
+    let mut inputs = Vec::with_capacity(5 + 6 * addresses.len());
+
+    inputs.push(DOMAIN);
+    inputs.extend_from_slice(&psk.A().to_hash_inputs());
+    inputs.extend_from_slice(&psk.B().to_hash_inputs());
+
+    addresses.iter().zip(shared.iter()).for_each(|(sa, aR)| {
+        inputs.extend_from_slice(&sa.R().to_hash_inputs());
+        inputs.extend_from_slice(&sa.address().to_hash_inputs());
+        inputs.extend_from_slice(&aR.to_hash_inputs());
+    });
+
+    sponge::hash(&inputs)
+}
+
+/// Random linear combination `Σ zᵢ·Pᵢ` with weights derived from the
+/// transcript, so no point can be cancelled out by the others
+#[cfg(feature = "alloc")]
+fn combine<'a>(
+    transcript: &BlsScalar,
+    points: impl Iterator<Item = &'a JubJubExtended>,
+) -> JubJubExtended {
+    points
+        .enumerate()
+        .map(|(i, p)| {
+            let z = truncated::hash(&[*transcript, BlsScalar::from(i as u64)]);
+            p * z
+        })
+        .sum()
+}
+
+fn challenge(
+    transcript: &BlsScalar,
+    t_a: &JubJubExtended,
+    t_r: &JubJubExtended,
+    t_b: &JubJubExtended,
+) -> JubJubScalar {
+    let [t_a_u, t_a_v] = t_a.to_hash_inputs();
+    let [t_r_u, t_r_v] = t_r.to_hash_inputs();
+    let [t_b_u, t_b_v] = t_b.to_hash_inputs();
+
+    truncated::hash(&[*transcript, t_a_u, t_a_v, t_r_u, t_r_v, t_b_u, t_b_v])
+}
+
+/// Compute the responses `(c, s_a, s_b)` of the secret spend key for the
+/// statement committed by `transcript`
+///
+/// The nonces are hashed from the secrets, the statement and the entropy, so
+/// they are unknown to whoever supplied the statement and the entropy.
+pub(crate) fn respond(
+    ssk: &SecretSpendKey,
+    transcript: &BlsScalar,
+    R: &JubJubExtended,
+    entropy: &JubJubScalar,
+) -> (JubJubScalar, JubJubScalar, JubJubScalar) {
+    let [R_u, R_v] = R.to_hash_inputs();
+    let nonce = |i: u64| {
+        let mut wide = [0u8; 64];
+        for (j, half) in wide.chunks_exact_mut(32).enumerate() {
+            let hash = sponge::hash(&[
+                NONCE,
+                BlsScalar::from(*ssk.a()),
+                BlsScalar::from(*ssk.b()),
+                *transcript,
+                R_u,
+                R_v,
+                BlsScalar::from(*entropy),
+                BlsScalar::from(2 * i + j as u64),
+            ]);
+            half.copy_from_slice(&hash.to_bytes());
+        }
+        JubJubScalar::from_bytes_wide(&wide)
+    };
+
+    let k_a = nonce(0);
+    let k_b = nonce(1);
+
+    let c = challenge(
+        transcript,
+        &(GENERATOR_EXTENDED * k_a),
+        &(R * k_a),
+        &(GENERATOR_EXTENDED * k_b),
+    );
+
+    let s_a = k_a - c * ssk.a();
+    let s_b = k_b - c * ssk.b();
+
+    (c, s_a, s_b)
+}
@@ -0,0 +1,389 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use crate::keys::spend::stealth::Ownable;
+use crate::{
+    fixed_base, JubJubScalar, KeyDerivation, KeyError, PreparedViewKey,
+    ProtocolVersion, SecretKey, SpendAuthority, StealthAddress, ViewKey,
+};
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use dusk_bytes::{DeserializableSlice, Serializable};
+
+#[cfg(feature = "rkyv-impl")]
+use crate::archive::VersionId;
+#[cfg(feature = "rkyv-impl")]
+use crate::view::ArchivedViewKey;
+#[cfg(feature = "rkyv-impl")]
+use crate::InvalidArchive;
+#[cfg(feature = "rkyv-impl")]
+use bytecheck::{CheckBytes, ErrorBox, StructCheckError};
+#[cfg(feature = "rkyv-impl")]
+use rkyv::{Archive, Deserialize, Serialize};
+
+/// Number of most recent blocks a [`Scanner`] can roll back
+pub const MAX_REORG_DEPTH: usize = 16;
+
+/// Hash identifying a block
+pub type BlockHash = [u8; 32];
+
+/// Error returned when a block cannot be scanned or rolled back
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScanError {
+    /// The block does not follow the last scanned block
+    UnexpectedHeight {
+        /// Height of the next block to scan
+        expected: u64,
+        /// Height of the given block
+        found: u64,
+    },
+    /// The parent of the block is not the last scanned block, so the chain
+    /// was reorganized and the scanner must be rolled back
+    ParentMismatch,
+    /// The height is not among the checkpoints of the scanner
+    UnknownCheckpoint(u64),
+    /// The block is the last representable height, so no block can follow it
+    HeightOverflow,
+}
+
+impl fmt::Display for ScanError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnexpectedHeight { expected, found } => {
+                write!(f, "expected block {expected}, found block {found}")
+            }
+            Self::ParentMismatch => write!(f, "parent block mismatch"),
+            Self::UnknownCheckpoint(height) => {
+                write!(f, "no checkpoint at height {height}")
+            }
+            Self::HeightOverflow => write!(f, "block height overflow"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ScanError {}
+
+/// Output found by a [`Scanner`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OwnedOutput {
+    /// Height of the block holding the output
+    pub height: u64,
+    /// Position of the output in its block
+    pub index: usize,
+    /// Scalar `H(a · R)` of the one-time key of the output
+    pub stealth_key: JubJubScalar,
+    /// Key derivation the output was found with
+    pub derivation: KeyDerivation,
+}
+
+impl OwnedOutput {
+    /// Computes the one-time secret key `sk_r = H(a · R) + b` of the output,
+    /// given its [`StealthAddress`], with the [`SpendAuthority`] of the
+    /// scanned view key
+    ///
+    /// Returns `None` if the address is not owned by the authority.
+    pub fn sk_r<S: SpendAuthority>(
+        &self,
+        authority: &S,
+        sa: &StealthAddress,
+    ) -> Result<Option<SecretKey>, S::Error> {
+        authority.sk_r_with(&self.derivation, sa)
+    }
+}
+
+/// Watch-only scanner of the outputs of a chain owned by a [`ViewKey`]
+///
+/// The scanner consumes the blocks in order, checking that each of them
+/// extends the previously scanned one, and keeps the hashes of the last
+/// [`MAX_REORG_DEPTH`] blocks as checkpoints. The owned outputs of a block
+/// are found with [`outputs`], and the block is recorded as scanned with
+/// [`commit`] once they are stored, or with [`scan`] doing both. When the
+/// chain is reorganized, the scanner is rolled back to the last common
+/// checkpoint, and the owned outputs found above it must be discarded.
+///
+/// The state of the scanner is persisted with its [`Serializable`] or rkyv
+/// encoding. Deserializing the archived scanner requires a deserializer
+/// whose error converts from `InvalidArchive`, returned for an unknown
+/// protocol version.
+///
+/// [`outputs`]: Scanner::outputs
+/// [`commit`]: Scanner::commit
+/// [`scan`]: Scanner::scan
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "rkyv-impl", derive(Archive, Serialize, Deserialize))]
+pub struct Scanner {
+    vk: ViewKey,
+    #[cfg_attr(feature = "rkyv-impl", with(VersionId))]
+    derivation: KeyDerivation,
+    next_height: u64,
+    len: u8,
+    checkpoints: [BlockHash; MAX_REORG_DEPTH],
+}
+
+#[cfg(feature = "rkyv-impl")]
+impl<C: ?Sized> CheckBytes<C> for ArchivedScanner {
+    type Error = StructCheckError;
+
+    unsafe fn check_bytes<'a>(
+        value: *const Self,
+        context: &mut C,
+    ) -> Result<&'a Self, Self::Error> {
+        ArchivedViewKey::check_bytes(core::ptr::addr_of!((*value).vk), context)
+            .map_err(|e| StructCheckError {
+                field_name: "vk",
+                inner: ErrorBox::new(e),
+            })?;
+
+        let archived = &*value;
+        if ProtocolVersion::from_id(archived.derivation).is_none() {
+            return Err(StructCheckError {
+                field_name: "derivation",
+                inner: ErrorBox::new(InvalidArchive::InvalidEncoding),
+            });
+        }
+        if !valid_len(archived.len, archived.next_height) {
+            return Err(StructCheckError {
+                field_name: "len",
+                inner: ErrorBox::new(InvalidArchive::InvalidEncoding),
+            });
+        }
+
+        Ok(archived)
+    }
+}
+
+impl Scanner {
+    /// Creates a scanner starting from the block at the given height
+    pub fn new(vk: ViewKey, start_height: u64) -> Self {
+        Self::with_derivation(vk, &KeyDerivation::LEGACY, start_height)
+    }
+
+    /// Creates a scanner starting from the block at the given height, hashing
+    /// the shared secrets with the given [`KeyDerivation`]
+    pub fn with_derivation(
+        vk: ViewKey,
+        derivation: &KeyDerivation,
+        start_height: u64,
+    ) -> Self {
+        Self {
+            vk,
+            derivation: *derivation,
+            next_height: start_height,
+            len: 0,
+            checkpoints: [[0; 32]; MAX_REORG_DEPTH],
+        }
+    }
+
+    /// Gets the view key of the scanner
+    pub const fn view_key(&self) -> &ViewKey {
+        &self.vk
+    }
+
+    /// Gets the key derivation of the scanner
+    pub const fn derivation(&self) -> KeyDerivation {
+        self.derivation
+    }
+
+    /// Gets the height of the next block to scan
+    pub const fn next_height(&self) -> u64 {
+        self.next_height
+    }
+
+    /// Gets the height of the last scanned block, if any
+    pub const fn height(&self) -> Option<u64> {
+        match self.len {
+            0 => None,
+            _ => Some(self.next_height - 1),
+        }
+    }
+
+    /// Gets the hash of the block scanned at the given height, if it is
+    /// among the checkpoints
+    pub fn checkpoint(&self, height: u64) -> Option<&BlockHash> {
+        let depth = self.next_height.checked_sub(height)?;
+
+        match depth >= 1 && depth <= self.len as u64 {
+            true => Some(&self.checkpoints[self.slot(height)]),
+            false => None,
+        }
+    }
+
+    /// Finds the outputs owned by the view key in the block at the given
+    /// height, whose parent is the block with the hash `parent`
+    ///
+    /// The first block is accepted whatever its parent, while every
+    /// following block must extend the last scanned block. The scanner is
+    /// left unchanged, so [`next_height`] stays at this block until it is
+    /// committed.
+    ///
+    /// [`next_height`]: Scanner::next_height
+    pub fn outputs<'a, T: Ownable>(
+        &self,
+        height: u64,
+        parent: &BlockHash,
+        items: &'a [T],
+    ) -> Result<impl Iterator<Item = OwnedOutput> + 'a, ScanError> {
+        self.check_block(height, parent)?;
+
+        let vk = PreparedViewKey::new(&self.vk);
+        let derivation = self.derivation();
+
+        Ok(items.iter().enumerate().filter_map(move |(index, item)| {
+            let sa = item.stealth_address();
+
+            let aR = vk.shared_secret(sa.R());
+            let stealth_key = derivation.stealth_key(&aR);
+            let pk_r =
+                fixed_base::mul_generator(&stealth_key) + vk.view_key().B();
+
+            (sa.address() == &pk_r).then_some(OwnedOutput {
+                height,
+                index,
+                stealth_key,
+                derivation,
+            })
+        }))
+    }
+
+    /// Records the block at the given height, whose parent is the block with
+    /// the hash `parent`, as scanned
+    pub fn commit(
+        &mut self,
+        height: u64,
+        hash: BlockHash,
+        parent: &BlockHash,
+    ) -> Result<(), ScanError> {
+        let next_height = self.check_block(height, parent)?;
+
+        let slot = self.slot(height);
+        self.checkpoints[slot] = hash;
+        self.len = (self.len + 1).min(MAX_REORG_DEPTH as u8);
+        self.next_height = next_height;
+
+        Ok(())
+    }
+
+    /// Finds the owned outputs of the block at the given height, whose parent
+    /// is the block with the hash `parent`, and commits the block
+    #[cfg(feature = "alloc")]
+    pub fn scan<T: Ownable>(
+        &mut self,
+        height: u64,
+        hash: BlockHash,
+        parent: &BlockHash,
+        items: &[T],
+    ) -> Result<Vec<OwnedOutput>, ScanError> {
+        let outputs = self.outputs(height, parent, items)?.collect();
+        self.commit(height, hash, parent)?;
+
+        Ok(outputs)
+    }
+
+    /// Rolls the scanner back to the checkpoint at the given height, so the
+    /// next block to scan is the one following it
+    ///
+    /// The outputs found in the blocks above the height are no longer valid.
+    pub fn rollback(&mut self, height: u64) -> Result<(), ScanError> {
+        if self.checkpoint(height).is_none() {
+            return Err(ScanError::UnknownCheckpoint(height));
+        }
+
+        self.len -= (self.next_height - height - 1) as u8;
+        self.next_height = height + 1;
+
+        Ok(())
+    }
+
+    /// Checks that the block extends the last scanned block, returning the
+    /// height of the block following it
+    fn check_block(
+        &self,
+        height: u64,
+        parent: &BlockHash,
+    ) -> Result<u64, ScanError> {
+        if height != self.next_height {
+            return Err(ScanError::UnexpectedHeight {
+                expected: self.next_height,
+                found: height,
+            });
+        }
+        if let Some(last) = self.height().and_then(|h| self.checkpoint(h)) {
+            if last != parent {
+                return Err(ScanError::ParentMismatch);
+            }
+        }
+
+        height.checked_add(1).ok_or(ScanError::HeightOverflow)
+    }
+
+    fn slot(&self, height: u64) -> usize {
+        (height % MAX_REORG_DEPTH as u64) as usize
+    }
+}
+
+/// Checks that the number of checkpoints fits both the ring and the blocks
+/// scanned so far
+const fn valid_len(len: u8, next_height: u64) -> bool {
+    len as usize <= MAX_REORG_DEPTH && len as u64 <= next_height
+}
+
+impl Serializable<{ 64 + 10 + 32 * MAX_REORG_DEPTH }> for Scanner {
+    type Error = KeyError;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0u8; Self::SIZE];
+
+        let (vk, rest) = bytes.split_at_mut(ViewKey::SIZE);
+        vk.copy_from_slice(&self.vk.to_bytes());
+        rest[0] = self.derivation.version().id();
+        rest[1..9].copy_from_slice(&self.next_height.to_le_bytes());
+        rest[9] = self.len;
+
+        rest[10..]
+            .chunks_exact_mut(32)
+            .zip(&self.checkpoints)
+            .for_each(|(chunk, hash)| chunk.copy_from_slice(hash));
+
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        let (vk, rest) = bytes.split_at(ViewKey::SIZE);
+        let vk = ViewKey::from_slice(vk)?;
+
+        let version =
+            ProtocolVersion::from_id(rest[0]).ok_or(KeyError::InvalidData)?;
+        let derivation = KeyDerivation::new(version);
+
+        let mut next_height = [0u8; 8];
+        next_height.copy_from_slice(&rest[1..9]);
+        let next_height = u64::from_le_bytes(next_height);
+
+        let len = rest[9];
+        if !valid_len(len, next_height) {
+            return Err(KeyError::InvalidData);
+        }
+
+        let mut checkpoints = [[0u8; 32]; MAX_REORG_DEPTH];
+        checkpoints
+            .iter_mut()
+            .zip(rest[10..].chunks_exact(32))
+            .for_each(|(hash, chunk)| hash.copy_from_slice(chunk));
+
+        Ok(Self {
+            vk,
+            derivation,
+            next_height,
+            len,
+            checkpoints,
+        })
+    }
+}
@@ -0,0 +1,165 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! `serde` implementations of the key types
+//!
+//! Keys are encoded with their [`Serializable`] representation: as a hex
+//! string for human readable formats and as raw bytes for binary formats.
+//! Decoding goes through [`Serializable::from_bytes`], so it performs the
+//! same validation of scalars and points.
+
+use crate::{
+    KeyError, PublicKey, PublicSpendKey, SecretKey, SecretSpendKey,
+    StealthAddress, ViewKey,
+};
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use dusk_bytes::{DeserializableSlice, ParseHexStr, Serializable};
+use serde::de::{self, Unexpected, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Largest serialized key, in bytes
+const MAX_SIZE: usize = 64;
+
+const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
+
+fn serialize<S, const N: usize>(
+    bytes: &[u8; N],
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if !serializer.is_human_readable() {
+        return serializer.serialize_bytes(bytes);
+    }
+
+    let mut buf = [0u8; 2 * MAX_SIZE];
+    let hex = &mut buf[..2 * N];
+
+    bytes.iter().enumerate().for_each(|(i, b)| {
+        hex[2 * i] = HEX_CHARS[(b >> 4) as usize];
+        hex[2 * i + 1] = HEX_CHARS[(b & 0x0f) as usize];
+    });
+
+    // The buffer only holds ASCII hex characters
+    let hex = core::str::from_utf8(hex).map_err(serde::ser::Error::custom)?;
+
+    serializer.serialize_str(hex)
+}
+
+fn deserialize<'de, D, T, const N: usize>(
+    deserializer: D,
+) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Serializable<N, Error = KeyError>,
+{
+    let visitor = KeyVisitor::<T, N>(PhantomData);
+
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(visitor)
+    } else {
+        deserializer.deserialize_bytes(visitor)
+    }
+}
+
+struct KeyVisitor<T, const N: usize>(PhantomData<T>);
+
+impl<T, const N: usize> KeyVisitor<T, N>
+where
+    T: Serializable<N, Error = KeyError>,
+{
+    fn map_err<E: de::Error>(
+        &self,
+        err: KeyError,
+        unexpected: Unexpected,
+    ) -> E {
+        match err {
+            KeyError::WrongLength { found, .. } => {
+                E::invalid_length(found, self)
+            }
+            _ => E::invalid_value(unexpected, self),
+        }
+    }
+}
+
+impl<'de, T, const N: usize> Visitor<'de> for KeyVisitor<T, N>
+where
+    T: Serializable<N, Error = KeyError>,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} bytes, or a string of {} hex characters", N, 2 * N)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        if v.len() != 2 * N {
+            return Err(E::invalid_length(v.len(), &self));
+        }
+
+        T::from_hex_str(v).map_err(|e| self.map_err(e, Unexpected::Str(v)))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<T, E> {
+        if v.len() != N {
+            return Err(E::invalid_length(v.len(), &self));
+        }
+
+        T::from_slice(v).map_err(|e| self.map_err(e, Unexpected::Bytes(v)))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<T, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        let mut bytes = [0u8; N];
+
+        for (i, b) in bytes.iter_mut().enumerate() {
+            *b = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(de::Error::invalid_length(N + 1, &self));
+        }
+
+        T::from_bytes(&bytes)
+            .map_err(|e| self.map_err(e, Unexpected::Bytes(&bytes)))
+    }
+}
+
+macro_rules! impl_serde {
+    ($ty:ty, $size:literal) => {
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                serialize::<S, $size>(&self.to_bytes(), serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                deserialize::<D, Self, $size>(deserializer)
+            }
+        }
+    };
+}
+
+impl_serde!(PublicKey, 32);
+impl_serde!(SecretKey, 32);
+impl_serde!(PublicSpendKey, 64);
+impl_serde!(SecretSpendKey, 64);
+impl_serde!(ViewKey, 64);
+impl_serde!(StealthAddress, 64);
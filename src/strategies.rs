@@ -0,0 +1,122 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Strategies generating the key types in property tests
+//!
+//! Every key is derived from uniformly distributed scalars, so the generated
+//! keys are always valid and consistent with each other: a generated
+//! [`StealthAddress`] is the one of some key, and [`owned_stealth_address`]
+//! also yields the [`SecretSpendKey`] owning it. The key types implement
+//! [`Arbitrary`] with the same strategies, so they can be generated with
+//! [`any`].
+//!
+//! Shrinking a key shrinks the bytes of its scalars, which carries no
+//! meaning, so failures are reported with the first failing key.
+
+use crate::{
+    IntegratedAddress, IntegratedStealthAddress, JubJubScalar, KeyDerivation,
+    ProtocolVersion, PublicKey, PublicSpendKey, SecretKey, SecretSpendKey,
+    StealthAddress, ViewKey, PAYMENT_ID_SIZE,
+};
+
+use proptest::prelude::*;
+
+/// Uniformly distributed scalar, reduced from 64 random bytes
+pub fn jubjub_scalar() -> impl Strategy<Value = JubJubScalar> {
+    (any::<[u8; 32]>(), any::<[u8; 32]>()).prop_map(|(lo, hi)| {
+        let mut wide = [0u8; 64];
+        wide[..32].copy_from_slice(&lo);
+        wide[32..].copy_from_slice(&hi);
+
+        JubJubScalar::from_bytes_wide(&wide)
+    })
+}
+
+/// Key derivation of any protocol version
+pub fn key_derivation() -> impl Strategy<Value = KeyDerivation> {
+    prop_oneof![Just(ProtocolVersion::Legacy), Just(ProtocolVersion::V1)]
+        .prop_map(KeyDerivation::new)
+}
+
+/// Random secret key
+pub fn secret_key() -> impl Strategy<Value = SecretKey> {
+    jubjub_scalar().prop_map(SecretKey::from)
+}
+
+/// Public key of a random secret key
+pub fn public_key() -> impl Strategy<Value = PublicKey> {
+    secret_key().prop_map(|sk| PublicKey::from(&sk))
+}
+
+/// Random secret spend key
+pub fn secret_spend_key() -> impl Strategy<Value = SecretSpendKey> {
+    (jubjub_scalar(), jubjub_scalar())
+        .prop_map(|(a, b)| SecretSpendKey::new(a, b))
+}
+
+/// Public spend key of a random secret spend key
+pub fn public_spend_key() -> impl Strategy<Value = PublicSpendKey> {
+    secret_spend_key().prop_map(|ssk| ssk.public_spend_key())
+}
+
+/// View key of a random secret spend key
+pub fn view_key() -> impl Strategy<Value = ViewKey> {
+    secret_spend_key().prop_map(|ssk| ssk.view_key())
+}
+
+/// Stealth address generated for a random public spend key
+pub fn stealth_address() -> impl Strategy<Value = StealthAddress> {
+    owned_stealth_address().prop_map(|(_, sa)| sa)
+}
+
+/// Stealth address generated for a random secret spend key, together with
+/// the key owning it
+pub fn owned_stealth_address(
+) -> impl Strategy<Value = (SecretSpendKey, StealthAddress)> {
+    (secret_spend_key(), jubjub_scalar()).prop_map(|(ssk, r)| {
+        let sa = ssk.public_spend_key().gen_stealth_address(&r);
+        (ssk, sa)
+    })
+}
+
+/// Integrated address of a random public spend key and payment ID
+pub fn integrated_address() -> impl Strategy<Value = IntegratedAddress> {
+    (public_spend_key(), any::<[u8; PAYMENT_ID_SIZE]>())
+        .prop_map(|(psk, payment_id)| IntegratedAddress::new(psk, payment_id))
+}
+
+/// Stealth address generated for a random integrated address
+pub fn integrated_stealth_address(
+) -> impl Strategy<Value = IntegratedStealthAddress> {
+    (integrated_address(), jubjub_scalar())
+        .prop_map(|(address, r)| address.gen_stealth_address(&r))
+}
+
+macro_rules! impl_arbitrary {
+    ($($ty:ty => $strategy:ident),* $(,)?) => {
+        $(
+            impl Arbitrary for $ty {
+                type Parameters = ();
+                type Strategy = BoxedStrategy<Self>;
+
+                fn arbitrary_with(_: ()) -> Self::Strategy {
+                    $strategy().boxed()
+                }
+            }
+        )*
+    };
+}
+
+impl_arbitrary!(
+    SecretKey => secret_key,
+    PublicKey => public_key,
+    SecretSpendKey => secret_spend_key,
+    PublicSpendKey => public_spend_key,
+    ViewKey => view_key,
+    StealthAddress => stealth_address,
+    IntegratedAddress => integrated_address,
+    IntegratedStealthAddress => integrated_stealth_address,
+);
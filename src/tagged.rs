@@ -0,0 +1,280 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Self-describing envelope of the key encodings
+//!
+//! The raw encodings of different key types can have the same length, so a
+//! view key would silently decode as a spend key. The tagged encoding wraps
+//! the [`Serializable`] bytes of a key in an envelope recording what they
+//! are:
+//!
+//! | bytes         | content                               |
+//! |---------------|---------------------------------------|
+//! | `0`           | [`KeyType`] identifier                |
+//! | `1`           | [`ProtocolVersion`] identifier        |
+//! | `2`           | [`Network`] identifier                |
+//! | `3..3 + N`    | encoding of the key                   |
+//! | last four     | CRC-32 of the preceding bytes, in LE  |
+//!
+//! The checksum catches accidental corruption, not tampering.
+
+use crate::{
+    KeyError, ProtocolVersion, PublicKey, PublicSpendKey, SecretKey,
+    SecretSpendKey, StealthAddress, ViewKey,
+};
+
+use dusk_bytes::Serializable;
+
+/// Size of the header of the tagged encoding
+const HEADER_SIZE: usize = 3;
+
+/// Size of the checksum of the tagged encoding
+const CHECKSUM_SIZE: usize = 4;
+
+/// Bytes added by the envelope to the encoding of a key
+pub const TAGGED_OVERHEAD: usize = HEADER_SIZE + CHECKSUM_SIZE;
+
+/// Network a key is meant to be used on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Network {
+    /// Main network
+    Mainnet,
+    /// Public test network
+    Testnet,
+    /// Local development network
+    Devnet,
+}
+
+impl Network {
+    /// Numeric identifier of the network
+    pub const fn id(&self) -> u8 {
+        match self {
+            Self::Mainnet => 0,
+            Self::Testnet => 1,
+            Self::Devnet => 2,
+        }
+    }
+
+    /// Gets the network matching the given identifier, if any
+    pub const fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(Self::Mainnet),
+            1 => Some(Self::Testnet),
+            2 => Some(Self::Devnet),
+            _ => None,
+        }
+    }
+}
+
+/// Type of the key held by a tagged encoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum KeyType {
+    /// [`SecretKey`]
+    SecretKey,
+    /// [`PublicKey`]
+    PublicKey,
+    /// [`SecretSpendKey`]
+    SecretSpendKey,
+    /// [`PublicSpendKey`]
+    PublicSpendKey,
+    /// [`ViewKey`]
+    ViewKey,
+    /// [`StealthAddress`]
+    StealthAddress,
+}
+
+impl KeyType {
+    /// Numeric identifier of the key type
+    pub const fn id(&self) -> u8 {
+        match self {
+            Self::SecretKey => 1,
+            Self::PublicKey => 2,
+            Self::SecretSpendKey => 3,
+            Self::PublicSpendKey => 4,
+            Self::ViewKey => 5,
+            Self::StealthAddress => 6,
+        }
+    }
+
+    /// Gets the key type matching the given identifier, if any
+    pub const fn from_id(id: u8) -> Option<Self> {
+        match id {
+            1 => Some(Self::SecretKey),
+            2 => Some(Self::PublicKey),
+            3 => Some(Self::SecretSpendKey),
+            4 => Some(Self::PublicSpendKey),
+            5 => Some(Self::ViewKey),
+            6 => Some(Self::StealthAddress),
+            _ => None,
+        }
+    }
+
+    /// Size of the encoding of the key, without the envelope
+    pub const fn size(&self) -> usize {
+        match self {
+            Self::SecretKey => SecretKey::SIZE,
+            Self::PublicKey => PublicKey::SIZE,
+            Self::SecretSpendKey => SecretSpendKey::SIZE,
+            Self::PublicSpendKey => PublicSpendKey::SIZE,
+            Self::ViewKey => ViewKey::SIZE,
+            Self::StealthAddress => StealthAddress::SIZE,
+        }
+    }
+}
+
+/// Header of a tagged encoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TaggedHeader {
+    /// Type of the key
+    pub key_type: KeyType,
+    /// Protocol version the key was encoded for
+    pub version: ProtocolVersion,
+    /// Network the key is meant to be used on
+    pub network: Network,
+}
+
+impl TaggedHeader {
+    /// Reads the header of a tagged encoding, checking its length and
+    /// checksum without decoding the key
+    pub fn inspect(bytes: &[u8]) -> Result<Self, KeyError> {
+        if bytes.len() < TAGGED_OVERHEAD {
+            return Err(KeyError::WrongLength {
+                found: bytes.len(),
+                expected: TAGGED_OVERHEAD,
+            });
+        }
+
+        let key_type =
+            KeyType::from_id(bytes[0]).ok_or(KeyError::InvalidData)?;
+
+        let expected = key_type.size() + TAGGED_OVERHEAD;
+        if bytes.len() != expected {
+            return Err(KeyError::WrongLength {
+                found: bytes.len(),
+                expected,
+            });
+        }
+
+        let (content, checksum) = bytes.split_at(expected - CHECKSUM_SIZE);
+        if crc32(content).to_le_bytes() != checksum {
+            return Err(KeyError::BadChecksum);
+        }
+
+        let version =
+            ProtocolVersion::from_id(bytes[1]).ok_or(KeyError::InvalidData)?;
+        let network =
+            Network::from_id(bytes[2]).ok_or(KeyError::InvalidData)?;
+
+        Ok(Self {
+            key_type,
+            version,
+            network,
+        })
+    }
+}
+
+/// Wraps the encoding of a key in the envelope
+fn encode<const N: usize, const M: usize>(
+    key_type: KeyType,
+    version: ProtocolVersion,
+    network: Network,
+    key: &[u8; N],
+) -> [u8; M] {
+    debug_assert_eq!(M, N + TAGGED_OVERHEAD);
+
+    let mut bytes = [0u8; M];
+    bytes[0] = key_type.id();
+    bytes[1] = version.id();
+    bytes[2] = network.id();
+    bytes[HEADER_SIZE..HEADER_SIZE + N].copy_from_slice(key);
+
+    let checksum = crc32(&bytes[..M - CHECKSUM_SIZE]);
+    bytes[M - CHECKSUM_SIZE..].copy_from_slice(&checksum.to_le_bytes());
+
+    bytes
+}
+
+/// Checks the envelope of a key of the given type, returning the protocol
+/// version and the encoding of the key
+fn decode<const N: usize>(
+    key_type: KeyType,
+    network: Network,
+    bytes: &[u8],
+) -> Result<(ProtocolVersion, [u8; N]), KeyError> {
+    let header = TaggedHeader::inspect(bytes)?;
+
+    if header.key_type != key_type {
+        return Err(KeyError::WrongKeyType);
+    }
+    if header.network != network {
+        return Err(KeyError::WrongNetwork);
+    }
+
+    let mut key = [0u8; N];
+    key.copy_from_slice(&bytes[HEADER_SIZE..HEADER_SIZE + N]);
+
+    Ok((header.version, key))
+}
+
+/// CRC-32 with the IEEE polynomial
+pub(crate) const fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        crc ^= bytes[i] as u32;
+
+        let mut bit = 0;
+        while bit < 8 {
+            crc = (crc >> 1) ^ (0xedb8_8320 & (crc & 1).wrapping_neg());
+            bit += 1;
+        }
+
+        i += 1;
+    }
+
+    !crc
+}
+
+macro_rules! impl_tagged {
+    ($ty:ident, $size:literal) => {
+        impl $ty {
+            /// Size of the tagged encoding
+            pub const TAGGED_SIZE: usize = $size + TAGGED_OVERHEAD;
+
+            /// Encodes the key in a self-describing envelope, recording its
+            /// type, the protocol version and the network
+            pub fn encode_tagged(
+                &self,
+                version: ProtocolVersion,
+                network: Network,
+            ) -> [u8; Self::TAGGED_SIZE] {
+                encode(KeyType::$ty, version, network, &self.to_bytes())
+            }
+
+            /// Decodes a key from its tagged encoding, returning it together
+            /// with the protocol version it was encoded for
+            ///
+            /// Encodings of another key type, or meant for another network,
+            /// are rejected.
+            pub fn decode_tagged(
+                bytes: &[u8],
+                network: Network,
+            ) -> Result<(Self, ProtocolVersion), KeyError> {
+                let (version, key) = decode(KeyType::$ty, network, bytes)?;
+
+                Ok((Self::from_bytes(&key)?, version))
+            }
+        }
+    };
+}
+
+impl_tagged!(SecretKey, 32);
+impl_tagged!(PublicKey, 32);
+impl_tagged!(SecretSpendKey, 64);
+impl_tagged!(PublicSpendKey, 64);
+impl_tagged!(ViewKey, 64);
+impl_tagged!(StealthAddress, 64);
@@ -4,17 +4,23 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
-use crate::keys::spend::stealth;
+use crate::keys::spend::stealth::{self, Ownable};
 
 use crate::{
-    permutation, JubJubAffine, JubJubExtended, JubJubScalar, PublicSpendKey,
-    SecretSpendKey,
+    fixed_base, IntegratedStealthAddress, JubJubAffine, JubJubExtended,
+    JubJubScalar, KeyDerivation, PublicSpendKey, SecretSpendKey,
+    PAYMENT_ID_SIZE,
 };
 
-use dusk_bytes::{DeserializableSlice, Error, HexDebug, Serializable};
-use dusk_jubjub::GENERATOR_EXTENDED;
+use crate::keys::{check_small_order, decode_point, decode_scalar};
+use crate::KeyError;
+use dusk_bytes::{HexDebug, Serializable};
 use subtle::{Choice, ConstantTimeEq};
 
+#[cfg(feature = "rkyv-impl")]
+use crate::archive;
+#[cfg(feature = "rkyv-impl")]
+use bytecheck::{CheckBytes, StructCheckError};
 #[cfg(feature = "rkyv-impl")]
 use rkyv::{Archive, Deserialize, Serialize};
 
@@ -23,16 +29,34 @@ use rkyv::{Archive, Deserialize, Serialize};
 /// The notes are encrypted against secret a, so this is used to decrypt the
 /// blinding factor and value
 #[derive(Clone, Copy, HexDebug)]
-#[cfg_attr(
-    feature = "rkyv-impl",
-    derive(Archive, Serialize, Deserialize),
-    archive_attr(derive(bytecheck::CheckBytes))
-)]
+#[cfg_attr(feature = "rkyv-impl", derive(Archive, Serialize, Deserialize))]
 pub struct ViewKey {
     a: JubJubScalar,
     B: JubJubExtended,
 }
 
+#[cfg(feature = "rkyv-impl")]
+impl<C: ?Sized> CheckBytes<C> for ArchivedViewKey {
+    type Error = StructCheckError;
+
+    unsafe fn check_bytes<'a>(
+        value: *const Self,
+        context: &mut C,
+    ) -> Result<&'a Self, Self::Error> {
+        archive::check_scalar(core::ptr::addr_of!((*value).a), context)
+            .map_err(|inner| StructCheckError {
+                field_name: "a",
+                inner,
+            })?;
+        archive::check_point(core::ptr::addr_of!((*value).B), context)
+            .map_err(|inner| StructCheckError {
+                field_name: "B",
+                inner,
+            })?;
+        Ok(&*value)
+    }
+}
+
 impl ConstantTimeEq for ViewKey {
     fn ct_eq(&self, other: &Self) -> Choice {
         // TODO - Why self.a is not checked?
@@ -57,7 +81,7 @@ impl ViewKey {
 
     /// Derive the secret to deterministically construct a [`PublicSpendKey`]
     pub fn public_spend_key(&self) -> PublicSpendKey {
-        let A = GENERATOR_EXTENDED * self.a;
+        let A = fixed_base::mul_generator(&self.a);
 
         PublicSpendKey::new(A, self.B)
     }
@@ -72,17 +96,61 @@ impl ViewKey {
         &self.B
     }
 
+    /// Rejects a key with `B` of small order
+    pub fn check_small_order(&self) -> Result<(), KeyError> {
+        check_small_order(&self.B)
+    }
+
     /// Checks `PKr = H(R · a) · G + B`
     pub fn owns(&self, owner: &impl stealth::Ownable) -> bool {
+        self.owns_with(&KeyDerivation::LEGACY, owner)
+    }
+
+    /// Checks `PKr = H(R · a) · G + B`, hashing the shared secret with the
+    /// given [`KeyDerivation`]
+    pub fn owns_with(
+        &self,
+        derivation: &KeyDerivation,
+        owner: &impl stealth::Ownable,
+    ) -> bool {
         let sa = owner.stealth_address();
 
         let aR = sa.R() * self.a();
-        let aR = permutation::hash(&aR);
-        let aR = GENERATOR_EXTENDED * aR;
+        let aR = derivation.stealth_key(&aR);
+        let aR = fixed_base::mul_generator(&aR);
         let pk_r = aR + self.B();
 
         sa.address() == &pk_r
     }
+
+    /// Checks `PKr = H(R · a) · G + B`, returning the decrypted payment ID
+    /// of the output if it is owned
+    pub fn owns_integrated(
+        &self,
+        owner: &IntegratedStealthAddress,
+    ) -> Option<[u8; PAYMENT_ID_SIZE]> {
+        self.owns_integrated_with(&KeyDerivation::LEGACY, owner)
+    }
+
+    /// Checks `PKr = H(R · a) · G + B`, hashing the shared secret with the
+    /// given [`KeyDerivation`], and returns the decrypted payment ID of the
+    /// output if it is owned
+    pub fn owns_integrated_with(
+        &self,
+        derivation: &KeyDerivation,
+        owner: &IntegratedStealthAddress,
+    ) -> Option<[u8; PAYMENT_ID_SIZE]> {
+        let sa = owner.stealth_address();
+
+        let aR = sa.R() * self.a();
+        let pk_r = fixed_base::mul_generator(&derivation.stealth_key(&aR));
+        let pk_r = pk_r + self.B();
+
+        match sa.address() == &pk_r {
+            true => Some(owner.decrypt_id(derivation, &aR)),
+            false => None,
+        }
+    }
 }
 
 impl From<SecretSpendKey> for ViewKey {
@@ -98,7 +166,7 @@ impl From<&SecretSpendKey> for ViewKey {
 }
 
 impl Serializable<64> for ViewKey {
-    type Error = Error;
+    type Error = KeyError;
 
     fn to_bytes(&self) -> [u8; 64] {
         let mut bytes = [0u8; 64];
@@ -108,8 +176,8 @@ impl Serializable<64> for ViewKey {
     }
 
     fn from_bytes(buf: &[u8; 64]) -> Result<Self, Self::Error> {
-        let a = JubJubScalar::from_slice(&buf[..32])?;
-        let B = JubJubExtended::from(JubJubAffine::from_slice(&buf[32..])?);
+        let a = decode_scalar(&buf[..32])?;
+        let B = decode_point(&buf[32..])?;
 
         Ok(Self { a, B })
     }
@@ -0,0 +1,235 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use crate::hash_to_curve::{hash_to_curve, DomainSeparationTag};
+use crate::{JubJubAffine, JubJubExtended, JubJubScalar};
+
+use dusk_bytes::{DeserializableSlice, Error, HexDebug, Serializable};
+use dusk_jubjub::{BlsScalar, GENERATOR_EXTENDED};
+use dusk_poseidon::sponge;
+use dusk_poseidon::sponge::truncated;
+
+#[cfg(feature = "rkyv-impl")]
+use crate::archive;
+#[cfg(feature = "rkyv-impl")]
+use bytecheck::{CheckBytes, StructCheckError};
+#[cfg(feature = "rkyv-impl")]
+use rkyv::{Archive, Deserialize, Serialize};
+
+/// Domain separator of the VRF hash to curve, `"vrf"` in ASCII
+const HASH_DOMAIN: DomainSeparationTag =
+    DomainSeparationTag::from_scalar(BlsScalar::from_raw([0x667276, 0, 0, 0]));
+
+/// Domain separator of the VRF nonce, `"vrf-nonce"` in ASCII
+const NONCE_DOMAIN: BlsScalar =
+    BlsScalar::from_raw([0x636e6f6e2d667276, 0x65, 0, 0]);
+
+/// Domain separator of the VRF challenge, `"vrf-chal"` in ASCII
+const CHALLENGE_DOMAIN: BlsScalar =
+    BlsScalar::from_raw([0x6c6168632d667276, 0, 0, 0]);
+
+/// Domain separator of the VRF output, `"vrf-out"` in ASCII
+const OUTPUT_DOMAIN: BlsScalar =
+    BlsScalar::from_raw([0x74756f2d667276, 0, 0, 0]);
+
+/// Pseudorandom output of the verifiable random function
+#[derive(Default, Clone, Copy, PartialEq, Eq, HexDebug)]
+#[cfg_attr(
+    feature = "rkyv-impl",
+    derive(Archive, Serialize, Deserialize),
+    archive_attr(derive(bytecheck::CheckBytes))
+)]
+pub struct VrfOutput(BlsScalar);
+
+impl VrfOutput {
+    /// Gets the output as a field element
+    pub const fn as_scalar(&self) -> &BlsScalar {
+        &self.0
+    }
+}
+
+impl From<VrfOutput> for BlsScalar {
+    fn from(output: VrfOutput) -> Self {
+        output.0
+    }
+}
+
+impl Serializable<32> for VrfOutput {
+    type Error = Error;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        <BlsScalar as Serializable<32>>::from_bytes(bytes).map(Self)
+    }
+}
+
+/// Proof that a [`VrfOutput`] was computed with the secret counterpart of a
+/// given [`PublicKey`](crate::PublicKey)
+///
+/// The proof is composed by the point `Γ = x·H`, where `H` is the input
+/// hashed to the curve, and by a Schnorr-like pair `(c, s)` proving that `Γ`
+/// and the public key share the same discrete logarithm `x`.
+#[derive(Default, Clone, Copy, PartialEq, Eq, HexDebug)]
+#[cfg_attr(feature = "rkyv-impl", derive(Archive, Serialize, Deserialize))]
+pub struct VrfProof {
+    gamma: JubJubExtended,
+    c: JubJubScalar,
+    s: JubJubScalar,
+}
+
+#[cfg(feature = "rkyv-impl")]
+impl<C: ?Sized> CheckBytes<C> for ArchivedVrfProof {
+    type Error = StructCheckError;
+
+    unsafe fn check_bytes<'a>(
+        value: *const Self,
+        context: &mut C,
+    ) -> Result<&'a Self, Self::Error> {
+        archive::check_point(core::ptr::addr_of!((*value).gamma), context)
+            .map_err(|inner| StructCheckError {
+                field_name: "gamma",
+                inner,
+            })?;
+        archive::check_scalar(core::ptr::addr_of!((*value).c), context)
+            .map_err(|inner| StructCheckError {
+                field_name: "c",
+                inner,
+            })?;
+        archive::check_scalar(core::ptr::addr_of!((*value).s), context)
+            .map_err(|inner| StructCheckError {
+                field_name: "s",
+                inner,
+            })?;
+        Ok(&*value)
+    }
+}
+
+impl VrfProof {
+    /// Gets the point `Γ`
+    pub const fn gamma(&self) -> &JubJubExtended {
+        &self.gamma
+    }
+
+    /// Gets the challenge `c`
+    pub const fn c(&self) -> &JubJubScalar {
+        &self.c
+    }
+
+    /// Gets the response `s`
+    pub const fn s(&self) -> &JubJubScalar {
+        &self.s
+    }
+}
+
+impl Serializable<96> for VrfProof {
+    type Error = Error;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0u8; Self::SIZE];
+        bytes[..32].copy_from_slice(&JubJubAffine::from(self.gamma).to_bytes());
+        bytes[32..64].copy_from_slice(&self.c.to_bytes());
+        bytes[64..].copy_from_slice(&self.s.to_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        let gamma =
+            JubJubExtended::from(JubJubAffine::from_slice(&bytes[..32])?);
+        let c = JubJubScalar::from_slice(&bytes[32..64])?;
+        let s = JubJubScalar::from_slice(&bytes[64..])?;
+
+        Ok(Self { gamma, c, s })
+    }
+}
+
+/// Computes the output and the proof of the VRF for the secret `x` and the
+/// public key `pk = x·G`
+pub(crate) fn prove(
+    x: &JubJubScalar,
+    pk: &JubJubExtended,
+    input: &[BlsScalar],
+) -> (VrfOutput, VrfProof) {
+    let H = hash_input(pk, input);
+    let gamma = H * x;
+
+    // The nonce is derived deterministically from the secret and the input
+    let [h_u, h_v] = H.to_hash_inputs();
+    let k = truncated::hash(&[NONCE_DOMAIN, BlsScalar::from(*x), h_u, h_v]);
+
+    let c = challenge(pk, &H, &gamma, &(GENERATOR_EXTENDED * k), &(H * k));
+    let s = k + c * x;
+
+    (output(&gamma), VrfProof { gamma, c, s })
+}
+
+/// Verifies the VRF proof for the given public key and input, returning the
+/// output on success
+///
+/// Keys of small order are rejected, as in the key validation of RFC 9381:
+/// anyone can prove for them, and their output is the same for every input.
+pub(crate) fn verify(
+    pk: &JubJubExtended,
+    input: &[BlsScalar],
+    proof: &VrfProof,
+) -> Option<VrfOutput> {
+    if pk.is_small_order().into() {
+        return None;
+    }
+
+    let H = hash_input(pk, input);
+
+    let U = GENERATOR_EXTENDED * proof.s - pk * proof.c;
+    let V = H * proof.s - proof.gamma * proof.c;
+
+    match challenge(pk, &H, &proof.gamma, &U, &V) == proof.c {
+        true => Some(output(&proof.gamma)),
+        false => None,
+    }
+}
+
+fn hash_input(pk: &JubJubExtended, input: &[BlsScalar]) -> JubJubExtended {
+    let [pk_u, pk_v] = pk.to_hash_inputs();
+    let input = sponge::hash(input);
+
+    hash_to_curve(&HASH_DOMAIN, &[pk_u, pk_v, input])
+}
+
+fn challenge(
+    pk: &JubJubExtended,
+    H: &JubJubExtended,
+    gamma: &JubJubExtended,
+    U: &JubJubExtended,
+    V: &JubJubExtended,
+) -> JubJubScalar {
+    let [pk_u, pk_v] = pk.to_hash_inputs();
+    let [h_u, h_v] = H.to_hash_inputs();
+    let [gamma_u, gamma_v] = gamma.to_hash_inputs();
+    let [u_u, u_v] = U.to_hash_inputs();
+    let [v_u, v_v] = V.to_hash_inputs();
+
+    truncated::hash(&[
+        CHALLENGE_DOMAIN,
+        pk_u,
+        pk_v,
+        h_u,
+        h_v,
+        gamma_u,
+        gamma_v,
+        u_u,
+        u_v,
+        v_u,
+        v_v,
+    ])
+}
+
+fn output(gamma: &JubJubExtended) -> VrfOutput {
+    let [u, v] = gamma.mul_by_cofactor().to_hash_inputs();
+
+    VrfOutput(sponge::hash(&[OUTPUT_DOMAIN, u, v]))
+}
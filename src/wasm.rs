@@ -0,0 +1,171 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! JavaScript bindings of the key operations
+//!
+//! Keys are passed as `Uint8Array`s holding their [`Serializable`] encoding,
+//! and failures are thrown as `Error`s. Addresses are exchanged as the hex of
+//! the tagged encoding of the [`PublicSpendKey`], so they are bound to a
+//! network and guarded by a checksum.
+//!
+//! The bindings are packaged with:
+//!
+//! ```text
+//! cargo rustc --lib --release --target wasm32-unknown-unknown \
+//!     --features wasm --crate-type cdylib
+//! wasm-bindgen --target web --out-dir pkg \
+//!     target/wasm32-unknown-unknown/release/dusk_pki.wasm
+//! ```
+
+use crate::{
+    JubJubScalar, KeyError, Network, ProtocolVersion, PublicSpendKey,
+    SecretSpendKey, StealthAddress, ViewKey,
+};
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use dusk_bytes::{DeserializableSlice, Serializable};
+use rand_chacha::rand_core::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use rand_core::OsRng;
+use wasm_bindgen::prelude::*;
+
+/// Generates a random secret spend key
+#[wasm_bindgen(js_name = generateSecretSpendKey)]
+pub fn generate_secret_spend_key() -> Vec<u8> {
+    SecretSpendKey::random(&mut OsRng).to_bytes().to_vec()
+}
+
+/// Generates a secret spend key from a seed of 32 bytes
+///
+/// The key is the same generated from the seed by the C bindings.
+#[wasm_bindgen(js_name = secretSpendKeyFromSeed)]
+pub fn secret_spend_key_from_seed(seed: &[u8]) -> Result<Vec<u8>, JsError> {
+    let seed: [u8; 32] = seed.try_into().map_err(|_| {
+        js_error(KeyError::WrongLength {
+            found: seed.len(),
+            expected: 32,
+        })
+    })?;
+
+    let mut rng = ChaCha20Rng::from_seed(seed);
+    Ok(SecretSpendKey::random(&mut rng).to_bytes().to_vec())
+}
+
+/// Derives the view key of a secret spend key
+#[wasm_bindgen(js_name = viewKey)]
+pub fn view_key(ssk: &[u8]) -> Result<Vec<u8>, JsError> {
+    let ssk = decode::<SecretSpendKey, 64>(ssk)?;
+    Ok(ssk.view_key().to_bytes().to_vec())
+}
+
+/// Derives the public spend key of a secret spend key
+#[wasm_bindgen(js_name = publicSpendKey)]
+pub fn public_spend_key(ssk: &[u8]) -> Result<Vec<u8>, JsError> {
+    let ssk = decode::<SecretSpendKey, 64>(ssk)?;
+    Ok(ssk.public_spend_key().to_bytes().to_vec())
+}
+
+/// Encodes a public spend key as an address for the given network, one of
+/// `mainnet`, `testnet` and `devnet`
+#[wasm_bindgen(js_name = encodeAddress)]
+pub fn encode_address(psk: &[u8], network: &str) -> Result<String, JsError> {
+    let psk = decode::<PublicSpendKey, 64>(psk)?;
+    let network = parse_network(network)?;
+
+    let bytes = psk.encode_tagged(ProtocolVersion::default(), network);
+    Ok(bytes.iter().map(|b| alloc::format!("{b:02x}")).collect())
+}
+
+/// Decodes the public spend key of an address for the given network
+#[wasm_bindgen(js_name = decodeAddress)]
+pub fn decode_address(
+    address: &str,
+    network: &str,
+) -> Result<Vec<u8>, JsError> {
+    let network = parse_network(network)?;
+
+    let bytes = from_hex(address).map_err(js_error)?;
+
+    let (psk, _) =
+        PublicSpendKey::decode_tagged(&bytes, network).map_err(js_error)?;
+    Ok(psk.to_bytes().to_vec())
+}
+
+/// Generates a stealth address for a public spend key
+///
+/// The random scalar `r` is generated if it is not given.
+#[wasm_bindgen(js_name = genStealthAddress)]
+pub fn gen_stealth_address(
+    psk: &[u8],
+    r: Option<Vec<u8>>,
+) -> Result<Vec<u8>, JsError> {
+    let psk = decode::<PublicSpendKey, 64>(psk)?;
+    let r = match r {
+        Some(r) => JubJubScalar::from_slice(&r)
+            .map_err(|e| js_error(KeyError::from(e)))?,
+        None => JubJubScalar::random(&mut OsRng),
+    };
+
+    Ok(psk.gen_stealth_address(&r).to_bytes().to_vec())
+}
+
+/// Checks whether a stealth address is owned by a view key
+#[wasm_bindgen]
+pub fn owns(vk: &[u8], sa: &[u8]) -> Result<bool, JsError> {
+    let vk = decode::<ViewKey, 64>(vk)?;
+    let sa = decode::<StealthAddress, 64>(sa)?;
+
+    Ok(vk.owns(&sa))
+}
+
+/// Derives the one-time secret key of a stealth address
+#[wasm_bindgen(js_name = skR)]
+pub fn sk_r(ssk: &[u8], sa: &[u8]) -> Result<Vec<u8>, JsError> {
+    let ssk = decode::<SecretSpendKey, 64>(ssk)?;
+    let sa = decode::<StealthAddress, 64>(sa)?;
+
+    Ok(ssk.sk_r(&sa).to_bytes().to_vec())
+}
+
+fn decode<T, const N: usize>(bytes: &[u8]) -> Result<T, JsError>
+where
+    T: Serializable<N, Error = KeyError>,
+{
+    T::from_slice(bytes).map_err(js_error)
+}
+
+fn from_hex(s: &str) -> Result<Vec<u8>, KeyError> {
+    let digits = s
+        .char_indices()
+        .map(|(index, ch)| {
+            ch.to_digit(16)
+                .map(|d| d as u8)
+                .ok_or(KeyError::InvalidChar { ch, index })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let bytes = digits.chunks_exact(2);
+    if !bytes.remainder().is_empty() {
+        return Err(KeyError::InvalidData);
+    }
+
+    Ok(bytes.map(|d| d[0] << 4 | d[1]).collect())
+}
+
+fn parse_network(network: &str) -> Result<Network, JsError> {
+    match network {
+        "mainnet" => Ok(Network::Mainnet),
+        "testnet" => Ok(Network::Testnet),
+        "devnet" => Ok(Network::Devnet),
+        _ => Err(JsError::new(&alloc::format!("unknown network {network}"))),
+    }
+}
+
+fn js_error(err: KeyError) -> JsError {
+    JsError::new(&err.to_string())
+}
@@ -0,0 +1,256 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![allow(deprecated)]
+#![allow(non_snake_case)]
+
+use std::sync::mpsc::{channel, Sender};
+use std::thread;
+
+use dusk_bytes::{DeserializableSlice, Serializable};
+use dusk_jubjub::{BlsScalar, JubJubAffine, JubJubExtended, JubJubScalar};
+use dusk_pki::{
+    IntegratedAddress, KeyDerivation, ProtocolVersion, PublicKey,
+    PublicSpendKey, Scanner, SecretKey, SecretSpendKey, SpendAuthority,
+    StealthAddress,
+};
+use rand_core::OsRng;
+
+#[cfg(feature = "alloc")]
+use dusk_pki::ReserveProof;
+
+/// Request sent to the signer, carrying the encoding of its arguments
+enum Request {
+    SharedSecret([u8; 32]),
+    SkR(u8, [u8; StealthAddress::SIZE]),
+    ReserveResponses([u8; 32], [u8; 32], [u8; 32]),
+}
+
+/// Signer keeping the secret spend key in its own thread, and answering the
+/// requests with the encoding of the result, empty if the address is not
+/// owned
+struct RemoteSigner {
+    psk: PublicSpendKey,
+    requests: Sender<(Request, Sender<Vec<u8>>)>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Disconnected;
+
+impl RemoteSigner {
+    fn spawn(ssk: SecretSpendKey) -> Self {
+        let (requests, rx) = channel::<(Request, Sender<Vec<u8>>)>();
+
+        thread::spawn(move || {
+            for (request, reply) in rx {
+                let response = match request {
+                    Request::SharedSecret(R) => {
+                        let R = JubJubAffine::from_bytes(R).unwrap();
+                        let aR = JubJubExtended::from(R) * ssk.a();
+                        JubJubAffine::from(aR).to_bytes().to_vec()
+                    }
+                    Request::SkR(version, sa) => {
+                        let version = ProtocolVersion::from_id(version);
+                        let derivation = KeyDerivation::new(version.unwrap());
+                        let sa = StealthAddress::from_bytes(&sa).unwrap();
+
+                        SpendAuthority::sk_r_with(&ssk, &derivation, &sa)
+                            .unwrap()
+                            .map(|sk_r| sk_r.to_bytes().to_vec())
+                            .unwrap_or_default()
+                    }
+                    Request::ReserveResponses(transcript, R, entropy) => {
+                        let transcript =
+                            BlsScalar::from_bytes(&transcript).unwrap();
+                        let R = JubJubAffine::from_bytes(R).unwrap().into();
+                        let entropy =
+                            JubJubScalar::from_bytes(&entropy).unwrap();
+
+                        let (c, s_a, s_b) = ssk
+                            .reserve_responses(&transcript, &R, &entropy)
+                            .unwrap();
+                        [c, s_a, s_b]
+                            .iter()
+                            .flat_map(|s| s.to_bytes())
+                            .collect()
+                    }
+                };
+                let _ = reply.send(response);
+            }
+        });
+
+        Self {
+            psk: ssk.public_spend_key(),
+            requests,
+        }
+    }
+
+    fn call(&self, request: Request) -> Result<Vec<u8>, Disconnected> {
+        let (reply, response) = channel();
+        self.requests
+            .send((request, reply))
+            .map_err(|_| Disconnected)?;
+        response.recv().map_err(|_| Disconnected)
+    }
+}
+
+impl SpendAuthority for RemoteSigner {
+    type Error = Disconnected;
+
+    fn public_spend_key(&self) -> PublicSpendKey {
+        self.psk
+    }
+
+    fn shared_secret(
+        &self,
+        R: &JubJubExtended,
+    ) -> Result<JubJubExtended, Disconnected> {
+        let R = JubJubAffine::from(R).to_bytes();
+        let aR = self.call(Request::SharedSecret(R))?;
+
+        Ok(JubJubAffine::from_slice(&aR).unwrap().into())
+    }
+
+    fn sk_r_with(
+        &self,
+        derivation: &KeyDerivation,
+        sa: &StealthAddress,
+    ) -> Result<Option<SecretKey>, Disconnected> {
+        let version = derivation.version().id();
+        let sk_r = self.call(Request::SkR(version, sa.to_bytes()))?;
+
+        Ok((!sk_r.is_empty()).then(|| SecretKey::from_slice(&sk_r).unwrap()))
+    }
+
+    fn reserve_responses(
+        &self,
+        transcript: &BlsScalar,
+        R: &JubJubExtended,
+        entropy: &JubJubScalar,
+    ) -> Result<(JubJubScalar, JubJubScalar, JubJubScalar), Disconnected> {
+        let request = Request::ReserveResponses(
+            transcript.to_bytes(),
+            JubJubAffine::from(R).to_bytes(),
+            entropy.to_bytes(),
+        );
+        let responses = self.call(request)?;
+
+        let scalar = |i: usize| {
+            JubJubScalar::from_slice(&responses[32 * i..32 * (i + 1)]).unwrap()
+        };
+        Ok((scalar(0), scalar(1), scalar(2)))
+    }
+}
+
+fn check_authority<S>(authority: &S, ssk: &SecretSpendKey)
+where
+    S: SpendAuthority,
+    S::Error: std::fmt::Debug,
+{
+    let psk = ssk.public_spend_key();
+    assert_eq!(authority.public_spend_key(), psk);
+
+    let r = JubJubScalar::random(&mut OsRng);
+    let sa = psk.gen_stealth_address(&r);
+
+    let sk_r = authority.sk_r(&sa).unwrap().expect("The address is owned");
+    assert_eq!(sk_r, ssk.sk_r(&sa));
+    assert_eq!(PublicKey::from(&sk_r), *sa.pk_r());
+
+    let derivation = KeyDerivation::V1;
+    let sa_v1 = psk.gen_stealth_address_with(&derivation, &r);
+    assert_eq!(
+        authority.sk_r_with(&derivation, &sa_v1).unwrap(),
+        Some(ssk.sk_r_with(&derivation, &sa_v1))
+    );
+    assert_eq!(authority.sk_r(&sa_v1).unwrap(), None);
+
+    let input = [BlsScalar::from(42)];
+    let (output, proof) = authority
+        .vrf_prove(&sa, &input)
+        .unwrap()
+        .expect("The address is owned");
+    assert_eq!(sa.pk_r().vrf_verify(&input, &proof), Some(output));
+
+    #[cfg(feature = "alloc")]
+    {
+        let sas = [
+            sa,
+            psk.gen_stealth_address(&JubJubScalar::random(&mut OsRng)),
+        ];
+        let reserve = ReserveProof::new(authority, &sas, &mut OsRng)
+            .unwrap()
+            .expect("The addresses are owned");
+        assert!(reserve.verify(&psk, &sas));
+    }
+
+    let address = IntegratedAddress::new(psk, [3; 8]);
+    let output = address.gen_stealth_address(&r);
+    assert_eq!(authority.decrypt_payment_id(&output).unwrap(), Some([3; 8]));
+
+    let foreign = SecretSpendKey::random(&mut OsRng).public_spend_key();
+    let foreign_sa = foreign.gen_stealth_address(&r);
+    assert_eq!(authority.sk_r(&foreign_sa).unwrap(), None);
+    assert_eq!(authority.vrf_prove(&foreign_sa, &input).unwrap(), None);
+    #[cfg(feature = "alloc")]
+    assert_eq!(
+        ReserveProof::new(authority, &[sa, foreign_sa], &mut OsRng).unwrap(),
+        None
+    );
+
+    let output =
+        IntegratedAddress::new(foreign, [3; 8]).gen_stealth_address(&r);
+    assert_eq!(authority.decrypt_payment_id(&output).unwrap(), None);
+}
+
+#[test]
+fn in_memory_authority() {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    check_authority(&ssk, &ssk);
+}
+
+#[test]
+fn remote_authority() {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let signer = RemoteSigner::spawn(ssk);
+    check_authority(&signer, &ssk);
+}
+
+#[test]
+fn scanned_outputs_are_spent_remotely() {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let signer = RemoteSigner::spawn(ssk);
+
+    let sa = ssk
+        .public_spend_key()
+        .gen_stealth_address(&JubJubScalar::random(&mut OsRng));
+
+    let scanner = Scanner::new(ssk.view_key(), 0);
+    let output = scanner
+        .outputs(0, &[0; 32], &[sa])
+        .unwrap()
+        .next()
+        .expect("The output should be owned");
+
+    assert_eq!(output.sk_r(&signer, &sa), Ok(Some(ssk.sk_r(&sa))));
+}
+
+#[test]
+fn remote_failures_are_reported() {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let (requests, rx) = channel();
+    drop(rx);
+
+    let signer = RemoteSigner {
+        psk: ssk.public_spend_key(),
+        requests,
+    };
+
+    let sa: StealthAddress = ssk
+        .public_spend_key()
+        .gen_stealth_address(&JubJubScalar::random(&mut OsRng));
+    assert_eq!(signer.sk_r(&sa), Err(Disconnected));
+}
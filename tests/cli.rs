@@ -0,0 +1,175 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "cli")]
+#![allow(deprecated)]
+
+use std::process::{Command, Output};
+
+use dusk_bytes::ParseHexStr;
+use dusk_pki::{
+    KeyDerivation, Network, ProtocolVersion, PublicSpendKey, SecretKey,
+    SecretSpendKey, StealthAddress, ViewKey,
+};
+use rand_core::OsRng;
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_dusk-pki"))
+        .args(args)
+        .output()
+        .expect("Failed to run the binary")
+}
+
+fn stdout(args: &[&str]) -> String {
+    let output = run(args);
+    assert!(output.status.success(), "{args:?} failed");
+
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn line<'a>(out: &'a str, label: &str) -> &'a str {
+    out.lines()
+        .find_map(|l| l.strip_prefix(label))
+        .expect("Missing output line")
+}
+
+#[test]
+fn keygen_and_derive() {
+    let out = stdout(&["keygen"]);
+    let ssk = SecretSpendKey::from_hex_str(out.trim()).expect("Valid key");
+
+    let hex = format!("{ssk:x}");
+    let out = stdout(&["derive", &hex]);
+    assert_eq!(line(&out, "view key: "), format!("{:x}", ssk.view_key()));
+    assert_eq!(
+        line(&out, "public spend key: "),
+        format!("{:x}", ssk.public_spend_key())
+    );
+
+    // Tagged output
+    let out = stdout(&["derive", &hex, "--network", "testnet"]);
+    let bytes = hex_bytes(line(&out, "view key: "));
+    let (vk, version) = ViewKey::decode_tagged(&bytes, Network::Testnet)
+        .expect("Valid tagged key");
+    assert_eq!(vk, ssk.view_key());
+    assert_eq!(version, ProtocolVersion::Legacy);
+}
+
+#[test]
+fn stealth_owns_and_sk_r() {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let psk = format!("{:x}", ssk.public_spend_key());
+    let vk = format!("{:x}", ssk.view_key());
+    let ssk_hex = format!("{ssk:x}");
+
+    let sa = stdout(&["stealth", &psk]);
+    let sa = sa.trim();
+    let address = StealthAddress::from_hex_str(sa).expect("Valid address");
+    assert!(ssk.view_key().owns(&address));
+
+    assert_eq!(stdout(&["owns", &vk, sa]).trim(), "owned");
+
+    let other = SecretSpendKey::random(&mut OsRng).view_key();
+    let output = run(&["owns", &format!("{other:x}"), sa]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "not owned");
+
+    let sk_r = stdout(&["sk-r", &ssk_hex, sa]);
+    let sk_r = SecretKey::from_hex_str(sk_r.trim()).expect("Valid key");
+    assert_eq!(sk_r, ssk.sk_r(&address));
+
+    let other = SecretSpendKey::random(&mut OsRng);
+    let output = run(&["sk-r", &format!("{other:x}"), sa]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "not owned");
+
+    // Keys in the tagged encoding are accepted as input, if encoded for the
+    // network and the protocol version of the command
+    let tagged = ssk
+        .public_spend_key()
+        .encode_tagged(ProtocolVersion::V1, Network::Devnet);
+    let tagged = hex(&tagged);
+    let code = |args: &[&str]| run(args).status.code();
+
+    assert_eq!(code(&["stealth", &tagged, "--protocol", "v1"]), Some(2));
+    assert_eq!(code(&["stealth", &tagged, "--network", "devnet"]), Some(2));
+
+    let sa = stdout(&[
+        "stealth",
+        &tagged,
+        "--network",
+        "devnet",
+        "--protocol",
+        "v1",
+    ]);
+    let sa = sa.trim();
+    let (address, _) =
+        StealthAddress::decode_tagged(&hex_bytes(sa), Network::Devnet)
+            .expect("Valid tagged address");
+    let derivation = KeyDerivation::new(ProtocolVersion::V1);
+    assert!(ssk.view_key().owns_with(&derivation, &address));
+
+    assert_eq!(code(&["owns", &vk, sa]), Some(2));
+    assert_eq!(code(&["owns", &vk, sa, "--network", "devnet"]), Some(2));
+    assert_eq!(
+        code(&["owns", &vk, sa, "--network", "devnet", "--protocol", "v1"]),
+        Some(0)
+    );
+}
+
+#[test]
+fn deterministic_stealth() {
+    let psk = SecretSpendKey::random(&mut OsRng).public_spend_key();
+    let r = dusk_jubjub::JubJubScalar::from(42u64);
+
+    let out =
+        stdout(&["stealth", &format!("{psk:x}"), "-r", &hex(&r.to_bytes())]);
+    assert_eq!(out.trim(), format!("{:x}", psk.gen_stealth_address(&r)));
+}
+
+#[test]
+fn inspect() {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let psk = ssk.public_spend_key();
+
+    let tagged = psk.encode_tagged(ProtocolVersion::V1, Network::Mainnet);
+    let out = stdout(&["inspect", &hex(&tagged)]);
+    assert_eq!(line(&out, "encoding: "), "tagged");
+    assert_eq!(line(&out, "type: "), "PublicSpendKey");
+    assert_eq!(line(&out, "version: "), "V1");
+    assert_eq!(line(&out, "network: "), "Mainnet");
+    assert_eq!(line(&out, "key: "), format!("{psk:x}"));
+
+    let out = stdout(&["inspect", &format!("{:x}", ssk.view_key())]);
+    assert_eq!(line(&out, "encoding: "), "plain");
+    assert!(line(&out, "type: ").contains("ViewKey"));
+
+    let pem = include_str!("fixtures/public_spend_key.pem");
+    let out = stdout(&["inspect", pem]);
+    assert_eq!(line(&out, "type: "), "PublicSpendKey");
+
+    let uri = format!("dusk:{psk:x}?amount=10");
+    let out = stdout(&["inspect", &uri]);
+    assert_eq!(line(&out, "amount: "), "10");
+
+    // Corrupted checksum and invalid keys are reported
+    let mut corrupted = tagged;
+    corrupted[PublicSpendKey::TAGGED_SIZE - 1] ^= 1;
+    assert_eq!(run(&["inspect", &hex(&corrupted)]).status.code(), Some(2));
+    assert_eq!(run(&["inspect", &"ff".repeat(32)]).status.code(), Some(2));
+    assert_eq!(run(&["derive", "00"]).status.code(), Some(2));
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn hex_bytes(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
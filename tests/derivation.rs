@@ -0,0 +1,86 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![allow(deprecated)]
+
+use dusk_jubjub::{JubJubScalar, GENERATOR_EXTENDED};
+use dusk_pki::{
+    DerivationDomain, KeyDerivation, ProtocolVersion, SecretSpendKey,
+};
+use dusk_poseidon::sponge::truncated;
+use rand_core::OsRng;
+
+#[test]
+fn legacy_derivation_is_unchanged() {
+    let p = GENERATOR_EXTENDED * JubJubScalar::random(&mut OsRng);
+
+    assert_eq!(KeyDerivation::default(), KeyDerivation::LEGACY);
+    assert_eq!(
+        KeyDerivation::LEGACY.stealth_key(&p),
+        truncated::hash(&p.to_hash_inputs())
+    );
+
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let psk = ssk.public_spend_key();
+    let vk = ssk.view_key();
+    let r = JubJubScalar::random(&mut OsRng);
+
+    let sa = psk.gen_stealth_address(&r);
+
+    assert_eq!(sa, psk.gen_stealth_address_with(&KeyDerivation::LEGACY, &r));
+    assert!(vk.owns_with(&KeyDerivation::LEGACY, &sa));
+    assert_eq!(ssk.sk_r(&sa), ssk.sk_r_with(&KeyDerivation::LEGACY, &sa));
+}
+
+#[test]
+fn domains_are_separated() {
+    let p = GENERATOR_EXTENDED * JubJubScalar::random(&mut OsRng);
+
+    let domains = [
+        DerivationDomain::StealthKey,
+        DerivationDomain::ViewTag,
+        DerivationDomain::EncryptionKey,
+        DerivationDomain::SubaddressTweak,
+    ];
+
+    for derivation in [KeyDerivation::LEGACY, KeyDerivation::V1] {
+        for (i, a) in domains.iter().enumerate() {
+            for b in &domains[i + 1..] {
+                assert_ne!(derivation.hash(*a, &p), derivation.hash(*b, &p));
+            }
+        }
+    }
+
+    for domain in domains {
+        assert_ne!(
+            KeyDerivation::LEGACY.hash(domain, &p),
+            KeyDerivation::V1.hash(domain, &p)
+        );
+    }
+}
+
+#[test]
+fn versioned_stealth_addresses() {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let psk = ssk.public_spend_key();
+    let vk = ssk.view_key();
+    let r = JubJubScalar::random(&mut OsRng);
+
+    let v1 = KeyDerivation::new(ProtocolVersion::V1);
+    let sa = psk.gen_stealth_address_with(&v1, &r);
+
+    assert_ne!(sa, psk.gen_stealth_address(&r));
+    assert!(vk.owns_with(&v1, &sa));
+    assert!(!vk.owns(&sa));
+
+    let sk_r = ssk.sk_r_with(&v1, &sa);
+    assert_eq!(sa.address(), &(GENERATOR_EXTENDED * sk_r.as_ref()));
+
+    for version in [ProtocolVersion::Legacy, ProtocolVersion::V1] {
+        assert_eq!(ProtocolVersion::from_id(version.id()), Some(version));
+    }
+    assert_eq!(ProtocolVersion::from_id(2), None);
+}
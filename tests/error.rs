@@ -0,0 +1,125 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![allow(deprecated)]
+
+use dusk_bytes::{DeserializableSlice, Error, ParseHexStr, Serializable};
+use dusk_jubjub::{BlsScalar, JubJubAffine, JubJubScalar};
+use dusk_pki::{
+    KeyError, PublicKey, PublicSpendKey, SecretKey, SecretSpendKey,
+    StealthAddress, ViewKey,
+};
+use rand_core::OsRng;
+
+#[test]
+fn decoding_errors() {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let psk = ssk.public_spend_key();
+    let vk = ssk.view_key();
+
+    // `v = 2` has no matching `u` on the curve
+    let mut off_curve = [0u8; 32];
+    off_curve[0] = 2;
+
+    assert_eq!(
+        SecretKey::from_bytes(&[0xff; 32]),
+        Err(KeyError::InvalidScalar)
+    );
+    assert_eq!(
+        PublicKey::from_bytes(&off_curve),
+        Err(KeyError::OffCurvePoint)
+    );
+
+    let mut bytes = vk.to_bytes();
+    bytes[..32].copy_from_slice(&[0xff; 32]);
+    assert_eq!(ViewKey::from_bytes(&bytes), Err(KeyError::InvalidScalar));
+
+    let mut bytes = psk
+        .gen_stealth_address(&JubJubScalar::random(&mut OsRng))
+        .to_bytes();
+    bytes[..32].copy_from_slice(&off_curve);
+    assert_eq!(
+        StealthAddress::from_bytes(&bytes),
+        Err(KeyError::OffCurvePoint)
+    );
+
+    assert_eq!(
+        SecretSpendKey::from_slice(&ssk.to_bytes()[..63]),
+        Err(KeyError::WrongLength {
+            found: 63,
+            expected: 64
+        })
+    );
+    assert_eq!(
+        PublicKey::from_hex_str(&"z".repeat(64)),
+        Err(KeyError::InvalidChar { ch: 'z', index: 0 })
+    );
+}
+
+#[test]
+fn small_order_points() {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let psk = ssk.public_spend_key();
+    let vk = ssk.view_key();
+    let sa = psk.gen_stealth_address(&JubJubScalar::random(&mut OsRng));
+
+    // Point of order two `(0, -1)`
+    let small_order =
+        JubJubAffine::from_raw_unchecked(BlsScalar::zero(), -BlsScalar::one())
+            .to_bytes();
+
+    assert_eq!(psk.check_small_order(), Ok(()));
+    assert_eq!(vk.check_small_order(), Ok(()));
+    assert_eq!(sa.check_small_order(), Ok(()));
+
+    // Points of small order are decoded, and rejected on request
+    let pk = PublicKey::from_bytes(&small_order).expect("Valid point");
+    assert_eq!(pk.check_small_order(), Err(KeyError::SmallOrderPoint));
+
+    let pk = PublicKey::default();
+    assert_eq!(PublicKey::from_bytes(&pk.to_bytes()), Ok(pk));
+    assert_eq!(pk.check_small_order(), Err(KeyError::SmallOrderPoint));
+
+    let mut bytes = psk.to_bytes();
+    bytes[32..].copy_from_slice(&small_order);
+    let psk = PublicSpendKey::from_bytes(&bytes).expect("Valid points");
+    assert_eq!(psk.check_small_order(), Err(KeyError::SmallOrderPoint));
+
+    let mut bytes = vk.to_bytes();
+    bytes[32..].copy_from_slice(&small_order);
+    let vk = ViewKey::from_bytes(&bytes).expect("Valid point");
+    assert_eq!(vk.check_small_order(), Err(KeyError::SmallOrderPoint));
+
+    let sa = StealthAddress::default();
+    assert_eq!(StealthAddress::from_bytes(&sa.to_bytes()), Ok(sa));
+    assert_eq!(sa.check_small_order(), Err(KeyError::SmallOrderPoint));
+}
+
+#[test]
+fn dusk_bytes_conversions() {
+    let errors = [
+        Error::InvalidData,
+        Error::BadLength {
+            found: 1,
+            expected: 32,
+        },
+        Error::InvalidChar { ch: 'x', index: 3 },
+    ];
+
+    for err in errors {
+        assert_eq!(Error::from(KeyError::from(err)), err);
+    }
+
+    assert_eq!(Error::from(KeyError::SmallOrderPoint), Error::InvalidData);
+    assert_eq!(Error::from(KeyError::BadChecksum), Error::InvalidData);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn std_error() {
+    let err: Box<dyn std::error::Error> = Box::new(KeyError::OffCurvePoint);
+    assert_eq!(err.to_string(), "point not on the curve");
+}
@@ -0,0 +1,81 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "ffi")]
+#![allow(deprecated)]
+
+use core::ptr;
+
+use dusk_bytes::Serializable;
+use dusk_jubjub::JubJubScalar;
+use dusk_pki::ffi::{self, Status};
+use dusk_pki::{PublicKey, SecretSpendKey, StealthAddress};
+use rand_core::SeedableRng;
+
+#[test]
+fn header_is_up_to_date() {
+    let generated = include_str!(concat!(env!("OUT_DIR"), "/dusk_pki.h"));
+    let committed = include_str!("../include/dusk_pki.h");
+
+    assert!(
+        generated == committed,
+        "include/dusk_pki.h is outdated, copy it from {}",
+        env!("OUT_DIR")
+    );
+}
+
+#[test]
+fn bindings_match_the_rust_api() {
+    let seed = [7u8; ffi::SEED_SIZE];
+    let mut ssk = [0u8; ffi::SECRET_SPEND_KEY_SIZE];
+    let mut psk = [0u8; ffi::PUBLIC_SPEND_KEY_SIZE];
+    let mut sa = [0u8; ffi::STEALTH_ADDRESS_SIZE];
+    let mut sk_r = [0u8; ffi::SECRET_KEY_SIZE];
+
+    unsafe {
+        let status =
+            ffi::dusk_pki_ssk_from_seed(seed.as_ptr(), ssk.as_mut_ptr());
+        assert_eq!(status, Status::Ok);
+
+        let status =
+            ffi::dusk_pki_public_spend_key(ssk.as_ptr(), psk.as_mut_ptr());
+        assert_eq!(status, Status::Ok);
+    }
+
+    let mut rng = rand_chacha::ChaCha20Rng::from_seed(seed);
+    let key = SecretSpendKey::random(&mut rng);
+    assert_eq!(ssk, key.to_bytes());
+    assert_eq!(psk, key.public_spend_key().to_bytes());
+
+    let r = JubJubScalar::from(1234u64);
+    unsafe {
+        let status = ffi::dusk_pki_gen_stealth_address(
+            psk.as_ptr(),
+            r.to_bytes().as_ptr(),
+            sa.as_mut_ptr(),
+        );
+        assert_eq!(status, Status::Ok);
+
+        let status =
+            ffi::dusk_pki_sk_r(ssk.as_ptr(), sa.as_ptr(), sk_r.as_mut_ptr());
+        assert_eq!(status, Status::Ok);
+    }
+
+    let address = key.public_spend_key().gen_stealth_address(&r);
+    assert_eq!(sa, address.to_bytes());
+    assert_eq!(
+        StealthAddress::from_bytes(&sa).unwrap().pk_r(),
+        &PublicKey::from(&key.sk_r(&address))
+    );
+    assert_eq!(sk_r, key.sk_r(&address).to_bytes());
+
+    // Outputs are left untouched on failure
+    let mut vk = [0u8; ffi::VIEW_KEY_SIZE];
+    let status =
+        unsafe { ffi::dusk_pki_view_key(ptr::null(), vk.as_mut_ptr()) };
+    assert_eq!(status, Status::NullPointer);
+    assert_eq!(vk, [0u8; ffi::VIEW_KEY_SIZE]);
+}
@@ -0,0 +1,18 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+/// Compiles the C harness against the committed header, which the test suite
+/// checks to be up to date
+fn main() {
+    println!("cargo:rerun-if-changed=harness.c");
+    println!("cargo:rerun-if-changed=../../include/dusk_pki.h");
+
+    cc::Build::new()
+        .file("harness.c")
+        .include("../../include")
+        .warnings_into_errors(true)
+        .compile("dusk_pki_harness");
+}
@@ -0,0 +1,84 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ *
+ * Copyright (c) DUSK NETWORK. All rights reserved. */
+
+/* Exercises the C bindings through the generated header. Returns 0 on
+ * success, or the line of the first failed check. */
+
+#include <stdbool.h>
+#include <stdint.h>
+#include <string.h>
+
+#include "dusk_pki.h"
+
+#define CHECK(cond)                                                            \
+  do {                                                                         \
+    if (!(cond)) {                                                             \
+      return __LINE__;                                                         \
+    }                                                                          \
+  } while (0)
+
+int dusk_pki_harness(void) {
+  uint8_t seed[DUSK_PKI_SEED_SIZE];
+  uint8_t ssk[DUSK_PKI_SECRET_SPEND_KEY_SIZE];
+  uint8_t other_ssk[DUSK_PKI_SECRET_SPEND_KEY_SIZE];
+  uint8_t vk[DUSK_PKI_VIEW_KEY_SIZE];
+  uint8_t other_vk[DUSK_PKI_VIEW_KEY_SIZE];
+  uint8_t psk[DUSK_PKI_PUBLIC_SPEND_KEY_SIZE];
+  uint8_t wide[2 * DUSK_PKI_SCALAR_SIZE];
+  uint8_t r[DUSK_PKI_SCALAR_SIZE];
+  uint8_t sa[DUSK_PKI_STEALTH_ADDRESS_SIZE];
+  uint8_t sk_r[DUSK_PKI_SECRET_KEY_SIZE];
+  bool owned = false;
+
+  /* Key generation is deterministic */
+  memset(seed, 0x2a, sizeof(seed));
+  CHECK(dusk_pki_ssk_from_seed(seed, ssk) == DUSK_PKI_STATUS_OK);
+  CHECK(dusk_pki_ssk_from_seed(seed, other_ssk) == DUSK_PKI_STATUS_OK);
+  CHECK(memcmp(ssk, other_ssk, sizeof(ssk)) == 0);
+
+  seed[0] ^= 1;
+  CHECK(dusk_pki_ssk_from_seed(seed, other_ssk) == DUSK_PKI_STATUS_OK);
+  CHECK(memcmp(ssk, other_ssk, sizeof(ssk)) != 0);
+
+  CHECK(dusk_pki_view_key(ssk, vk) == DUSK_PKI_STATUS_OK);
+  CHECK(dusk_pki_view_key(other_ssk, other_vk) == DUSK_PKI_STATUS_OK);
+  CHECK(dusk_pki_public_spend_key(ssk, psk) == DUSK_PKI_STATUS_OK);
+
+  /* The view key shares `a` with the secret spend key, and `b·G` with the
+   * public spend key */
+  CHECK(memcmp(vk, ssk, 32) == 0);
+  CHECK(memcmp(vk + 32, psk + 32, 32) == 0);
+
+  memset(wide, 0x5c, sizeof(wide));
+  CHECK(dusk_pki_scalar_from_wide(wide, r) == DUSK_PKI_STATUS_OK);
+  CHECK(dusk_pki_gen_stealth_address(psk, r, sa) == DUSK_PKI_STATUS_OK);
+
+  CHECK(dusk_pki_owns(vk, sa, &owned) == DUSK_PKI_STATUS_OK);
+  CHECK(owned);
+  CHECK(dusk_pki_owns(other_vk, sa, &owned) == DUSK_PKI_STATUS_OK);
+  CHECK(!owned);
+
+  CHECK(dusk_pki_sk_r(ssk, sa, sk_r) == DUSK_PKI_STATUS_OK);
+
+  /* Errors */
+  CHECK(dusk_pki_view_key(NULL, vk) == DUSK_PKI_STATUS_NULL_POINTER);
+  CHECK(dusk_pki_view_key(ssk, NULL) == DUSK_PKI_STATUS_NULL_POINTER);
+  CHECK(dusk_pki_owns(vk, sa, NULL) == DUSK_PKI_STATUS_NULL_POINTER);
+
+  memset(r, 0xff, sizeof(r));
+  CHECK(dusk_pki_gen_stealth_address(psk, r, sa) ==
+        DUSK_PKI_STATUS_INVALID_SCALAR);
+
+  memset(other_ssk, 0xff, sizeof(other_ssk));
+  CHECK(dusk_pki_public_spend_key(other_ssk, psk) ==
+        DUSK_PKI_STATUS_INVALID_SCALAR);
+
+  memset(psk, 0xff, sizeof(psk));
+  CHECK(dusk_pki_gen_stealth_address(psk, sk_r, sa) ==
+        DUSK_PKI_STATUS_OFF_CURVE_POINT);
+
+  return 0;
+}
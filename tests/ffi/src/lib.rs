@@ -0,0 +1,19 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! C harness exercising the bindings of `dusk-pki` through the generated
+//! header
+//!
+//! The harness calls the bindings without linking them, so it is only built
+//! as a dev-dependency of `dusk-pki`, whose test suite provides them.
+
+#![no_std]
+
+extern "C" {
+    /// Runs the checks of `harness.c`, returning 0 on success or the line of
+    /// the first failed check
+    pub fn dusk_pki_harness() -> i32;
+}
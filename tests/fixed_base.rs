@@ -0,0 +1,137 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![allow(deprecated)]
+#![allow(non_snake_case)]
+
+use std::fmt::Write;
+
+use dusk_jubjub::{
+    JubJubAffine, JubJubExtended, JubJubScalar, GENERATOR_EXTENDED,
+};
+use dusk_pki::{
+    KeyDerivation, PublicSpendKey, SecretSpendKey, SpendAuthority, ViewKey,
+};
+use rand_core::OsRng;
+
+const TABLE: &str = "src/fixed_base/table.rs";
+
+/// Generates the coordinates of the multiples `d · 16^i · G`
+fn generate_table() -> String {
+    let mut table = String::from(
+        "// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Affine coordinates `[u, v]` of the multiples `d · 16^i · G`, for every
+//! window `i` and non-zero digit `d`
+//!
+//! Generated by `tests/fixed_base.rs`, do not edit.
+
+// Only read when building the table at compile time
+#[allow(clippy::large_const_arrays)]
+#[rustfmt::skip]
+pub(super) const MULTIPLES: [[[u64; 4]; 2]; 945] = [
+",
+    );
+
+    let mut base = GENERATOR_EXTENDED;
+    for _ in 0..63 {
+        let mut p = base;
+        for _ in 1..16 {
+            let p_affine = JubJubAffine::from(p);
+            let [u, v] = [p_affine.get_u(), p_affine.get_v()].map(|c| {
+                let bytes = c.to_bytes();
+                bytes
+                    .chunks_exact(8)
+                    .map(|limb| {
+                        let limb = u64::from_le_bytes(limb.try_into().unwrap());
+                        format!("{limb:#018x}")
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            });
+            writeln!(table, "    [[{u}], [{v}]],").unwrap();
+
+            p += base;
+        }
+
+        // The base of the next window is `16 · base`
+        base = p;
+    }
+
+    table.push_str("];\n");
+    table
+}
+
+#[test]
+fn table_is_up_to_date() {
+    let generated = generate_table();
+    let committed = include_str!("../src/fixed_base/table.rs");
+
+    if generated != committed && std::env::var_os("UPDATE_TABLE").is_some() {
+        let path =
+            concat!(env!("CARGO_MANIFEST_DIR"), "/src/fixed_base/table.rs");
+        std::fs::write(path, generated).expect("Failed to write the table");
+        return;
+    }
+
+    assert!(
+        generated == committed,
+        "{TABLE} is outdated, regenerate it with UPDATE_TABLE=1"
+    );
+}
+
+fn scalars() -> Vec<JubJubScalar> {
+    let mut scalars = vec![
+        JubJubScalar::zero(),
+        JubJubScalar::one(),
+        -JubJubScalar::one(),
+        JubJubScalar::from(16u64),
+        JubJubScalar::from(u64::MAX),
+    ];
+    scalars.extend((0..16).map(|_| JubJubScalar::random(&mut OsRng)));
+    scalars
+}
+
+#[test]
+fn keys_match_the_ladder() {
+    let G = GENERATOR_EXTENDED;
+
+    for a in scalars() {
+        let b = JubJubScalar::random(&mut OsRng);
+        let ssk = SecretSpendKey::new(a, b);
+
+        let psk = ssk.public_spend_key();
+        assert_eq!(psk, PublicSpendKey::new(G * a, G * b));
+        assert_eq!(ssk.view_key(), ViewKey::new(a, G * b));
+        assert_eq!(ssk.view_key().public_spend_key(), psk);
+        assert_eq!(SpendAuthority::public_spend_key(&ssk), psk);
+    }
+}
+
+#[test]
+fn stealth_addresses_match_the_ladder() {
+    let G = GENERATOR_EXTENDED;
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let psk = ssk.public_spend_key();
+    let vk = ssk.view_key();
+
+    for derivation in [KeyDerivation::LEGACY, KeyDerivation::V1] {
+        for r in scalars() {
+            let sa = psk.gen_stealth_address_with(&derivation, &r);
+
+            let R: JubJubExtended = G * r;
+            let pk_r = G * derivation.stealth_key(&(psk.A() * r)) + psk.B();
+            assert_eq!(sa.R(), &R);
+            assert_eq!(sa.address(), &pk_r);
+
+            assert!(vk.owns_with(&derivation, &sa));
+        }
+    }
+}
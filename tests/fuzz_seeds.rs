@@ -0,0 +1,130 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "rkyv-impl")]
+#![allow(deprecated)]
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use dusk_bytes::Serializable;
+use dusk_jubjub::JubJubScalar;
+use dusk_pki::{
+    Compact, PublicKey, PublicSpendKey, SecretKey, SecretSpendKey,
+    StealthAddress,
+};
+use rand_chacha::rand_core::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use rkyv::ser::serializers::AllocSerializer;
+use rkyv::{Archive, Deserialize, Serialize};
+
+const SEEDS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fuzz/seeds");
+
+/// Same layout of the `CompactKeys` of the fuzz crate
+#[derive(Archive, Serialize, Deserialize)]
+#[archive(check_bytes)]
+struct CompactKeys {
+    #[with(Compact)]
+    pk: PublicKey,
+    #[with(Compact)]
+    psk: PublicSpendKey,
+    #[with(Compact)]
+    sa: StealthAddress,
+}
+
+fn archive<T: rkyv::Serialize<AllocSerializer<256>>>(value: &T) -> Vec<u8> {
+    rkyv::to_bytes::<_, 256>(value)
+        .expect("Failed to archive")
+        .to_vec()
+}
+
+/// Generates the seeds of every fuzz target, by their path in the seeds
+/// directory
+///
+/// The decoders are seeded with valid encodings and their hex, and the
+/// archive target with a valid archive of every type, preceded by the byte
+/// selecting the type.
+fn generate_seeds() -> BTreeMap<String, Vec<u8>> {
+    let mut seeds = BTreeMap::new();
+
+    for i in 0..2 {
+        let mut rng = ChaCha20Rng::seed_from_u64(i);
+
+        let sk = SecretKey::random(&mut rng);
+        let pk = PublicKey::from(&sk);
+        let ssk = SecretSpendKey::random(&mut rng);
+        let psk = ssk.public_spend_key();
+        let vk = ssk.view_key();
+        let sa = psk.gen_stealth_address(&JubJubScalar::random(&mut rng));
+
+        let mut add = |target: &str, bytes: &[u8]| {
+            let hex: String =
+                bytes.iter().map(|b| format!("{b:02x}")).collect();
+            seeds.insert(format!("decode_{target}/valid-{i}"), bytes.to_vec());
+            seeds.insert(format!("decode_{target}/hex-{i}"), hex.into_bytes());
+        };
+        add("secret_key", &sk.to_bytes());
+        add("public_key", &pk.to_bytes());
+        add("secret_spend_key", &ssk.to_bytes());
+        add("public_spend_key", &psk.to_bytes());
+        add("view_key", &vk.to_bytes());
+        add("stealth_address", &sa.to_bytes());
+
+        let archives = [
+            ("secret_key", archive(&sk)),
+            ("public_key", archive(&pk)),
+            ("secret_spend_key", archive(&ssk)),
+            ("public_spend_key", archive(&psk)),
+            ("view_key", archive(&vk)),
+            ("stealth_address", archive(&sa)),
+            ("compact_keys", archive(&CompactKeys { pk, psk, sa })),
+        ];
+        for (selector, (name, bytes)) in archives.into_iter().enumerate() {
+            let mut seed = vec![selector as u8];
+            seed.extend_from_slice(&bytes);
+            seeds.insert(format!("archive/{name}-{i}"), seed);
+        }
+    }
+
+    seeds
+}
+
+fn read_seeds(dir: &Path) -> BTreeMap<String, Vec<u8>> {
+    let mut seeds = BTreeMap::new();
+
+    for target in fs::read_dir(dir).expect("Failed to read the seeds") {
+        let target = target.unwrap().path();
+        for seed in fs::read_dir(&target).unwrap() {
+            let seed = seed.unwrap().path();
+            let name = seed.strip_prefix(dir).unwrap().to_str().unwrap();
+            seeds.insert(name.to_string(), fs::read(&seed).unwrap());
+        }
+    }
+
+    seeds
+}
+
+#[test]
+fn seeds_are_up_to_date() {
+    let generated = generate_seeds();
+    let dir = Path::new(SEEDS);
+
+    if std::env::var_os("UPDATE_SEEDS").is_some() {
+        let _ = fs::remove_dir_all(dir);
+        for (name, bytes) in &generated {
+            let path = dir.join(name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, bytes).expect("Failed to write the seed");
+        }
+        return;
+    }
+
+    assert!(
+        read_seeds(dir) == generated,
+        "fuzz/seeds is outdated, regenerate it with UPDATE_SEEDS=1"
+    );
+}
@@ -0,0 +1,146 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "plonk")]
+#![allow(deprecated)]
+#![allow(non_snake_case)]
+
+use dusk_jubjub::GENERATOR_EXTENDED;
+use dusk_pki::gadget::{self, WitnessStealthAddress};
+use dusk_pki::{Nullifier, SecretKey, SecretSpendKey, StealthAddress};
+use dusk_plonk::prelude::*;
+use rand_core::OsRng;
+
+struct StealthCircuit {
+    ssk: SecretSpendKey,
+    r: JubJubScalar,
+    sa: StealthAddress,
+}
+
+impl Default for StealthCircuit {
+    fn default() -> Self {
+        let ssk = SecretSpendKey::new(JubJubScalar::one(), JubJubScalar::one());
+        let r = JubJubScalar::one();
+        let sa = ssk.public_spend_key().gen_stealth_address(&r);
+
+        Self { ssk, r, sa }
+    }
+}
+
+impl Circuit for StealthCircuit {
+    fn circuit<C: Composer>(&self, composer: &mut C) -> Result<(), Error> {
+        let psk = self.ssk.public_spend_key();
+
+        let (A, B) = gadget::append_public_spend_key(composer, &psk);
+        let (a, b) = gadget::append_secret_spend_key(composer, &self.ssk);
+        let r = composer.append_witness(self.r);
+
+        // The stealth address generated in circuit matches the public one
+        let sa = gadget::gen_stealth_address(composer, A, B, r)?;
+        let public = WitnessStealthAddress::append_public(composer, &self.sa);
+
+        composer.assert_equal_point(sa.R, public.R);
+        composer.assert_equal_point(sa.pk_r, public.pk_r);
+
+        // The view key owns it
+        gadget::owns(composer, a, B, &public)?;
+
+        // The one-time secret key opens it
+        let sk_r = gadget::sk_r(composer, a, b, public.R);
+        let pk_r =
+            composer.component_mul_generator(sk_r, GENERATOR_EXTENDED)?;
+
+        composer.assert_equal_point(pk_r, public.pk_r);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn stealth_address_gadgets() {
+    let pp = PublicParameters::setup(1 << 14, &mut OsRng).unwrap();
+    let (prover, verifier) =
+        Compiler::compile::<StealthCircuit>(&pp, b"dusk-pki").unwrap();
+
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let r = JubJubScalar::random(&mut OsRng);
+    let sa = ssk.public_spend_key().gen_stealth_address(&r);
+
+    let circuit = StealthCircuit { ssk, r, sa };
+    let (proof, pi) = prover.prove(&mut OsRng, &circuit).unwrap();
+
+    verifier.verify(&proof, &pi).expect("Proof should verify");
+
+    let wrong = StealthCircuit {
+        ssk: SecretSpendKey::random(&mut OsRng),
+        r,
+        sa,
+    };
+    assert!(prover.prove(&mut OsRng, &wrong).is_err());
+}
+
+#[derive(Default)]
+struct NullifierCircuit {
+    sk_r: JubJubScalar,
+    pos: u64,
+}
+
+impl Circuit for NullifierCircuit {
+    fn circuit<C: Composer>(&self, composer: &mut C) -> Result<(), Error> {
+        let sk_r = composer.append_witness(self.sk_r);
+        let pos = composer.append_witness(self.pos);
+
+        let pk_r =
+            composer.component_mul_generator(sk_r, GENERATOR_EXTENDED)?;
+        composer
+            .assert_equal_public_point(pk_r, GENERATOR_EXTENDED * self.sk_r);
+
+        let sk = SecretKey::from(self.sk_r);
+
+        let nullifier = gadget::nullifier(composer, sk_r)?;
+        composer.assert_equal_constant(
+            nullifier,
+            0,
+            Some(Nullifier::new(&sk).into()),
+        );
+
+        let nullifier = gadget::nullifier_with_position(composer, sk_r, pos)?;
+        composer.assert_equal_constant(
+            nullifier,
+            0,
+            Some(Nullifier::with_position(&sk, self.pos).into()),
+        );
+
+        Ok(())
+    }
+}
+
+#[test]
+fn nullifier_gadget() {
+    let pp = PublicParameters::setup(1 << 13, &mut OsRng).unwrap();
+    let (prover, verifier) =
+        Compiler::compile::<NullifierCircuit>(&pp, b"dusk-pki").unwrap();
+
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let sa = ssk
+        .public_spend_key()
+        .gen_stealth_address(&JubJubScalar::random(&mut OsRng));
+    let sk_r = ssk.sk_r(&sa);
+
+    let circuit = NullifierCircuit {
+        sk_r: *sk_r.as_ref(),
+        pos: 42,
+    };
+    let (proof, pi) = prover.prove(&mut OsRng, &circuit).unwrap();
+
+    assert_eq!(pi[pi.len() - 2], BlsScalar::from(Nullifier::new(&sk_r)));
+    assert_eq!(
+        pi[pi.len() - 1],
+        BlsScalar::from(Nullifier::with_position(&sk_r, 42))
+    );
+
+    verifier.verify(&proof, &pi).expect("Proof should verify");
+}
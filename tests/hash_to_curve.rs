@@ -0,0 +1,97 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![allow(deprecated)]
+
+use dusk_bytes::{DeserializableSlice, ParseHexStr};
+use dusk_jubjub::{BlsScalar, JubJubAffine, EDWARDS_D};
+use dusk_pki::hash_to_curve::{
+    encode_to_curve, hash_bytes_to_curve, hash_to_curve, DomainSeparationTag,
+};
+use rand_core::OsRng;
+
+fn assert_valid(p: &dusk_jubjub::JubJubExtended) {
+    let p = JubJubAffine::from(p);
+    let (u, v) = (p.get_u(), p.get_v());
+
+    // -u² + v² = 1 + d·u²·v²
+    assert_eq!(
+        v.square() - u.square(),
+        BlsScalar::one() + EDWARDS_D * u.square() * v.square()
+    );
+    assert_eq!(p.is_prime_order().unwrap_u8(), 1);
+    assert_eq!(JubJubAffine::from_slice(&p.to_bytes()), Ok(p));
+}
+
+#[test]
+fn points_are_in_subgroup() {
+    let dst = DomainSeparationTag::new(b"dusk-pki/test");
+
+    for _ in 0..32 {
+        let message = [BlsScalar::uni_random(&mut OsRng)];
+
+        assert_valid(&hash_to_curve(&dst, &message));
+        assert_valid(&encode_to_curve(&dst, &message));
+    }
+
+    assert_valid(&hash_to_curve(&dst, &[]));
+    assert_valid(&hash_bytes_to_curve(&dst, b""));
+    assert_valid(&hash_bytes_to_curve(&dst, &[0xff; 100]));
+}
+
+#[test]
+fn domain_separation() {
+    let a = DomainSeparationTag::new(b"dusk-pki/a");
+    let b = DomainSeparationTag::new(b"dusk-pki/b");
+    let message = [BlsScalar::from(42u64)];
+
+    assert_ne!(a, b);
+    assert_eq!(hash_to_curve(&a, &message), hash_to_curve(&a, &message));
+    assert_ne!(hash_to_curve(&a, &message), hash_to_curve(&b, &message));
+    assert_ne!(hash_to_curve(&a, &message), encode_to_curve(&a, &message));
+
+    assert_ne!(
+        hash_to_curve(&a, &message),
+        hash_to_curve(&a, &[BlsScalar::from(43u64)])
+    );
+
+    // The byte length is part of the hash
+    assert_ne!(
+        hash_bytes_to_curve(&a, b"dusk"),
+        hash_bytes_to_curve(&a, b"dusk\0")
+    );
+}
+
+#[test]
+fn hash_to_curve_vectors() {
+    let dst = DomainSeparationTag::from_scalar(BlsScalar::from(0x1234u64));
+
+    let p = hash_to_curve(&dst, &[BlsScalar::one()]);
+    let q = encode_to_curve(&dst, &[BlsScalar::one()]);
+    let r = hash_bytes_to_curve(&dst, b"dusk");
+
+    assert_eq!(
+        JubJubAffine::from(p),
+        JubJubAffine::from_hex_str(
+            "bb57d54cdc3c68540f79aa5e027e1c9425a6e44ac9c273a96a8e0f942adef1c3"
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        JubJubAffine::from(q),
+        JubJubAffine::from_hex_str(
+            "96adc4f414d1b6b99e434b8edcca3be527c346962bdcd74e1278c3d57d389e3f"
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        JubJubAffine::from(r),
+        JubJubAffine::from_hex_str(
+            "33f4f24ca7414b9c2d1686a64dc5eab2a2e43d20a78f6f97b3c43e1681af203f"
+        )
+        .unwrap()
+    );
+}
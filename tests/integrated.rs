@@ -0,0 +1,89 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![allow(deprecated)]
+
+use dusk_bytes::Serializable;
+use dusk_jubjub::JubJubScalar;
+use dusk_pki::{
+    IntegratedAddress, IntegratedStealthAddress, KeyDerivation, KeyError,
+    Ownable, PublicKey, SecretSpendKey,
+};
+use rand_core::OsRng;
+
+const PAYMENT_ID: [u8; 8] = [0xde, 0xad, 0xbe, 0xef, 0x00, 0x01, 0x02, 0x03];
+
+#[test]
+fn payment_id_is_recovered() {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let vk = ssk.view_key();
+    let address = IntegratedAddress::new(ssk.public_spend_key(), PAYMENT_ID);
+
+    let r = JubJubScalar::random(&mut OsRng);
+    let output = address.gen_stealth_address(&r);
+
+    assert_eq!(vk.owns_integrated(&output), Some(PAYMENT_ID));
+    assert_ne!(output.encrypted_id(), &PAYMENT_ID);
+
+    // The output is a regular stealth address of the spend key
+    let sa = output.stealth_address();
+    assert_eq!(sa, &ssk.public_spend_key().gen_stealth_address(&r));
+    assert!(vk.owns(&output));
+    assert_eq!(PublicKey::from(&ssk.sk_r(sa)), *sa.pk_r());
+
+    // Every output encrypts the ID with its own key
+    let other = address.gen_stealth_address(&JubJubScalar::random(&mut OsRng));
+    assert_ne!(other.encrypted_id(), output.encrypted_id());
+    assert_eq!(vk.owns_integrated(&other), Some(PAYMENT_ID));
+}
+
+#[test]
+fn foreign_outputs_are_rejected() {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let vk = SecretSpendKey::random(&mut OsRng).view_key();
+    let address = IntegratedAddress::new(ssk.public_spend_key(), PAYMENT_ID);
+
+    let output = address.gen_stealth_address(&JubJubScalar::random(&mut OsRng));
+    assert_eq!(vk.owns_integrated(&output), None);
+}
+
+#[test]
+fn payment_id_with_derivation() {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let vk = ssk.view_key();
+    let address = IntegratedAddress::new(ssk.public_spend_key(), PAYMENT_ID);
+
+    let r = JubJubScalar::random(&mut OsRng);
+    let output = address.gen_stealth_address_with(&KeyDerivation::V1, &r);
+
+    assert_eq!(
+        vk.owns_integrated_with(&KeyDerivation::V1, &output),
+        Some(PAYMENT_ID)
+    );
+    assert_eq!(vk.owns_integrated(&output), None);
+}
+
+#[test]
+fn integrated_serialization() {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let address = IntegratedAddress::new(ssk.public_spend_key(), PAYMENT_ID);
+
+    let bytes = address.to_bytes();
+    assert_eq!(bytes.len(), 72);
+    assert_eq!(&bytes[64..], &PAYMENT_ID);
+    assert_eq!(IntegratedAddress::from_bytes(&bytes), Ok(address));
+
+    let output = address.gen_stealth_address(&JubJubScalar::random(&mut OsRng));
+    let bytes = output.to_bytes();
+    assert_eq!(IntegratedStealthAddress::from_bytes(&bytes), Ok(output));
+
+    let mut bytes = address.to_bytes();
+    bytes[..32].copy_from_slice(&[0xff; 32]);
+    assert_eq!(
+        IntegratedAddress::from_bytes(&bytes),
+        Err(KeyError::OffCurvePoint)
+    );
+}
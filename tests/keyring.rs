@@ -0,0 +1,72 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "alloc")]
+#![allow(deprecated)]
+
+use dusk_jubjub::JubJubScalar;
+use dusk_pki::{Keyring, PublicKey, SecretSpendKey};
+use rand_core::OsRng;
+
+#[test]
+fn accounts_are_deterministic() {
+    let mut keyring = Keyring::new([7; 32]);
+
+    let ssk = keyring.secret_spend_key(3);
+    assert_eq!(ssk, Keyring::new([7; 32]).secret_spend_key(3));
+    assert_ne!(ssk, keyring.secret_spend_key(4));
+    assert_ne!(ssk, Keyring::new([8; 32]).secret_spend_key(3));
+
+    let account = *keyring.account(3);
+    assert_eq!(account.index(), 3);
+    assert_eq!(account.view_key(), &ssk.view_key());
+    assert_eq!(account.public_spend_key(), &ssk.public_spend_key());
+    assert_eq!(keyring.view_key(3), &ssk.view_key());
+    assert_eq!(keyring.public_spend_key(3), &ssk.public_spend_key());
+}
+
+#[test]
+fn accessed_accounts_are_cached() {
+    let mut keyring = Keyring::new([1; 32]);
+    assert_eq!(keyring.accounts().count(), 0);
+
+    keyring.account(5);
+    keyring.view_key(0);
+    keyring.public_spend_key(5);
+
+    let indexes: Vec<u32> = keyring.accounts().map(|a| a.index()).collect();
+    assert_eq!(indexes, [0, 5]);
+}
+
+#[test]
+fn owner_is_found() {
+    let mut keyring = Keyring::new([2; 32]);
+    (0..4).for_each(|index| {
+        keyring.account(index);
+    });
+
+    let r = JubJubScalar::random(&mut OsRng);
+    let sa = keyring.public_spend_key(2).gen_stealth_address(&r);
+
+    let owner = keyring.owner(&sa).expect("The owner should be found");
+    assert_eq!(owner.index(), 2);
+
+    let sk_r = keyring.sk_r(&sa).expect("The output should be owned");
+    assert_eq!(sk_r, keyring.secret_spend_key(2).sk_r(&sa));
+    assert_eq!(PublicKey::from(&sk_r), *sa.pk_r());
+
+    // Outputs of accounts not accessed yet are not found
+    let unmanaged = keyring.secret_spend_key(9).public_spend_key();
+    let sa = unmanaged.gen_stealth_address(&r);
+    assert!(keyring.owner(&sa).is_none());
+    assert!(keyring.sk_r(&sa).is_none());
+
+    keyring.account(9);
+    assert_eq!(keyring.owner(&sa).map(|a| a.index()), Some(9));
+
+    let foreign = SecretSpendKey::random(&mut OsRng).public_spend_key();
+    assert!(keyring.owner(&foreign.gen_stealth_address(&r)).is_none());
+}
@@ -0,0 +1,43 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![allow(deprecated)]
+
+use dusk_bytes::Serializable;
+use dusk_jubjub::JubJubScalar;
+use dusk_pki::{Nullifier, SecretSpendKey};
+use rand_core::OsRng;
+
+#[test]
+fn nullifier_consistency() {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let psk = ssk.public_spend_key();
+
+    let sa = psk.gen_stealth_address(&JubJubScalar::random(&mut OsRng));
+    let other = psk.gen_stealth_address(&JubJubScalar::random(&mut OsRng));
+
+    let sk_r = ssk.sk_r(&sa);
+    let nullifier = Nullifier::new(&sk_r);
+
+    assert_eq!(nullifier, Nullifier::new(&ssk.sk_r(&sa)));
+    assert_ne!(nullifier, Nullifier::new(&ssk.sk_r(&other)));
+
+    assert_eq!(
+        Nullifier::with_position(&sk_r, 7),
+        Nullifier::with_position(&sk_r, 7)
+    );
+    assert_ne!(
+        Nullifier::with_position(&sk_r, 7),
+        Nullifier::with_position(&sk_r, 8)
+    );
+    assert_ne!(nullifier, Nullifier::with_position(&sk_r, 0));
+
+    // The key image is not the one-time public key
+    assert_ne!(&Nullifier::key_image(&sk_r), sa.address());
+
+    let decoded = Nullifier::from_bytes(&nullifier.to_bytes()).unwrap();
+    assert_eq!(decoded, nullifier);
+}
@@ -0,0 +1,152 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "alloc")]
+#![allow(deprecated)]
+
+use dusk_pki::{
+    IntegratedAddress, KeyError, PaymentRequest, PaymentRequestError,
+    PublicSpendKey, SecretSpendKey,
+};
+use rand_core::OsRng;
+
+fn psk() -> PublicSpendKey {
+    SecretSpendKey::random(&mut OsRng).public_spend_key()
+}
+
+#[test]
+fn payment_request_roundtrip() {
+    let psk = psk();
+
+    let request = PaymentRequest::new(psk);
+    assert_eq!(request.to_uri(), format!("dusk:{psk:x}"));
+    assert_eq!(request.to_uri().parse(), Ok(request));
+
+    let request = PaymentRequest::new(psk)
+        .with_amount(1_000)
+        .and_then(|r| r.with_memo("Coffee & beans, 100%"))
+        .expect("Valid parameters")
+        .with_payment_id([0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77])
+        .with_expiry(1_700_000_000);
+
+    let uri = request.to_uri();
+    assert_eq!(
+        uri,
+        format!(
+            "dusk:{psk:x}?amount=1000&memo=Coffee%20%26%20beans%2C%20100%25\
+             &id=0011223344556677&expires=1700000000"
+        )
+    );
+
+    let parsed: PaymentRequest = uri.parse().expect("Valid URI");
+    assert_eq!(parsed, request);
+    assert_eq!(parsed.psk(), &psk);
+    assert_eq!(parsed.amount(), Some(1_000));
+    assert_eq!(parsed.memo(), Some("Coffee & beans, 100%"));
+    assert_eq!(parsed.expiry(), Some(1_700_000_000));
+
+    assert!(!parsed.is_expired(1_700_000_000));
+    assert!(parsed.is_expired(1_700_000_001));
+    assert!(!PaymentRequest::new(psk).is_expired(u64::MAX));
+}
+
+#[test]
+fn payment_request_parsing() {
+    let psk = psk();
+    let parse = |query: &str| format!("dusk:{psk:x}{query}").parse();
+
+    // Scheme is case insensitive, unknown optional parameters are ignored
+    let uri = format!("DUSK:{psk:x}?label=shop&amount=5");
+    let request: PaymentRequest = uri.parse().unwrap();
+    assert_eq!(request.amount(), Some(5));
+
+    // Unicode memo
+    let request: PaymentRequest = parse("?memo=caf%C3%A9").unwrap();
+    assert_eq!(request.memo(), Some("café"));
+
+    assert_eq!(
+        format!("dusk-{psk:x}").parse::<PaymentRequest>(),
+        Err(PaymentRequestError::InvalidScheme)
+    );
+    assert_eq!(
+        "dusk:00".parse::<PaymentRequest>(),
+        Err(PaymentRequestError::InvalidAddress(KeyError::WrongLength {
+            found: 2,
+            expected: 128,
+        }))
+    );
+    assert!(matches!(
+        format!("dusk:02{}", "0".repeat(126)).parse::<PaymentRequest>(),
+        Err(PaymentRequestError::InvalidAddress(KeyError::OffCurvePoint))
+    ));
+
+    let cases = [
+        ("?amount=0", PaymentRequestError::InvalidAmount),
+        ("?amount=-1", PaymentRequestError::InvalidAmount),
+        ("?amount=01", PaymentRequestError::InvalidAmount),
+        (
+            "?amount=18446744073709551616",
+            PaymentRequestError::InvalidAmount,
+        ),
+        ("?id=00112233", PaymentRequestError::InvalidPaymentId),
+        (
+            "?id=001122334455667g",
+            PaymentRequestError::InvalidPaymentId,
+        ),
+        (
+            "?id=+f11223344556677",
+            PaymentRequestError::InvalidPaymentId,
+        ),
+        ("?expires=soon", PaymentRequestError::InvalidExpiry),
+        ("?amount=1&amount=2", PaymentRequestError::InvalidParameter),
+        ("?req-fee=1", PaymentRequestError::InvalidParameter),
+        ("?amount", PaymentRequestError::InvalidParameter),
+        ("?memo=%4", PaymentRequestError::InvalidParameter),
+        ("?memo=%ff", PaymentRequestError::InvalidParameter),
+        ("?memo=%+f", PaymentRequestError::InvalidParameter),
+    ];
+
+    for (query, err) in cases {
+        assert_eq!(parse(query), Err(err), "{query}");
+    }
+
+    let memo = "a".repeat(PaymentRequest::MAX_MEMO_SIZE + 1);
+    assert_eq!(
+        parse(&format!("?memo={memo}")),
+        Err(PaymentRequestError::MemoTooLong)
+    );
+}
+
+#[test]
+fn payment_request_validation() {
+    let request = PaymentRequest::new(psk());
+
+    assert_eq!(
+        request.clone().with_amount(0),
+        Err(PaymentRequestError::InvalidAmount)
+    );
+
+    let memo = "a".repeat(PaymentRequest::MAX_MEMO_SIZE);
+    assert!(request.clone().with_memo(memo.as_str()).is_ok());
+    assert_eq!(
+        request.with_memo(memo + "a"),
+        Err(PaymentRequestError::MemoTooLong)
+    );
+}
+
+#[test]
+fn payment_request_integrated_address() {
+    let psk = psk();
+    let id = [1, 2, 3, 4, 5, 6, 7, 8];
+
+    assert_eq!(PaymentRequest::new(psk).integrated_address(), None);
+    assert_eq!(
+        PaymentRequest::new(psk)
+            .with_payment_id(id)
+            .integrated_address(),
+        Some(IntegratedAddress::new(psk, id))
+    );
+}
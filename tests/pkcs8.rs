@@ -0,0 +1,168 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![allow(deprecated)]
+
+use dusk_jubjub::JubJubScalar;
+use dusk_pki::{
+    KeyError, PublicKey, PublicSpendKey, SecretKey, SecretSpendKey,
+};
+use rand_core::OsRng;
+
+const SECRET_KEY_PEM: &str = include_str!("fixtures/secret_key.pem");
+const PUBLIC_KEY_PEM: &str = include_str!("fixtures/public_key.pem");
+const SECRET_SPEND_KEY_PEM: &str =
+    include_str!("fixtures/secret_spend_key.pem");
+const PUBLIC_SPEND_KEY_PEM: &str =
+    include_str!("fixtures/public_spend_key.pem");
+
+fn fixture_keys() -> (SecretKey, SecretSpendKey) {
+    let sk = SecretKey::from(JubJubScalar::from_raw([
+        0x0123456789abcdef,
+        0xfedcba9876543210,
+        0x0f1e2d3c4b5a6978,
+        1,
+    ]));
+    let ssk = SecretSpendKey::new(
+        JubJubScalar::from_raw([
+            0x1111111111111111,
+            0x2222222222222222,
+            0x3333333333333333,
+            2,
+        ]),
+        JubJubScalar::from_raw([
+            0x4444444444444444,
+            0x5555555555555555,
+            0x6666666666666666,
+            3,
+        ]),
+    );
+
+    (sk, ssk)
+}
+
+#[test]
+fn der_roundtrip() {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let psk = ssk.public_spend_key();
+    let sk = SecretKey::random(&mut OsRng);
+    let pk = PublicKey::from(&sk);
+
+    assert_eq!(SecretKey::from_pkcs8_der(&sk.to_pkcs8_der()), Ok(sk));
+    assert_eq!(SecretSpendKey::from_pkcs8_der(&ssk.to_pkcs8_der()), Ok(ssk));
+    assert_eq!(
+        PublicKey::from_public_key_der(&pk.to_public_key_der()),
+        Ok(pk)
+    );
+    assert_eq!(
+        PublicSpendKey::from_public_key_der(&psk.to_public_key_der()),
+        Ok(psk)
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn pem_roundtrip() {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let psk = ssk.public_spend_key();
+    let sk = SecretKey::random(&mut OsRng);
+    let pk = PublicKey::from(&sk);
+
+    assert_eq!(SecretKey::from_pkcs8_pem(&sk.to_pkcs8_pem()), Ok(sk));
+    assert_eq!(SecretSpendKey::from_pkcs8_pem(&ssk.to_pkcs8_pem()), Ok(ssk));
+    assert_eq!(
+        PublicKey::from_public_key_pem(&pk.to_public_key_pem()),
+        Ok(pk)
+    );
+    assert_eq!(
+        PublicSpendKey::from_public_key_pem(&psk.to_public_key_pem()),
+        Ok(psk)
+    );
+}
+
+#[test]
+fn pem_fixtures() {
+    let (sk, ssk) = fixture_keys();
+    let pk = PublicKey::from(&sk);
+    let psk = ssk.public_spend_key();
+
+    assert_eq!(SecretKey::from_pkcs8_pem(SECRET_KEY_PEM), Ok(sk));
+    assert_eq!(PublicKey::from_public_key_pem(PUBLIC_KEY_PEM), Ok(pk));
+    assert_eq!(
+        SecretSpendKey::from_pkcs8_pem(SECRET_SPEND_KEY_PEM),
+        Ok(ssk)
+    );
+    assert_eq!(
+        PublicSpendKey::from_public_key_pem(PUBLIC_SPEND_KEY_PEM),
+        Ok(psk)
+    );
+
+    #[cfg(feature = "alloc")]
+    {
+        assert_eq!(sk.to_pkcs8_pem(), SECRET_KEY_PEM);
+        assert_eq!(pk.to_public_key_pem(), PUBLIC_KEY_PEM);
+        assert_eq!(ssk.to_pkcs8_pem(), SECRET_SPEND_KEY_PEM);
+        assert_eq!(psk.to_public_key_pem(), PUBLIC_SPEND_KEY_PEM);
+    }
+
+    // Line endings are not significant
+    let crlf = SECRET_SPEND_KEY_PEM.replace('\n', "\r\n");
+    assert_eq!(SecretSpendKey::from_pkcs8_pem(&crlf), Ok(ssk));
+}
+
+#[test]
+fn pkcs8_rejects_other_keys() {
+    let (sk, ssk) = fixture_keys();
+
+    assert_eq!(
+        SecretKey::from_pkcs8_pem(SECRET_SPEND_KEY_PEM),
+        Err(KeyError::WrongKeyType)
+    );
+    assert_eq!(
+        SecretSpendKey::from_pkcs8_pem(SECRET_KEY_PEM),
+        Err(KeyError::WrongKeyType)
+    );
+    assert_eq!(
+        PublicKey::from_public_key_pem(PUBLIC_SPEND_KEY_PEM),
+        Err(KeyError::WrongKeyType)
+    );
+
+    // A secret key is never read as a public key, and vice versa
+    assert_eq!(
+        PublicKey::from_public_key_pem(SECRET_KEY_PEM),
+        Err(KeyError::InvalidData)
+    );
+    assert_eq!(
+        SecretKey::from_pkcs8_pem(PUBLIC_KEY_PEM),
+        Err(KeyError::InvalidData)
+    );
+
+    let der = ssk.to_pkcs8_der();
+    assert_eq!(
+        SecretSpendKey::from_pkcs8_der(&der[..90]),
+        Err(KeyError::WrongLength {
+            found: 90,
+            expected: 98
+        })
+    );
+
+    let mut der = sk.to_pkcs8_der();
+    der[10] ^= 1;
+    assert_eq!(SecretKey::from_pkcs8_der(&der), Err(KeyError::InvalidData));
+
+    let mut der = sk.to_pkcs8_der();
+    der[34..].copy_from_slice(&[0xff; 32]);
+    assert_eq!(
+        SecretKey::from_pkcs8_der(&der),
+        Err(KeyError::InvalidScalar)
+    );
+
+    let corrupted = SECRET_KEY_PEM.replacen('M', "!", 1);
+    assert_eq!(
+        SecretKey::from_pkcs8_pem(&corrupted),
+        Err(KeyError::InvalidData)
+    );
+}
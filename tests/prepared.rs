@@ -0,0 +1,89 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![allow(deprecated)]
+
+use dusk_jubjub::JubJubScalar;
+use dusk_pki::{
+    IntegratedAddress, KeyDerivation, PreparedViewKey, SecretSpendKey,
+    StealthAddress, ViewKey,
+};
+use rand_core::OsRng;
+
+fn outputs(vk: &ViewKey, derivation: &KeyDerivation) -> Vec<StealthAddress> {
+    let psk = vk.public_spend_key();
+    let foreign = SecretSpendKey::random(&mut OsRng).public_spend_key();
+
+    (0..16)
+        .map(|i| {
+            let r = JubJubScalar::random(&mut OsRng);
+            match i % 3 {
+                0 => foreign.gen_stealth_address_with(derivation, &r),
+                _ => psk.gen_stealth_address_with(derivation, &r),
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn prepared_key_matches_view_key() {
+    for a in [
+        JubJubScalar::zero(),
+        JubJubScalar::one(),
+        -JubJubScalar::one(),
+    ] {
+        let b = JubJubScalar::random(&mut OsRng);
+        let vk = SecretSpendKey::new(a, b).view_key();
+        let prepared = PreparedViewKey::from(&vk);
+
+        for sa in outputs(&vk, &KeyDerivation::LEGACY) {
+            assert_eq!(prepared.owns(&sa), vk.owns(&sa));
+        }
+    }
+
+    let vk = SecretSpendKey::random(&mut OsRng).view_key();
+    let prepared = PreparedViewKey::new(&vk);
+    assert_eq!(prepared.view_key(), &vk);
+
+    for derivation in [KeyDerivation::LEGACY, KeyDerivation::V1] {
+        let outputs = outputs(&vk, &derivation);
+        assert!(outputs.iter().any(|sa| vk.owns_with(&derivation, sa)));
+
+        for sa in &outputs {
+            assert_eq!(
+                prepared.owns_with(&derivation, sa),
+                vk.owns_with(&derivation, sa)
+            );
+        }
+    }
+
+    let address = IntegratedAddress::new(vk.public_spend_key(), [1; 8]);
+    let output = address.gen_stealth_address(&JubJubScalar::random(&mut OsRng));
+    assert!(prepared.owns(&output));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn batch_matches_view_key() {
+    let vk = SecretSpendKey::random(&mut OsRng).view_key();
+    let prepared = PreparedViewKey::new(&vk);
+
+    for derivation in [KeyDerivation::LEGACY, KeyDerivation::V1] {
+        let outputs = outputs(&vk, &derivation);
+
+        let expected: Vec<bool> = outputs
+            .iter()
+            .map(|sa| vk.owns_with(&derivation, sa))
+            .collect();
+        assert_eq!(prepared.owns_batch_with(&derivation, &outputs), expected);
+    }
+
+    let outputs = outputs(&vk, &KeyDerivation::LEGACY);
+    let expected: Vec<bool> = outputs.iter().map(|sa| vk.owns(sa)).collect();
+    assert_eq!(prepared.owns_batch(&outputs), expected);
+
+    assert!(prepared.owns_batch::<StealthAddress>(&[]).is_empty());
+}
@@ -0,0 +1,151 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "proptest")]
+#![allow(deprecated)]
+
+use dusk_bytes::{DeserializableSlice, Serializable};
+use dusk_pki::strategies::*;
+use dusk_pki::{
+    IntegratedAddress, IntegratedStealthAddress, KeyError, PublicKey,
+    PublicSpendKey, SecretKey, SecretSpendKey, StealthAddress, ViewKey,
+};
+use proptest::prelude::*;
+
+macro_rules! roundtrip {
+    ($value:expr, $ty:ty) => {{
+        let bytes = $value.to_bytes();
+        prop_assert_eq!(<$ty>::from_bytes(&bytes), Ok($value));
+        prop_assert_eq!(<$ty>::from_slice(&bytes), Ok($value));
+    }};
+}
+
+/// Checks that any bytes either fail to decode, or decode into a value with
+/// the same encoding
+macro_rules! decodes_canonically {
+    ($bytes:expr, $ty:ty) => {{
+        let bytes = $bytes;
+        if let Ok(value) = <$ty>::from_slice(&bytes) {
+            prop_assert_eq!(&value.to_bytes()[..], &bytes[..]);
+        }
+    }};
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn keys_roundtrip(
+        sk in any::<SecretKey>(),
+        pk in any::<PublicKey>(),
+        ssk in any::<SecretSpendKey>(),
+        psk in any::<PublicSpendKey>(),
+        vk in any::<ViewKey>(),
+    ) {
+        roundtrip!(sk, SecretKey);
+        roundtrip!(pk, PublicKey);
+        roundtrip!(ssk, SecretSpendKey);
+        roundtrip!(psk, PublicSpendKey);
+        roundtrip!(vk, ViewKey);
+    }
+
+    #[test]
+    fn addresses_roundtrip(
+        sa in any::<StealthAddress>(),
+        address in any::<IntegratedAddress>(),
+        output in any::<IntegratedStealthAddress>(),
+    ) {
+        roundtrip!(sa, StealthAddress);
+        roundtrip!(address, IntegratedAddress);
+        roundtrip!(output, IntegratedStealthAddress);
+    }
+
+    #[test]
+    fn generated_addresses_are_owned(
+        ssk in any::<SecretSpendKey>(),
+        derivation in key_derivation(),
+        r in jubjub_scalar(),
+    ) {
+        let psk = ssk.public_spend_key();
+        let sa = psk.gen_stealth_address_with(&derivation, &r);
+
+        prop_assert!(ssk.view_key().owns_with(&derivation, &sa));
+    }
+
+    #[test]
+    fn sk_r_matches_pk_r(
+        ssk in any::<SecretSpendKey>(),
+        derivation in key_derivation(),
+        r in jubjub_scalar(),
+    ) {
+        let psk = ssk.public_spend_key();
+        let sa = psk.gen_stealth_address_with(&derivation, &r);
+        let sk_r = ssk.sk_r_with(&derivation, &sa);
+
+        prop_assert_eq!(PublicKey::from(&sk_r), *sa.pk_r());
+    }
+
+    #[test]
+    fn unrelated_keys_differ(
+        (ssk, sa) in owned_stealth_address(),
+        other in any::<SecretSpendKey>(),
+    ) {
+        prop_assume!(ssk != other);
+
+        prop_assert_ne!(ssk.public_spend_key(), other.public_spend_key());
+        prop_assert_ne!(ssk.view_key(), other.view_key());
+        prop_assert!(!other.view_key().owns(&sa));
+        prop_assert_ne!(PublicKey::from(&other.sk_r(&sa)), *sa.pk_r());
+    }
+
+    #[test]
+    fn non_canonical_scalars_are_rejected(
+        mut bytes in any::<[u8; 32]>(),
+        high in 0x10u8..,
+    ) {
+        // Every value of at least 2^252 exceeds the order of the scalars
+        bytes[31] |= high & 0xf0;
+
+        prop_assert_eq!(
+            SecretKey::from_bytes(&bytes),
+            Err(KeyError::InvalidScalar)
+        );
+
+        let mut ssk = [0u8; 64];
+        ssk[32..].copy_from_slice(&bytes);
+        prop_assert_eq!(
+            SecretSpendKey::from_bytes(&ssk),
+            Err(KeyError::InvalidScalar)
+        );
+    }
+
+    #[test]
+    fn malformed_bytes_are_rejected(
+        short in any::<[u8; 32]>(),
+        lo in any::<[u8; 32]>(),
+        hi in any::<[u8; 32]>(),
+    ) {
+        let mut long = [0u8; 64];
+        long[..32].copy_from_slice(&lo);
+        long[32..].copy_from_slice(&hi);
+
+        decodes_canonically!(short, SecretKey);
+        decodes_canonically!(short, PublicKey);
+        decodes_canonically!(long, SecretSpendKey);
+        decodes_canonically!(long, PublicSpendKey);
+        decodes_canonically!(long, ViewKey);
+        decodes_canonically!(long, StealthAddress);
+
+        prop_assert_eq!(
+            PublicSpendKey::from_slice(&short).err(),
+            Some(KeyError::WrongLength { found: 32, expected: 64 })
+        );
+        prop_assert_eq!(
+            PublicKey::from_slice(&short[..31]).err(),
+            Some(KeyError::WrongLength { found: 31, expected: 32 })
+        );
+    }
+}
@@ -0,0 +1,90 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "alloc")]
+#![allow(deprecated)]
+
+use dusk_bytes::Error;
+use dusk_jubjub::JubJubScalar;
+use dusk_pki::{ReserveProof, SecretSpendKey, StealthAddress};
+use rand_core::OsRng;
+
+fn addresses(ssk: &SecretSpendKey, n: usize) -> Vec<StealthAddress> {
+    let psk = ssk.public_spend_key();
+
+    (0..n)
+        .map(|_| psk.gen_stealth_address(&JubJubScalar::random(&mut OsRng)))
+        .collect()
+}
+
+#[test]
+fn reserve_proof() {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let psk = ssk.public_spend_key();
+    let sas = addresses(&ssk, 16);
+
+    let proof = ReserveProof::new(&ssk, &sas, &mut OsRng)
+        .unwrap()
+        .expect("All addresses are owned");
+
+    assert_eq!(proof.len(), sas.len());
+    assert!(proof.verify(&psk, &sas));
+
+    let wrong_psk = SecretSpendKey::random(&mut OsRng).public_spend_key();
+    assert!(!proof.verify(&wrong_psk, &sas));
+
+    let mut swapped = sas.clone();
+    swapped.swap(0, 1);
+    assert!(!proof.verify(&psk, &swapped));
+
+    assert!(!proof.verify(&psk, &sas[1..]));
+}
+
+#[test]
+fn reserve_proof_foreign_address() {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let mut sas = addresses(&ssk, 4);
+
+    let other = SecretSpendKey::random(&mut OsRng);
+    sas.extend(addresses(&other, 1));
+
+    assert_eq!(ReserveProof::new(&ssk, &sas, &mut OsRng), Ok(None));
+}
+
+#[test]
+fn reserve_proof_bytes() {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let psk = ssk.public_spend_key();
+    let sas = addresses(&ssk, 8);
+
+    let proof = ReserveProof::new(&ssk, &sas, &mut OsRng).unwrap().unwrap();
+    let bytes = proof.to_bytes();
+
+    assert_eq!(bytes.len(), 32 * sas.len() + ReserveProof::FIXED_SIZE);
+    assert!(bytes.len() < 96 * sas.len());
+
+    let decoded = ReserveProof::from_slice(&bytes).expect("Valid proof");
+
+    assert_eq!(proof, decoded);
+    assert!(decoded.verify(&psk, &sas));
+
+    // The expected length is the one of a proof covering the shared
+    // secrets that fit in the bytes
+    assert_eq!(
+        ReserveProof::from_slice(&bytes[1..]),
+        Err(Error::BadLength {
+            found: bytes.len() - 1,
+            expected: bytes.len() - 32,
+        })
+    );
+    assert_eq!(
+        ReserveProof::from_slice(&bytes[..10]),
+        Err(Error::BadLength {
+            found: 10,
+            expected: ReserveProof::FIXED_SIZE,
+        })
+    );
+}
@@ -0,0 +1,259 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "rkyv-impl")]
+#![allow(deprecated)]
+#![allow(non_snake_case)]
+
+use dusk_bytes::Serializable;
+use dusk_jubjub::{
+    BlsScalar, JubJubExtended, JubJubScalar, GENERATOR_EXTENDED,
+};
+use dusk_pki::{
+    ArchivedCompact, Compact, IntegratedAddress, IntegratedStealthAddress,
+    InvalidArchive, KeyDerivation, ProtocolVersion, PublicKey, PublicSpendKey,
+    Scanner, SecretKey, SecretSpendKey, StealthAddress, ViewKey,
+};
+use rand_core::OsRng;
+use rkyv::ser::serializers::AllocSerializer;
+use rkyv::{
+    check_archived_root, AlignedVec, Archive, Deserialize, Fallible,
+    Infallible, Serialize,
+};
+
+fn archive<T: rkyv::Serialize<AllocSerializer<256>>>(value: &T) -> AlignedVec {
+    rkyv::to_bytes::<_, 256>(value).expect("Failed to archive")
+}
+
+fn with_a(A: JubJubExtended) -> PublicSpendKey {
+    let psk = SecretSpendKey::random(&mut OsRng).public_spend_key();
+    PublicSpendKey::new(A, *psk.B())
+}
+
+fn check_error(psk: &PublicSpendKey) -> String {
+    let bytes = archive(psk);
+    match check_archived_root::<PublicSpendKey>(&bytes) {
+        Ok(_) => panic!("Invalid point accepted"),
+        Err(e) => e.to_string(),
+    }
+}
+
+#[test]
+fn archived_keys_roundtrip() {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let psk = ssk.public_spend_key();
+    let vk = ssk.view_key();
+    let sa = psk.gen_stealth_address(&JubJubScalar::random(&mut OsRng));
+    let sk = SecretKey::random(&mut OsRng);
+    let pk = PublicKey::from(&sk);
+
+    macro_rules! roundtrip {
+        ($value:expr, $ty:ty) => {
+            let bytes = archive(&$value);
+            let archived =
+                check_archived_root::<$ty>(&bytes).expect("Valid key rejected");
+            let value: $ty = archived.deserialize(&mut Infallible).unwrap();
+            assert_eq!(value, $value);
+        };
+    }
+
+    roundtrip!(ssk, SecretSpendKey);
+    roundtrip!(psk, PublicSpendKey);
+    roundtrip!(vk, ViewKey);
+    roundtrip!(sa, StealthAddress);
+    roundtrip!(sk, SecretKey);
+    roundtrip!(pk, PublicKey);
+    roundtrip!(StealthAddress::default(), StealthAddress);
+}
+
+#[test]
+fn archived_points_are_validated() {
+    let G = GENERATOR_EXTENDED;
+    let one = BlsScalar::one();
+
+    // Consistent extended coordinates of the affine point `(2, 3)`
+    let (u, v) = (BlsScalar::from(2), BlsScalar::from(3));
+    let off_curve = JubJubExtended::from_raw_unchecked(u, v, one, u, v);
+    assert!(check_error(&with_a(off_curve)).contains("not on the curve"));
+
+    let inconsistent = JubJubExtended::from_raw_unchecked(
+        G.get_u(),
+        G.get_v(),
+        G.get_z(),
+        G.get_t1() + one,
+        G.get_t2(),
+    );
+    assert!(check_error(&with_a(inconsistent)).contains("inconsistent"));
+
+    let zero = BlsScalar::zero();
+    let projection =
+        JubJubExtended::from_raw_unchecked(zero, zero, zero, zero, zero);
+    assert!(check_error(&with_a(projection)).contains("zero z"));
+
+    let non_canonical = JubJubExtended::from_raw_unchecked(
+        BlsScalar([u64::MAX; 4]),
+        G.get_v(),
+        G.get_z(),
+        G.get_t1(),
+        G.get_t2(),
+    );
+    assert!(check_error(&with_a(non_canonical)).contains("non canonical"));
+
+    // The one-time key of a stealth address is validated as well
+    let sa = StealthAddress::from_raw_unchecked(
+        G,
+        PublicKey::from_raw_unchecked(off_curve),
+    );
+    let bytes = archive(&sa);
+    assert!(check_archived_root::<StealthAddress>(&bytes).is_err());
+}
+
+#[test]
+fn archived_scalars_are_validated() {
+    let mut bytes = AlignedVec::new();
+    bytes.extend_from_slice(&[0xff; 32]);
+    assert!(check_archived_root::<SecretKey>(&bytes).is_err());
+
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let mut bytes = archive(&ssk);
+    let len = bytes.len();
+    bytes[len - 32..].copy_from_slice(&[0xff; 32]);
+    assert!(check_archived_root::<SecretSpendKey>(&bytes).is_err());
+}
+
+#[derive(Archive, Serialize, Deserialize)]
+#[archive(check_bytes)]
+struct Output {
+    psk: PublicSpendKey,
+    sa: StealthAddress,
+}
+
+/// Deserializer failing on the invalid encodings of the compact keys and of
+/// the scanner
+struct Strict;
+
+impl Fallible for Strict {
+    type Error = InvalidArchive;
+}
+
+#[derive(Archive, Serialize, Deserialize)]
+#[archive(check_bytes)]
+struct CompactOutput {
+    #[with(Compact)]
+    psk: PublicSpendKey,
+    #[with(Compact)]
+    sa: StealthAddress,
+}
+
+#[test]
+fn compact_layout() {
+    assert_eq!(core::mem::size_of::<ArchivedOutput>(), 640);
+    assert_eq!(core::mem::size_of::<ArchivedCompactOutput>(), 128);
+
+    let psk = SecretSpendKey::random(&mut OsRng).public_spend_key();
+    let sa = psk.gen_stealth_address(&JubJubScalar::random(&mut OsRng));
+
+    let bytes = archive(&CompactOutput { psk, sa });
+    let archived = check_archived_root::<CompactOutput>(&bytes)
+        .expect("Valid keys rejected");
+
+    assert_eq!(archived.psk.as_bytes(), &psk.to_bytes());
+    assert_eq!(archived.sa.decode(), Ok(sa));
+
+    let output: CompactOutput = archived.deserialize(&mut Strict).unwrap();
+    assert_eq!(output.psk, psk);
+    assert_eq!(output.sa, sa);
+
+    // Invalid encodings are rejected
+    let mut bytes = AlignedVec::new();
+    bytes.extend_from_slice(&[0xff; 128]);
+    assert!(check_archived_root::<CompactOutput>(&bytes).is_err());
+
+    // And fail to deserialize when accessed without checking
+    let archived = unsafe { rkyv::archived_root::<CompactOutput>(&bytes) };
+    assert!(archived.psk.decode().is_err());
+
+    let output: Result<CompactOutput, _> = archived.deserialize(&mut Strict);
+    assert_eq!(output.err(), Some(InvalidArchive::InvalidEncoding));
+}
+
+#[test]
+fn compact_migration() {
+    let psk = SecretSpendKey::random(&mut OsRng).public_spend_key();
+    let sa = psk.gen_stealth_address(&JubJubScalar::random(&mut OsRng));
+
+    let bytes = archive(&Output { psk, sa });
+    let legacy = check_archived_root::<Output>(&bytes).unwrap();
+
+    let compact = ArchivedCompact::<PublicSpendKey, 64>::migrate(&legacy.psk);
+    assert_eq!(compact.decode(), Ok(psk));
+
+    let compact = ArchivedCompact::<StealthAddress, 64>::migrate(&legacy.sa);
+    assert_eq!(compact.as_bytes(), &sa.to_bytes());
+}
+
+#[test]
+fn archived_integrated_stealth_address() {
+    let psk = SecretSpendKey::random(&mut OsRng).public_spend_key();
+    let address = IntegratedAddress::new(psk, [7; 8]);
+    let output = address.gen_stealth_address(&JubJubScalar::random(&mut OsRng));
+
+    let bytes = archive(&output);
+    let archived = check_archived_root::<IntegratedStealthAddress>(&bytes)
+        .expect("Valid address rejected");
+    let value: IntegratedStealthAddress =
+        archived.deserialize(&mut Infallible).unwrap();
+    assert_eq!(value, output);
+
+    let sa = StealthAddress::from_raw_unchecked(
+        JubJubExtended::from_raw_unchecked(
+            BlsScalar::zero(),
+            BlsScalar::zero(),
+            BlsScalar::zero(),
+            BlsScalar::zero(),
+            BlsScalar::zero(),
+        ),
+        *psk.gen_stealth_address(&JubJubScalar::one()).pk_r(),
+    );
+    let mut bytes = archive(&output);
+    let invalid = archive(&sa);
+    bytes[..invalid.len()].copy_from_slice(&invalid);
+    assert!(check_archived_root::<IntegratedStealthAddress>(&bytes).is_err());
+}
+
+#[test]
+fn archived_scanner() {
+    let vk = SecretSpendKey::random(&mut OsRng).view_key();
+    let mut scanner = Scanner::new(vk, 0);
+    scanner
+        .commit(0, [1; 32], &[0; 32])
+        .expect("Failed to scan block");
+
+    let bytes = archive(&scanner);
+    let archived =
+        check_archived_root::<Scanner>(&bytes).expect("Valid scanner rejected");
+    let value: Scanner = archived.deserialize(&mut Strict).unwrap();
+    assert_eq!(value, scanner);
+
+    // Locate the version by archiving the scanner with another derivation
+    let derivation = KeyDerivation::new(ProtocolVersion::V1);
+    let mut v1 = Scanner::with_derivation(vk, &derivation, 0);
+    v1.commit(0, [1; 32], &[0; 32])
+        .expect("Failed to scan block");
+    let other = archive(&v1);
+    let offset = (0..bytes.len())
+        .find(|&i| bytes[i] != other[i])
+        .expect("The version should be archived");
+
+    let mut bytes = other;
+    bytes[offset] = 0xff;
+    assert!(check_archived_root::<Scanner>(&bytes).is_err());
+
+    // And fails to deserialize when accessed without checking
+    let archived = unsafe { rkyv::archived_root::<Scanner>(&bytes) };
+    let value: Result<Scanner, _> = archived.deserialize(&mut Strict);
+    assert_eq!(value.err(), Some(InvalidArchive::InvalidEncoding));
+}
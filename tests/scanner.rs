@@ -0,0 +1,219 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![allow(deprecated)]
+
+use dusk_bytes::Serializable;
+use dusk_jubjub::JubJubScalar;
+use dusk_pki::{
+    BlockHash, KeyDerivation, KeyError, OwnedOutput, ProtocolVersion,
+    PublicKey, ScanError, Scanner, SecretSpendKey, StealthAddress,
+    MAX_REORG_DEPTH,
+};
+use rand_core::OsRng;
+
+fn hash(height: u64, fork: u8) -> BlockHash {
+    let mut hash = [fork; 32];
+    hash[..8].copy_from_slice(&height.to_le_bytes());
+    hash
+}
+
+fn random_address() -> StealthAddress {
+    SecretSpendKey::random(&mut OsRng)
+        .public_spend_key()
+        .gen_stealth_address(&JubJubScalar::random(&mut OsRng))
+}
+
+fn scan_empty(scanner: &mut Scanner, from: u64, to: u64, fork: u8) {
+    for height in from..to {
+        let parent = hash(height.wrapping_sub(1), fork);
+        scanner
+            .commit(height, hash(height, fork), &parent)
+            .expect("Failed to scan block");
+    }
+}
+
+#[test]
+fn owned_outputs_are_found() {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let psk = ssk.public_spend_key();
+    let mut scanner = Scanner::new(ssk.view_key(), 10);
+
+    let owned = psk.gen_stealth_address(&JubJubScalar::random(&mut OsRng));
+    let block = [random_address(), owned, random_address()];
+
+    let outputs: Vec<OwnedOutput> = scanner
+        .outputs(10, &[0; 32], &block)
+        .expect("Failed to scan block")
+        .collect();
+
+    // The block is only recorded once committed
+    assert_eq!(scanner.height(), None);
+    assert_eq!(scanner.next_height(), 10);
+    scanner
+        .commit(10, hash(10, 0), &[0; 32])
+        .expect("Failed to commit block");
+
+    assert_eq!(outputs.len(), 1);
+    assert_eq!(outputs[0].height, 10);
+    assert_eq!(outputs[0].index, 1);
+    let sk_r = outputs[0]
+        .sk_r(&ssk, &owned)
+        .unwrap()
+        .expect("The output should be owned");
+    assert_eq!(sk_r, ssk.sk_r(&owned));
+    assert_eq!(PublicKey::from(&sk_r), *owned.pk_r());
+
+    assert_eq!(scanner.height(), Some(10));
+    assert_eq!(scanner.next_height(), 11);
+    assert_eq!(scanner.checkpoint(10), Some(&hash(10, 0)));
+}
+
+#[test]
+fn derivation_is_used() {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let psk = ssk.public_spend_key();
+    let derivation = KeyDerivation::new(ProtocolVersion::V1);
+    let r = JubJubScalar::random(&mut OsRng);
+
+    let block = [psk.gen_stealth_address_with(&derivation, &r)];
+
+    let legacy = Scanner::new(ssk.view_key(), 0);
+    let found = legacy.outputs(0, &[0; 32], &block).unwrap();
+    assert_eq!(found.count(), 0);
+
+    let scanner = Scanner::with_derivation(ssk.view_key(), &derivation, 0);
+    let found: Vec<_> = scanner.outputs(0, &[0; 32], &block).unwrap().collect();
+    assert_eq!(
+        found[0].sk_r(&ssk, &block[0]).unwrap(),
+        Some(ssk.sk_r_with(&derivation, &block[0]))
+    );
+    assert_eq!(scanner.derivation(), derivation);
+}
+
+#[test]
+fn blocks_must_be_consecutive() {
+    let vk = SecretSpendKey::random(&mut OsRng).view_key();
+    let mut scanner = Scanner::new(vk, 5);
+
+    let err = scanner.commit(6, hash(6, 0), &hash(5, 0)).err();
+    assert_eq!(
+        err,
+        Some(ScanError::UnexpectedHeight {
+            expected: 5,
+            found: 6
+        })
+    );
+
+    scan_empty(&mut scanner, 5, 8, 0);
+
+    let err = scanner.commit(8, hash(8, 1), &hash(7, 1)).err();
+    assert_eq!(err, Some(ScanError::ParentMismatch));
+    assert_eq!(scanner.next_height(), 8);
+
+    let err = scanner.outputs::<StealthAddress>(8, &hash(7, 1), &[]).err();
+    assert_eq!(err, Some(ScanError::ParentMismatch));
+}
+
+#[test]
+fn last_height_is_rejected() {
+    let vk = SecretSpendKey::random(&mut OsRng).view_key();
+    let mut scanner = Scanner::new(vk, u64::MAX);
+
+    let err = scanner
+        .outputs::<StealthAddress>(u64::MAX, &[0; 32], &[])
+        .err();
+    assert_eq!(err, Some(ScanError::HeightOverflow));
+    assert_eq!(
+        scanner.commit(u64::MAX, [1; 32], &[0; 32]),
+        Err(ScanError::HeightOverflow)
+    );
+    assert_eq!(scanner.next_height(), u64::MAX);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn scan_commits_the_block() {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let psk = ssk.public_spend_key();
+    let mut scanner = Scanner::new(ssk.view_key(), 0);
+
+    let owned = psk.gen_stealth_address(&JubJubScalar::random(&mut OsRng));
+    let block = [owned, random_address(), owned];
+
+    let outputs = scanner
+        .scan(0, hash(0, 0), &[0; 32], &block)
+        .expect("Failed to scan block");
+    assert_eq!(outputs.iter().map(|o| o.index).collect::<Vec<_>>(), [0, 2]);
+    assert_eq!(scanner.checkpoint(0), Some(&hash(0, 0)));
+
+    let err = scanner.scan(2, hash(2, 0), &hash(1, 0), &block).err();
+    assert_eq!(
+        err,
+        Some(ScanError::UnexpectedHeight {
+            expected: 1,
+            found: 2
+        })
+    );
+    assert_eq!(scanner.next_height(), 1);
+}
+
+#[test]
+fn reorgs_are_rolled_back() {
+    let vk = SecretSpendKey::random(&mut OsRng).view_key();
+    let mut scanner = Scanner::new(vk, 0);
+
+    scan_empty(&mut scanner, 0, 40, 0);
+
+    // Only the most recent blocks are kept as checkpoints
+    let oldest = 40 - MAX_REORG_DEPTH as u64;
+    assert_eq!(scanner.checkpoint(oldest - 1), None);
+    assert_eq!(scanner.checkpoint(oldest), Some(&hash(oldest, 0)));
+    assert_eq!(scanner.checkpoint(40), None);
+    assert_eq!(
+        scanner.rollback(oldest - 1),
+        Err(ScanError::UnknownCheckpoint(oldest - 1))
+    );
+
+    scanner.rollback(35).expect("Failed to roll back");
+    assert_eq!(scanner.height(), Some(35));
+    assert_eq!(scanner.checkpoint(36), None);
+    assert_eq!(scanner.checkpoint(35), Some(&hash(35, 0)));
+
+    // The fork is scanned on top of the common block
+    let fork = hash(36, 1);
+    scanner
+        .commit(36, fork, &hash(35, 0))
+        .expect("Failed to scan fork");
+    assert_eq!(scanner.checkpoint(36), Some(&fork));
+
+    // The checkpoints below the rollback are still available
+    scanner.rollback(oldest).expect("Failed to roll back");
+    assert_eq!(scanner.checkpoint(oldest), Some(&hash(oldest, 0)));
+    assert_eq!(scanner.checkpoint(oldest + 1), None);
+}
+
+#[test]
+fn scanner_serialization() {
+    let vk = SecretSpendKey::random(&mut OsRng).view_key();
+    let derivation = KeyDerivation::new(ProtocolVersion::V1);
+    let mut scanner = Scanner::with_derivation(vk, &derivation, 100);
+
+    scan_empty(&mut scanner, 100, 120, 0);
+
+    let bytes = scanner.to_bytes();
+    let restored = Scanner::from_bytes(&bytes).expect("Failed to restore");
+    assert_eq!(restored, scanner);
+    assert_eq!(restored.checkpoint(119), Some(&hash(119, 0)));
+
+    let mut invalid = bytes;
+    invalid[64] = 0xff;
+    assert_eq!(Scanner::from_bytes(&invalid), Err(KeyError::InvalidData));
+
+    let mut invalid = bytes;
+    invalid[73] = MAX_REORG_DEPTH as u8 + 1;
+    assert_eq!(Scanner::from_bytes(&invalid), Err(KeyError::InvalidData));
+}
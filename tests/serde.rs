@@ -0,0 +1,76 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "serde")]
+#![allow(deprecated)]
+
+use core::fmt::LowerHex;
+
+use dusk_jubjub::JubJubScalar;
+use dusk_pki::{
+    PublicKey, PublicSpendKey, SecretKey, SecretSpendKey, StealthAddress,
+    ViewKey,
+};
+use rand_core::OsRng;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+fn assert_roundtrip<T>(key: &T)
+where
+    T: Serialize + DeserializeOwned + PartialEq + LowerHex + core::fmt::Debug,
+{
+    let json = serde_json::to_string(key).unwrap();
+    assert_eq!(json, format!("\"{:x}\"", key));
+    assert_eq!(&serde_json::from_str::<T>(&json).unwrap(), key);
+
+    let bin = bincode::serialize(key).unwrap();
+    assert_eq!(&bincode::deserialize::<T>(&bin).unwrap(), key);
+}
+
+#[test]
+fn serde_roundtrip() {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let psk = ssk.public_spend_key();
+    let vk = ssk.view_key();
+    let sa = psk.gen_stealth_address(&JubJubScalar::random(&mut OsRng));
+    let sk = SecretKey::random(&mut OsRng);
+    let pk = PublicKey::from(&sk);
+
+    assert_roundtrip(&ssk);
+    assert_roundtrip(&psk);
+    assert_roundtrip(&vk);
+    assert_roundtrip(&sa);
+    assert_roundtrip(&sk);
+    assert_roundtrip(&pk);
+}
+
+#[test]
+fn serde_rejects_invalid_keys() {
+    let psk = SecretSpendKey::random(&mut OsRng).public_spend_key();
+    let json = serde_json::to_string(&psk).unwrap();
+
+    // Wrong length
+    assert!(serde_json::from_str::<PublicSpendKey>(&json[..65]).is_err());
+    assert!(serde_json::from_str::<ViewKey>("\"00\"").is_err());
+
+    // Not hex
+    let not_hex = json.replace(&json[1..3], "zz");
+    assert!(serde_json::from_str::<PublicSpendKey>(&not_hex).is_err());
+
+    // Not a point: `v = 2` has no matching `u` on the curve
+    let mut bytes = [0u8; 32];
+    bytes[0] = 2;
+    let not_a_point = serde_json::to_string(&hex(&bytes)).unwrap();
+    assert!(serde_json::from_str::<PublicKey>(&not_a_point).is_err());
+
+    let bin = bincode::serialize(&[0xffu8; 64][..]).unwrap();
+    assert!(bincode::deserialize::<StealthAddress>(&bin).is_err());
+    assert!(bincode::deserialize::<SecretSpendKey>(&bin).is_err());
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
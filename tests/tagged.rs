@@ -0,0 +1,109 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![allow(deprecated)]
+
+use dusk_jubjub::JubJubScalar;
+use dusk_pki::{
+    KeyError, KeyType, Network, ProtocolVersion, PublicKey, PublicSpendKey,
+    SecretKey, SecretSpendKey, StealthAddress, TaggedHeader, ViewKey,
+    TAGGED_OVERHEAD,
+};
+use rand_core::OsRng;
+
+const VERSION: ProtocolVersion = ProtocolVersion::V1;
+const NETWORK: Network = Network::Testnet;
+
+#[test]
+fn tagged_roundtrip() {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let psk = ssk.public_spend_key();
+    let vk = ssk.view_key();
+    let sa = psk.gen_stealth_address(&JubJubScalar::random(&mut OsRng));
+    let sk = SecretKey::random(&mut OsRng);
+    let pk = PublicKey::from(&sk);
+
+    macro_rules! roundtrip {
+        ($key:expr, $ty:ident) => {
+            let bytes = $key.encode_tagged(VERSION, NETWORK);
+            assert_eq!(bytes.len(), $ty::TAGGED_SIZE);
+            assert_eq!(
+                $ty::decode_tagged(&bytes, NETWORK),
+                Ok(($key, VERSION))
+            );
+            assert_eq!(
+                TaggedHeader::inspect(&bytes),
+                Ok(TaggedHeader {
+                    key_type: KeyType::$ty,
+                    version: VERSION,
+                    network: NETWORK,
+                })
+            );
+        };
+    }
+
+    roundtrip!(ssk, SecretSpendKey);
+    roundtrip!(psk, PublicSpendKey);
+    roundtrip!(vk, ViewKey);
+    roundtrip!(sa, StealthAddress);
+    roundtrip!(sk, SecretKey);
+    roundtrip!(pk, PublicKey);
+}
+
+#[test]
+fn tagged_vector() {
+    let sk = SecretKey::from(JubJubScalar::one());
+
+    let mut expected = [0u8; SecretKey::TAGGED_SIZE];
+    expected[..4].copy_from_slice(&[1, 1, 1, 1]);
+    expected[35..].copy_from_slice(&[0x90, 0x84, 0xcc, 0x25]);
+
+    assert_eq!(sk.encode_tagged(VERSION, NETWORK), expected);
+}
+
+#[test]
+fn tagged_rejects_mismatches() {
+    let ssk = SecretSpendKey::random(&mut OsRng);
+    let vk = ssk.view_key();
+
+    // A view key is not a spend key, even though both are 64 bytes
+    let bytes = vk.encode_tagged(VERSION, NETWORK);
+    assert_eq!(
+        SecretSpendKey::decode_tagged(&bytes, NETWORK),
+        Err(KeyError::WrongKeyType)
+    );
+    assert_eq!(
+        PublicSpendKey::decode_tagged(&bytes, NETWORK),
+        Err(KeyError::WrongKeyType)
+    );
+
+    assert_eq!(
+        ViewKey::decode_tagged(&bytes, Network::Mainnet),
+        Err(KeyError::WrongNetwork)
+    );
+
+    let mut corrupted = bytes;
+    corrupted[10] ^= 1;
+    assert_eq!(
+        ViewKey::decode_tagged(&corrupted, NETWORK),
+        Err(KeyError::BadChecksum)
+    );
+
+    assert_eq!(
+        ViewKey::decode_tagged(&bytes[..70], NETWORK),
+        Err(KeyError::WrongLength {
+            found: 70,
+            expected: 64 + TAGGED_OVERHEAD
+        })
+    );
+    assert_eq!(
+        ViewKey::decode_tagged(&[], NETWORK),
+        Err(KeyError::WrongLength {
+            found: 0,
+            expected: TAGGED_OVERHEAD
+        })
+    );
+}
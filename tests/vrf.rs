@@ -0,0 +1,111 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![allow(deprecated)]
+
+use dusk_bytes::{ParseHexStr, Serializable};
+use dusk_jubjub::{BlsScalar, JubJubScalar};
+use dusk_pki::{PublicKey, SecretKey, VrfOutput, VrfProof};
+use rand_core::OsRng;
+
+const VECTORS: [(&[u64], &str, &str); 3] = [
+    (
+        &[],
+        "529936da100e1065e254b2d4ad082df9ad8cca2156e2ad52f89e16d715247a18",
+        "2256c153c0fc075a157e991f5373d5ff673c2480843827b3c0421e5c1d5318cb0d7ea461e8d14c995fd1b4e5148dc0e5246e0371d606e6adaf25157005f80501918074a90a2948b1a765dd918f12821109d7adeb849beb9f853ee5cd0355280b",
+    ),
+    (
+        &[0],
+        "a6092be2ebe334efb6e18c70db70d94fe220dc8b1ef6ba1e323e2f10b2827723",
+        "2271f4469839703862a07c12136cca88f2b720775347c7087da6c6f5dd2e57479c1473e5bf9cd0f460722c170c6cf27da2fd95adc2c1fa9c0b287e382b83aa020babde342be6a7ba7b39a2ad108fb2d706e1732d895ef0e1ca7372ca921b3e02",
+    ),
+    (
+        &[1, 2, 3],
+        "671a5cb93aabd764284134aa831711bba6572f134d3aade2ac419cdb95396630",
+        "9b5077c569fcc9dcb73a1f65d78c4bd0d4300aba750a2c6da0a15dba8845f66534dd1c4b8587b273a69dcbf171f1e868cbdd7e139005f9351d1c1d52b9587f00d780a814c770e0daaa7bf896102235855667a7321e2192d63ba3f1d12206850a",
+    ),
+];
+
+fn vector_key() -> SecretKey {
+    SecretKey::from(JubJubScalar::from_raw([
+        0x0123456789abcdef,
+        0xfedcba9876543210,
+        0x0f1e2d3c4b5a6978,
+        0x0000000000000001,
+    ]))
+}
+
+#[test]
+fn vrf_vectors() {
+    let sk = vector_key();
+    let pk = PublicKey::from(&sk);
+
+    for (input, output, proof) in VECTORS {
+        let input: Vec<BlsScalar> =
+            input.iter().map(|i| BlsScalar::from(*i)).collect();
+
+        let output = VrfOutput::from_hex_str(output).unwrap();
+        let proof = VrfProof::from_hex_str(proof).unwrap();
+
+        assert_eq!(sk.vrf_prove(&input), (output, proof));
+        assert_eq!(pk.vrf_verify(&input, &proof), Some(output));
+        assert_eq!(proof.gamma().is_prime_order().unwrap_u8(), 1);
+    }
+}
+
+#[test]
+fn vrf_consistency() {
+    let sk = SecretKey::random(&mut OsRng);
+    let pk = PublicKey::from(&sk);
+    let input = [BlsScalar::uni_random(&mut OsRng)];
+
+    let (output, proof) = sk.vrf_prove(&input);
+
+    assert_eq!(pk.vrf_verify(&input, &proof), Some(output));
+    assert_eq!(sk.vrf_prove(&input), (output, proof));
+
+    let wrong_pk = PublicKey::from(&SecretKey::random(&mut OsRng));
+    assert_eq!(wrong_pk.vrf_verify(&input, &proof), None);
+
+    let wrong_input = [input[0] + BlsScalar::one()];
+    assert_eq!(pk.vrf_verify(&wrong_input, &proof), None);
+
+    let (other_output, other_proof) = sk.vrf_prove(&wrong_input);
+    assert_ne!(output, other_output);
+
+    let decoded = VrfProof::from_bytes(&proof.to_bytes()).unwrap();
+    assert_eq!(decoded, proof);
+    assert_eq!(pk.vrf_verify(&input, &other_proof), None);
+}
+
+/// Keys of small order, the identity and the point of order two `(0, -1)`,
+/// with proofs for the empty input forged respectively with the zero secret,
+/// and with `gamma` the identity and an even challenge. Both proofs have the
+/// same output.
+const SMALL_ORDER_VECTORS: [(&str, &str); 2] = [
+    (
+        "0100000000000000000000000000000000000000000000000000000000000000",
+        "010000000000000000000000000000000000000000000000000000000000000008146e5a51876d23e15abfbfd18fc31701d8d1997566c19065711f4f8b2c9c0271df2ffc6dd4cf1c7367d81d0528f1af2ed905688ca87b47cbbbc5031bb8ec02",
+    ),
+    (
+        "00000000fffffffffe5bfeff02a4bd5305d8a10908d83933487d9d2953a7ed73",
+        "010000000000000000000000000000000000000000000000000000000000000064953815d5c58cb0e8dc8f32c73c720edbe4e968a528873fdf396f81e5f90d030100000000000000000000000000000000000000000000000000000000000000",
+    ),
+];
+
+#[test]
+fn vrf_small_order_keys() {
+    for (pk, proof) in SMALL_ORDER_VECTORS {
+        let pk = PublicKey::from_hex_str(pk).unwrap();
+        let proof = VrfProof::from_hex_str(proof).unwrap();
+
+        assert_eq!(pk.vrf_verify(&[], &proof), None);
+    }
+
+    // The zero secret key proves for the identity
+    let (_, proof) = SecretKey::from(JubJubScalar::zero()).vrf_prove(&[]);
+    assert_eq!(PublicKey::default().vrf_verify(&[], &proof), None);
+}
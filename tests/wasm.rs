@@ -0,0 +1,81 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Run with `wasm-bindgen-test-runner` under Node:
+//!
+//! ```text
+//! cargo test --target wasm32-unknown-unknown --features wasm --test wasm
+//! ```
+
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+#![allow(deprecated)]
+
+use dusk_bytes::{DeserializableSlice, Serializable};
+use dusk_jubjub::JubJubScalar;
+use dusk_pki::wasm;
+use dusk_pki::{PublicKey, SecretKey, SecretSpendKey};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn keys_roundtrip() {
+    let ssk = wasm::generate_secret_spend_key();
+    let key = SecretSpendKey::from_slice(&ssk).unwrap();
+
+    let vk = wasm::view_key(&ssk).unwrap();
+    assert_eq!(vk, key.view_key().to_bytes());
+
+    let psk = wasm::public_spend_key(&ssk).unwrap();
+    assert_eq!(psk, key.public_spend_key().to_bytes());
+
+    let seed = [3u8; 32];
+    assert_eq!(
+        wasm::secret_spend_key_from_seed(&seed).unwrap(),
+        wasm::secret_spend_key_from_seed(&seed).unwrap()
+    );
+    assert!(wasm::secret_spend_key_from_seed(&seed[1..]).is_err());
+}
+
+#[wasm_bindgen_test]
+fn address_encoding() {
+    let ssk = wasm::generate_secret_spend_key();
+    let psk = wasm::public_spend_key(&ssk).unwrap();
+
+    let address = wasm::encode_address(&psk, "testnet").unwrap();
+    assert_eq!(wasm::decode_address(&address, "testnet").unwrap(), psk);
+
+    assert!(wasm::decode_address(&address, "mainnet").is_err());
+    assert!(wasm::decode_address(&address[2..], "testnet").is_err());
+    assert!(wasm::decode_address("zz", "testnet").is_err());
+    assert!(wasm::encode_address(&psk, "localnet").is_err());
+}
+
+#[wasm_bindgen_test]
+fn stealth_addresses() {
+    let ssk = wasm::generate_secret_spend_key();
+    let vk = wasm::view_key(&ssk).unwrap();
+    let psk = wasm::public_spend_key(&ssk).unwrap();
+
+    let sa = wasm::gen_stealth_address(&psk, None).unwrap();
+    assert!(wasm::owns(&vk, &sa).unwrap());
+
+    let other = wasm::view_key(&wasm::generate_secret_spend_key()).unwrap();
+    assert!(!wasm::owns(&other, &sa).unwrap());
+
+    let r = JubJubScalar::from(5u64).to_bytes().to_vec();
+    let sa = wasm::gen_stealth_address(&psk, Some(r)).unwrap();
+    let key = SecretSpendKey::from_slice(&ssk).unwrap();
+    let expected = key
+        .public_spend_key()
+        .gen_stealth_address(&JubJubScalar::from(5u64));
+    assert_eq!(sa, expected.to_bytes());
+
+    let sk_r = wasm::sk_r(&ssk, &sa).unwrap();
+    let sk_r = SecretKey::from_slice(&sk_r).unwrap();
+    assert_eq!(&PublicKey::from(&sk_r), expected.pk_r());
+
+    assert!(wasm::owns(&vk, &[0xff; 64]).is_err());
+    assert!(wasm::sk_r(&ssk[..32], &sa).is_err());
+}